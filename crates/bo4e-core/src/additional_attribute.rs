@@ -44,7 +44,7 @@ pub struct AdditionalAttribute {
     /// Name/key of the attribute
     pub name: String,
     /// Value of the attribute (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     pub value: Option<AttributeValue>,
 }
 
@@ -0,0 +1,168 @@
+//! Polymorphic container for any Business Object.
+//!
+//! Used when the concrete type of a BO4E object is only known at runtime
+//! from its `_typ` discriminator.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::*;
+use crate::enums::BoType;
+use crate::traits::{Bo4eMeta, Bo4eObject};
+
+macro_rules! bo4e_any {
+    ($($variant:ident),* $(,)?) => {
+        /// Any BO4E Business Object.
+        ///
+        /// Serializes transparently as the wrapped object. For deserialization,
+        /// the concrete type has to be resolved first (see [`Bo4eAny::resolve_type`]
+        /// and [`Bo4eAny::deserialize_as`]).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use bo4e_core::bo::{Bo4eAny, Meter};
+        /// use bo4e_core::enums::BoType;
+        ///
+        /// let any = Bo4eAny::from(Meter::default());
+        /// assert_eq!(any.bo_type(), BoType::Meter);
+        /// ```
+        #[derive(Debug, Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        pub enum Bo4eAny {
+            $(
+                #[doc = concat!("A [`", stringify!($variant), "`]")]
+                $variant($variant),
+            )*
+        }
+
+        impl Bo4eAny {
+            /// Returns the type discriminator of the wrapped object.
+            pub fn bo_type(&self) -> BoType {
+                match self {
+                    $(Self::$variant(_) => BoType::$variant,)*
+                }
+            }
+
+            /// Resolve a `_typ` value (German or English type name) to a [`BoType`].
+            pub fn resolve_type(name: &str) -> Option<BoType> {
+                $(
+                    if name == $variant::type_name_german() || name == $variant::type_name_english() {
+                        return Some(BoType::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Deserialize the concrete Business Object identified by `bo_type`.
+            pub fn deserialize_as<'de, D>(bo_type: BoType, deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                match bo_type {
+                    $(BoType::$variant => $variant::deserialize(deserializer).map(Self::$variant),)*
+                }
+            }
+
+            /// Returns a reference to the metadata of the wrapped object.
+            pub fn meta(&self) -> &Bo4eMeta {
+                match self {
+                    $(Self::$variant(bo) => bo.meta(),)*
+                }
+            }
+
+            /// Returns a mutable reference to the metadata of the wrapped object.
+            pub fn meta_mut(&mut self) -> &mut Bo4eMeta {
+                match self {
+                    $(Self::$variant(bo) => bo.meta_mut(),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$variant> for Bo4eAny {
+                fn from(bo: $variant) -> Self {
+                    Self::$variant(bo)
+                }
+            }
+        )*
+    };
+}
+
+bo4e_any!(
+    Balancing,
+    BundleContract,
+    BusinessPartner,
+    ConcessionFeePriceSheet,
+    Contract,
+    ControllableResource,
+    Costs,
+    Device,
+    EnergyAmount,
+    ExternalCosts,
+    HardwarePriceSheet,
+    Invoice,
+    LoadProfile,
+    LocationAssignment,
+    LocationProperties,
+    MarketLocation,
+    MarketParticipant,
+    Meter,
+    MeteringLocation,
+    MeteringPriceSheet,
+    NetworkLocation,
+    NetworkUsagePriceSheet,
+    Offer,
+    Person,
+    PriceSheet,
+    Region,
+    RegionalTariff,
+    ServicePriceSheet,
+    Tariff,
+    TariffCosts,
+    TariffInfo,
+    TariffPriceSheet,
+    TechnicalResource,
+    Tender,
+    TimeSeries,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_type() {
+        assert_eq!(Bo4eAny::resolve_type("Zaehler"), Some(BoType::Meter));
+        assert_eq!(Bo4eAny::resolve_type("Meter"), Some(BoType::Meter));
+        assert_eq!(Bo4eAny::resolve_type("Rechnung"), Some(BoType::Invoice));
+        assert_eq!(Bo4eAny::resolve_type("Unbekannt"), None);
+    }
+
+    #[test]
+    fn test_deserialize_as() {
+        let json = r#"{"_typ":"Zaehler","meterNumber":"ANY123"}"#;
+        let mut de = serde_json::Deserializer::from_str(json);
+        let any = Bo4eAny::deserialize_as(BoType::Meter, &mut de).unwrap();
+
+        match any {
+            Bo4eAny::Meter(meter) => {
+                assert_eq!(meter.meter_number, Some("ANY123".to_string()));
+                assert_eq!(meter.meta.typ, Some("Zaehler".to_string()));
+            }
+            other => panic!("Expected Meter, got {:?}", other.bo_type()),
+        }
+    }
+
+    #[test]
+    fn test_serialize_transparent() {
+        let meter = Meter {
+            meta: Bo4eMeta::with_type("Zaehler"),
+            meter_number: Some("ANY456".to_string()),
+            ..Default::default()
+        };
+
+        let direct = serde_json::to_string(&meter).unwrap();
+        let wrapped = serde_json::to_string(&Bo4eAny::from(meter)).unwrap();
+        assert_eq!(direct, wrapped);
+    }
+}
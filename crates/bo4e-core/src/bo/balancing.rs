@@ -33,32 +33,32 @@ pub struct Balancing {
     pub meta: Bo4eMeta,
 
     /// Balance group identifier (Bilanzkreis-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bilanzkreisId"))]
     pub balance_group_id: Option<String>,
 
     /// Balance group name (Bilanzkreisname)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bilanzkreisname"))]
     pub balance_group_name: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Market area (Marktgebiet)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktgebiet"))]
     pub market_area: Option<String>,
 
     /// Balance responsible party (Bilanzkreisverantwortlicher)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "bilanzkreisverantwortlicher")
@@ -66,17 +66,17 @@ pub struct Balancing {
     pub balance_responsible_party: Option<Box<super::MarketParticipant>>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Start date of balancing (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start_date: Option<DateTime<Utc>>,
 
     /// End date of balancing (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end_date: Option<DateTime<Utc>>,
 }
@@ -34,47 +34,47 @@ pub struct BundleContract {
     pub meta: Bo4eMeta,
 
     /// Bundle contract number (Buendelvertragsnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "buendelvertragsnummer"))]
     pub bundle_contract_number: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Status of bundle contract (Vertragsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragsstatus"))]
     pub status: Option<ContractStatus>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Bundle contract start date (Vertragsbeginn)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragsbeginn"))]
     pub contract_start: Option<DateTime<Utc>>,
 
     /// Bundle contract end date (Vertragsende)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragsende"))]
     pub contract_end: Option<DateTime<Utc>>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Individual contracts in this bundle (Einzelvertraege)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einzelvertraege"))]
     pub individual_contracts: Vec<Box<super::Contract>>,
 
     /// Contracting party (Vertragspartner)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragspartner"))]
     pub contract_partner: Option<Box<super::BusinessPartner>>,
 }
@@ -97,6 +97,89 @@ impl Bo4eObject for BundleContract {
     }
 }
 
+impl BundleContract {
+    /// Check that the individual contracts fit into the bundle.
+    ///
+    /// If the bundle has a division, every contract that declares one must
+    /// use the same. No contract may start before the bundle does. Contracts
+    /// are reported by their index in `individual_contracts`.
+    pub fn validate_consistency(&self) -> Result<(), BundleError> {
+        for (index, contract) in self.individual_contracts.iter().enumerate() {
+            if let (Some(expected), Some(found)) = (self.division, contract.division) {
+                if expected != found {
+                    return Err(BundleError::DivisionMismatch {
+                        index,
+                        expected,
+                        found,
+                    });
+                }
+            }
+            if let (Some(bundle_start), Some(contract_start)) =
+                (self.contract_start, contract.contract_start)
+            {
+                if contract_start < bundle_start {
+                    return Err(BundleError::StartsBeforeBundle {
+                        index,
+                        contract_start,
+                        bundle_start,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`BundleContract::validate_consistency`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// A contract belongs to a different division than the bundle
+    DivisionMismatch {
+        /// Index of the contract in `individual_contracts`
+        index: usize,
+        /// Division of the bundle
+        expected: Division,
+        /// Division of the contract
+        found: Division,
+    },
+    /// A contract starts before the bundle
+    StartsBeforeBundle {
+        /// Index of the contract in `individual_contracts`
+        index: usize,
+        /// Start of the contract
+        contract_start: DateTime<Utc>,
+        /// Start of the bundle
+        bundle_start: DateTime<Utc>,
+    },
+}
+
+impl std::fmt::Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DivisionMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "contract {} has division {:?}, bundle has {:?}",
+                index, found, expected
+            ),
+            Self::StartsBeforeBundle {
+                index,
+                contract_start,
+                bundle_start,
+            } => write!(
+                f,
+                "contract {} starts at {}, before the bundle start {}",
+                index, contract_start, bundle_start
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +255,62 @@ mod tests {
         assert_eq!(BundleContract::type_name_german(), "Buendelvertrag");
         assert_eq!(BundleContract::type_name_english(), "BundleContract");
     }
+
+    fn contract(division: Division, start: DateTime<Utc>) -> Box<Contract> {
+        Box::new(Contract {
+            division: Some(division),
+            contract_start: Some(start),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_validate_consistency() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let later = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let mut bundle = BundleContract {
+            division: Some(Division::Electricity),
+            contract_start: Some(start),
+            individual_contracts: vec![
+                contract(Division::Electricity, start),
+                contract(Division::Electricity, later),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(bundle.validate_consistency(), Ok(()));
+
+        bundle.contract_start = Some(later);
+        assert!(matches!(
+            bundle.validate_consistency(),
+            Err(BundleError::StartsBeforeBundle { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_consistency_mixed_division() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut bundle = BundleContract {
+            division: Some(Division::Electricity),
+            individual_contracts: vec![
+                contract(Division::Electricity, start),
+                contract(Division::Gas, start),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            bundle.validate_consistency(),
+            Err(BundleError::DivisionMismatch {
+                index: 1,
+                expected: Division::Electricity,
+                found: Division::Gas,
+            })
+        );
+
+        bundle.division = None;
+        assert_eq!(bundle.validate_consistency(), Ok(()));
+    }
 }
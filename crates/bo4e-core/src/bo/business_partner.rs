@@ -32,59 +32,66 @@ pub struct BusinessPartner {
     pub meta: Bo4eMeta,
 
     /// Partner ID (Geschaeftspartner-ID)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "geschaeftspartnerId")]
+    #[serde(
+        skip_serializing_if = "crate::ser::is_none",
+        alias = "geschaeftspartnerId"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geschaeftspartnerId"))]
     pub partner_id: Option<String>,
 
     /// Company/organization name (Name1)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name1"))]
     pub name1: Option<String>,
 
     /// Additional name line (Name2)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name2"))]
     pub name2: Option<String>,
 
     /// Additional name line (Name3)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name3"))]
     pub name3: Option<String>,
 
     /// Roles this partner has (Rollen)
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
+        skip_serializing_if = "crate::ser::is_empty",
         alias = "geschaeftspartnerrollen"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geschaeftspartnerrollen"))]
     pub roles: Vec<BusinessPartnerRole>,
 
     /// Primary address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "adresse")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "adresse")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Contact methods (Kontaktwege)
-    #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "kontaktwege")]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::ser::is_empty",
+        alias = "kontaktwege"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kontaktwege"))]
     pub contact_methods: Vec<ContactMethod>,
 
     /// Commercial register number (Handelsregisternummer)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "handelsregisternummer"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "handelsregisternummer"))]
     pub commercial_register_number: Option<String>,
 
     /// Tax ID (Steuernummer)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "steuernummer")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "steuernummer")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuernummer"))]
     pub tax_id: Option<String>,
 
     /// VAT ID (Umsatzsteuer-ID)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "umsatzsteuerId")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "umsatzsteuerId")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "umsatzsteuerId"))]
     pub vat_id: Option<String>,
 }
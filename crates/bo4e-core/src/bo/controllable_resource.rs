@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::Address;
+use crate::com::{Address, TimePeriod};
 use crate::enums::{ControllableResourceType, Division, EnergyDirection};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -38,76 +38,123 @@ pub struct ControllableResource {
     pub meta: Bo4eMeta,
 
     /// Controllable resource ID (SteuerbareRessource-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerbareRessourceId"))]
     pub controllable_resource_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Resource type (Ressourcentyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ressourcentyp"))]
     pub resource_type: Option<ControllableResourceType>,
 
     /// Energy direction (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Location address (Standort)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "standort"))]
     pub address: Option<Address>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Controllable power in kW (Steuerbare Leistung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerbareLeistung"))]
     pub controllable_power: Option<f64>,
 
     /// Minimum activation time in minutes (Mindestaktivierungszeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "mindestaktivierungszeit"))]
     pub min_activation_time: Option<i32>,
 
     /// Maximum activation time in minutes (Maximalaktivierungszeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "maximalaktivierungszeit"))]
     pub max_activation_time: Option<i32>,
 
     /// Ramp up time in seconds (Hochlaufzeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hochlaufzeit"))]
     pub ramp_up_time: Option<i32>,
 
     /// Ramp down time in seconds (Herunterlaufzeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "herunterlaufzeit"))]
     pub ramp_down_time: Option<i32>,
 
     /// Associated technical resource ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "technischeRessourceId"))]
     pub technical_resource_id: Option<String>,
 
     /// Associated market location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
     pub market_location_id: Option<String>,
 
     /// Is currently active/available (Ist aktiv)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "istAktiv"))]
     pub is_active: Option<bool>,
 }
 
+/// Power in kW that §14a EnWG leaves to a dimmed consumption device.
+const GUARANTEED_POWER_KW: f64 = 4.2;
+
+impl ControllableResource {
+    /// Average power in kW that can be shifted during `window`.
+    ///
+    /// A graduated consumption resource, like a heat pump or wallbox
+    /// steered under §14a EnWG, can only be dimmed down to 4.2 kW, so only
+    /// the power above that counts. On/off resources and generation can
+    /// be curtailed fully. If the resource can be activated for less than
+    /// the whole window, the flexibility is averaged over the window, and
+    /// a window shorter than the minimum activation time gives `0.0`.
+    ///
+    /// Negative activation times are clamped to zero: a negative minimum
+    /// imposes no minimum, and a negative maximum means the resource cannot
+    /// be activated at all. A minimum above the maximum also gives `0.0`.
+    ///
+    /// Returns `None` if the resource is inactive, has no controllable
+    /// power, or the window is open or empty.
+    pub fn available_flexibility(&self, window: &TimePeriod) -> Option<f64> {
+        if self.is_active == Some(false) {
+            return None;
+        }
+        let power = self.controllable_power?;
+        let minutes = (window.end? - window.start?).num_seconds() as f64 / 60.0;
+        if minutes <= 0.0 {
+            return None;
+        }
+        let min_active = self
+            .min_activation_time
+            .map_or(0.0, |min| f64::from(min.max(0)));
+        let max_active = self
+            .max_activation_time
+            .map_or(minutes, |max| f64::from(max.max(0)).min(minutes));
+        if min_active > max_active {
+            return Some(0.0);
+        }
+        let curtailable = match (self.resource_type, self.energy_direction) {
+            (Some(ControllableResourceType::Graduated), Some(EnergyDirection::FeedOut)) => {
+                (power - GUARANTEED_POWER_KW).max(0.0)
+            }
+            _ => power,
+        };
+        Some(curtailable * max_active / minutes)
+    }
+}
+
 impl Bo4eObject for ControllableResource {
     fn type_name_german() -> &'static str {
         "SteuerbareRessource"
@@ -183,4 +230,78 @@ mod tests {
             "ControllableResource"
         );
     }
+
+    #[test]
+    fn test_heat_pump_flexibility() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 16, 0, 0).unwrap();
+        let window = TimePeriod::new(start, start + Duration::hours(2));
+        let heat_pump = ControllableResource {
+            division: Some(Division::Electricity),
+            resource_type: Some(ControllableResourceType::Graduated),
+            energy_direction: Some(EnergyDirection::FeedOut),
+            controllable_power: Some(9.0),
+            max_activation_time: Some(120),
+            ..Default::default()
+        };
+
+        let flexibility = heat_pump.available_flexibility(&window).unwrap();
+        assert!((flexibility - 4.8).abs() < 1e-9);
+
+        // Dimmed for only one of the two hours
+        let limited = ControllableResource {
+            max_activation_time: Some(60),
+            ..heat_pump.clone()
+        };
+        assert!((limited.available_flexibility(&window).unwrap() - 2.4).abs() < 1e-9);
+
+        let switched = ControllableResource {
+            resource_type: Some(ControllableResourceType::OnOff),
+            ..heat_pump.clone()
+        };
+        assert_eq!(switched.available_flexibility(&window), Some(9.0));
+
+        let inactive = ControllableResource {
+            is_active: Some(false),
+            ..heat_pump.clone()
+        };
+        assert_eq!(inactive.available_flexibility(&window), None);
+        assert_eq!(
+            heat_pump.available_flexibility(&TimePeriod::starting_from(start)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_flexibility_with_invalid_activation_times() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 16, 0, 0).unwrap();
+        let window = TimePeriod::new(start, start + Duration::hours(1));
+        let resource = ControllableResource {
+            resource_type: Some(ControllableResourceType::OnOff),
+            controllable_power: Some(10.0),
+            ..Default::default()
+        };
+
+        let negative_max = ControllableResource {
+            max_activation_time: Some(-30),
+            ..resource.clone()
+        };
+        assert_eq!(negative_max.available_flexibility(&window), Some(0.0));
+
+        let negative_min = ControllableResource {
+            min_activation_time: Some(-30),
+            ..resource.clone()
+        };
+        assert_eq!(negative_min.available_flexibility(&window), Some(10.0));
+
+        let min_above_max = ControllableResource {
+            min_activation_time: Some(45),
+            max_activation_time: Some(30),
+            ..resource
+        };
+        assert_eq!(min_above_max.available_flexibility(&window), Some(0.0));
+    }
 }
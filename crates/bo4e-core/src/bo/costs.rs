@@ -2,9 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::{Amount, CostBlock, TimePeriod};
+use crate::com::{Amount, CostBlock, CurrencyMismatch, TimePeriod};
 use crate::enums::Division;
-use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::traits::{Bo4eMeta, Bo4eObject, CostReport};
 
 /// A cost breakdown/summary.
 ///
@@ -34,37 +34,37 @@ pub struct Costs {
     pub meta: Bo4eMeta,
 
     /// Name/designation of the cost summary (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub designation: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Period the costs apply to (Abrechnungszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungszeitraum"))]
     pub period: Option<TimePeriod>,
 
     /// Total amount (Gesamtbetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtbetrag"))]
     pub total_amount: Option<Amount>,
 
     /// Cost blocks (Kostenbloecke)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenbloecke"))]
     pub cost_blocks: Vec<CostBlock>,
 
     /// Related market location (Marktlokation)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokation"))]
     pub market_location: Option<Box<super::MarketLocation>>,
 }
@@ -87,6 +87,12 @@ impl Bo4eObject for Costs {
     }
 }
 
+impl CostReport for Costs {
+    fn breakdown(&self) -> Result<Vec<(String, Amount)>, CurrencyMismatch> {
+        CostBlock::breakdown(&self.cost_blocks)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +168,28 @@ mod tests {
         assert_eq!(Costs::type_name_german(), "Kosten");
         assert_eq!(Costs::type_name_english(), "Costs");
     }
+
+    #[test]
+    fn test_grand_total() {
+        let costs = Costs {
+            cost_blocks: vec![
+                CostBlock {
+                    designation: Some("Energy".to_string()),
+                    total_amount: Some(Amount::eur(1500.0)),
+                    ..Default::default()
+                },
+                CostBlock {
+                    designation: Some("Network".to_string()),
+                    total_amount: Some(Amount::eur(800.0)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let breakdown = costs.breakdown().unwrap();
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0], ("Energy".to_string(), Amount::eur(1500.0)));
+        assert_eq!(costs.grand_total().unwrap(), Amount::eur(2300.0));
+    }
 }
@@ -36,62 +36,62 @@ pub struct Device {
     pub meta: Bo4eMeta,
 
     /// Device identification (Geraetkennung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraetkennung"))]
     pub device_id: Option<String>,
 
     /// Serial number (Seriennummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "seriennummer"))]
     pub serial_number: Option<String>,
 
     /// Device category (Geraeteklasse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraeteklasse"))]
     pub device_category: Option<DeviceCategory>,
 
     /// Device type (Geraetetyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraetetyp"))]
     pub device_type: Option<DeviceType>,
 
     /// Manufacturer (Hersteller)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hersteller"))]
     pub manufacturer: Option<String>,
 
     /// Model name (Modellbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "modellbezeichnung"))]
     pub model: Option<String>,
 
     /// Manufacturing year (Baujahr)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "baujahr"))]
     pub manufacturing_year: Option<i32>,
 
     /// Installation date (Einbaudatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einbaudatum"))]
     pub installation_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Removal date (Ausbaudatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausbaudatum"))]
     pub removal_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Firmware version (Firmware-Version)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "firmwareVersion"))]
     pub firmware_version: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Associated metering location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
     pub metering_location_id: Option<String>,
 }
@@ -114,6 +114,58 @@ impl Bo4eObject for Device {
     }
 }
 
+impl Device {
+    /// Check that the device category matches the device type.
+    ///
+    /// Each device type belongs to exactly one category (see
+    /// [`DeviceType::category`]). Devices without a type or category pass.
+    pub fn validate(&self) -> Result<(), DeviceError> {
+        if let (Some(device_type), Some(declared)) = (self.device_type, self.device_category) {
+            let implied = device_type.category();
+            if declared != implied {
+                return Err(DeviceError::CategoryMismatch {
+                    device_type,
+                    declared,
+                    implied,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`Device::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceError {
+    /// The declared category does not match the device type
+    CategoryMismatch {
+        /// Declared device type
+        device_type: DeviceType,
+        /// Declared device category
+        declared: DeviceCategory,
+        /// Category implied by the device type
+        implied: DeviceCategory,
+    },
+}
+
+impl std::fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CategoryMismatch {
+                device_type,
+                declared,
+                implied,
+            } => write!(
+                f,
+                "device type {:?} belongs to category {:?}, not {:?}",
+                device_type, implied, declared
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DeviceError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +215,28 @@ mod tests {
         assert_eq!(Device::type_name_german(), "Geraet");
         assert_eq!(Device::type_name_english(), "Device");
     }
+
+    #[test]
+    fn test_validate() {
+        let device = Device {
+            device_type: Some(DeviceType::ModemGsm),
+            device_category: Some(DeviceCategory::CommunicationEquipment),
+            ..Default::default()
+        };
+        assert_eq!(device.validate(), Ok(()));
+
+        let mismatched = Device {
+            device_type: Some(DeviceType::ModernMeasuringDevice),
+            device_category: Some(DeviceCategory::CommunicationEquipment),
+            ..Default::default()
+        };
+        assert_eq!(
+            mismatched.validate(),
+            Err(DeviceError::CategoryMismatch {
+                device_type: DeviceType::ModernMeasuringDevice,
+                declared: DeviceCategory::CommunicationEquipment,
+                implied: DeviceCategory::MeteringDevice,
+            })
+        );
+    }
 }
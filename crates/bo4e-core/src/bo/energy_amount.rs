@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::{MeasuredValue, TimePeriod};
+use crate::com::{MeasuredValue, Quantity, TimePeriod};
 use crate::enums::{Division, EnergyDirection, MeasurementType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -38,56 +38,102 @@ pub struct EnergyAmount {
     pub meta: Bo4eMeta,
 
     /// Energy amount ID (Energiemenge-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energiemengeId"))]
     pub energy_amount_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Energy direction (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Measurement type (Messart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messart"))]
     pub measurement_type: Option<MeasurementType>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Time series data (Messwerte)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messwerte"))]
     pub measured_values: Vec<MeasuredValue>,
 
     /// Associated market location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
     pub market_location_id: Option<String>,
 
     /// Associated metering location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
     pub metering_location_id: Option<String>,
 
     /// OBIS code for the measurement
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 
     /// Total energy value (Gesamtenergie)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtenergie"))]
     pub total_energy: Option<f64>,
 }
 
+impl EnergyAmount {
+    /// Sum of all values in the time series.
+    ///
+    /// Values without a number are skipped. Returns `None` if there are no
+    /// values or they do not all have the same unit.
+    pub fn total(&self) -> Option<Quantity> {
+        sum_values(self.measured_values.iter())
+    }
+
+    /// Sum of the values whose timestamp lies in `period`.
+    ///
+    /// The period includes its start and excludes its end, as in
+    /// [`TimePeriod::contains`]. Values without a timestamp are skipped.
+    /// Returns `None` under the same conditions as [`EnergyAmount::total`].
+    pub fn total_for_period(&self, period: &TimePeriod) -> Option<Quantity> {
+        sum_values(
+            self.measured_values
+                .iter()
+                .filter(|v| v.timestamp.is_some_and(|t| period.contains(t))),
+        )
+    }
+}
+
+fn sum_values<'a>(values: impl Iterator<Item = &'a MeasuredValue>) -> Option<Quantity> {
+    let mut total: Option<Quantity> = None;
+    for measured in values {
+        let Some(value) = measured.value else {
+            continue;
+        };
+        match &mut total {
+            None => {
+                total = Some(Quantity {
+                    value: Some(value),
+                    unit: measured.unit,
+                    ..Default::default()
+                })
+            }
+            Some(sum) if sum.unit == measured.unit => {
+                sum.value = sum.value.map(|v| v + value);
+            }
+            Some(_) => return None,
+        }
+    }
+    total
+}
+
 impl Bo4eObject for EnergyAmount {
     fn type_name_german() -> &'static str {
         "Energiemenge"
@@ -109,6 +155,7 @@ impl Bo4eObject for EnergyAmount {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::Unit;
 
     #[test]
     fn test_energy_amount_creation() {
@@ -156,4 +203,53 @@ mod tests {
         assert_eq!(EnergyAmount::type_name_german(), "Energiemenge");
         assert_eq!(EnergyAmount::type_name_english(), "EnergyAmount");
     }
+
+    fn hourly_series(values: &[f64]) -> Vec<MeasuredValue> {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| MeasuredValue {
+                timestamp: Some(start + Duration::hours(i as i64)),
+                value: Some(*value),
+                unit: Some(Unit::KilowattHour),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_total() {
+        let energy = EnergyAmount {
+            measured_values: hourly_series(&[1.5, 2.0, 2.5, 4.0]),
+            ..Default::default()
+        };
+        assert_eq!(energy.total(), Some(Quantity::kwh(10.0)));
+        assert_eq!(EnergyAmount::default().total(), None);
+
+        let mut mixed = energy.clone();
+        mixed.measured_values[2].unit = Some(Unit::MegawattHour);
+        assert_eq!(mixed.total(), None);
+    }
+
+    #[test]
+    fn test_total_for_period() {
+        use chrono::{TimeZone, Utc};
+
+        let energy = EnergyAmount {
+            measured_values: hourly_series(&[1.5, 2.0, 2.5, 4.0]),
+            ..Default::default()
+        };
+        // 01:00 up to but excluding 03:00
+        let period = TimePeriod::new(
+            Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 3, 0, 0).unwrap(),
+        );
+        assert_eq!(energy.total_for_period(&period), Some(Quantity::kwh(4.5)));
+
+        let later = TimePeriod::starting_from(Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap());
+        assert_eq!(energy.total_for_period(&later), None);
+    }
 }
@@ -2,9 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::{Amount, ExternalCostBlock, TimePeriod};
+use crate::com::{Amount, CurrencyMismatch, ExternalCostBlock, TimePeriod};
 use crate::enums::Division;
-use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::traits::{Bo4eMeta, Bo4eObject, CostReport};
 
 /// External/third-party costs.
 ///
@@ -37,42 +37,42 @@ pub struct ExternalCosts {
     pub meta: Bo4eMeta,
 
     /// Name/designation of the external costs (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub designation: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Period the costs apply to (Abrechnungszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungszeitraum"))]
     pub period: Option<TimePeriod>,
 
     /// Total amount (Gesamtbetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtbetrag"))]
     pub total_amount: Option<Amount>,
 
     /// External cost blocks (Fremdkostenbloecke)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "fremdkostenbloecke"))]
     pub cost_blocks: Vec<ExternalCostBlock>,
 
     /// External provider/party
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "fremdpartei"))]
     pub external_party: Option<Box<super::BusinessPartner>>,
 
     /// Related market location (Marktlokation)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokation"))]
     pub market_location: Option<Box<super::MarketLocation>>,
 }
@@ -95,6 +95,25 @@ impl Bo4eObject for ExternalCosts {
     }
 }
 
+impl CostReport for ExternalCosts {
+    /// One entry per block that states a total amount.
+    fn breakdown(&self) -> Result<Vec<(String, Amount)>, CurrencyMismatch> {
+        Ok(self
+            .cost_blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, block)| {
+                let amount = block.total_amount.clone()?;
+                let label = block
+                    .designation
+                    .clone()
+                    .unwrap_or_else(|| format!("cost block {}", index + 1));
+                Some((label, amount))
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
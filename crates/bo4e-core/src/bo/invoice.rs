@@ -4,8 +4,9 @@ use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 use crate::com::{Amount, InvoicePosition, TimePeriod};
-use crate::enums::{Division, InvoiceStatus, InvoiceType};
+use crate::enums::{Currency, Division, InvoiceStatus, InvoiceType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{finish, Validate, ValidationIssue};
 
 /// An invoice for energy services.
 ///
@@ -36,62 +37,62 @@ pub struct Invoice {
     pub meta: Bo4eMeta,
 
     /// Invoice number (Rechnungsnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungsnummer"))]
     pub invoice_number: Option<String>,
 
     /// Invoice type (Rechnungstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungstyp"))]
     pub invoice_type: Option<InvoiceType>,
 
     /// Invoice status (Rechnungsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungsstatus"))]
     pub status: Option<InvoiceStatus>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Invoice date (Rechnungsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungsdatum"))]
     pub invoice_date: Option<NaiveDate>,
 
     /// Due date (Faelligkeitsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "faelligkeitsdatum"))]
     pub due_date: Option<NaiveDate>,
 
     /// Billing period (Abrechnungszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungszeitraum"))]
     pub billing_period: Option<TimePeriod>,
 
     /// Net amount (Nettobetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "nettobetrag"))]
     pub net_amount: Option<Amount>,
 
     /// Tax amount (Steuerbetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerbetrag"))]
     pub tax_amount: Option<Amount>,
 
     /// Gross amount (Bruttobetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bruttobetrag"))]
     pub gross_amount: Option<Amount>,
 
     /// Invoice line items (Rechnungspositionen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungspositionen"))]
     pub positions: Vec<InvoicePosition>,
 
     /// Invoice recipient (Rechnungsempfaenger)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechnungsempfaenger"))]
     pub recipient: Option<Box<super::BusinessPartner>>,
 }
@@ -114,6 +115,141 @@ impl Bo4eObject for Invoice {
     }
 }
 
+/// Tolerance for comparing monetary totals (half a cent).
+const TOTALS_EPSILON: f64 = 0.005;
+
+impl Invoice {
+    /// Check that the invoice totals are consistent.
+    ///
+    /// Verifies that net, tax, and gross amounts are present, share the same
+    /// currency, and that `net + tax == gross` within half a cent. If any
+    /// position carries a total price, the sum of all position totals must
+    /// also match the net amount.
+    pub fn validate_totals(&self) -> Result<(), InvoiceTotalsError> {
+        let net = required_amount(&self.net_amount, "net_amount")?;
+        let tax = required_amount(&self.tax_amount, "tax_amount")?;
+        let gross = required_amount(&self.gross_amount, "gross_amount")?;
+
+        let currency = self.net_amount.as_ref().and_then(|a| a.currency);
+        for amount in [&self.tax_amount, &self.gross_amount].into_iter().flatten() {
+            if amount.currency != currency {
+                return Err(InvoiceTotalsError::CurrencyMismatch {
+                    expected: currency,
+                    found: amount.currency,
+                });
+            }
+        }
+
+        if (net + tax - gross).abs() > TOTALS_EPSILON {
+            return Err(InvoiceTotalsError::GrossMismatch {
+                expected: net + tax,
+                actual: gross,
+            });
+        }
+
+        if self.positions.iter().any(|p| p.total_price_value.is_some()) {
+            let positions_total: f64 = self
+                .positions
+                .iter()
+                .filter_map(|p| p.total_price_value)
+                .sum();
+            if (positions_total - net).abs() > TOTALS_EPSILON {
+                return Err(InvoiceTotalsError::PositionsMismatch {
+                    expected: net,
+                    actual: positions_total,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn required_amount(
+    amount: &Option<Amount>,
+    field: &'static str,
+) -> Result<f64, InvoiceTotalsError> {
+    amount
+        .as_ref()
+        .and_then(|a| a.value)
+        .ok_or(InvoiceTotalsError::MissingAmount(field))
+}
+
+/// Error returned by [`Invoice::validate_totals`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvoiceTotalsError {
+    /// A required amount (or its value) is missing
+    MissingAmount(&'static str),
+    /// The amounts use different currencies
+    CurrencyMismatch {
+        /// Currency of the net amount
+        expected: Option<Currency>,
+        /// Conflicting currency
+        found: Option<Currency>,
+    },
+    /// Net plus tax does not equal the gross amount
+    GrossMismatch {
+        /// Net plus tax
+        expected: f64,
+        /// Declared gross amount
+        actual: f64,
+    },
+    /// The position totals do not add up to the net amount
+    PositionsMismatch {
+        /// Declared net amount
+        expected: f64,
+        /// Sum of position totals
+        actual: f64,
+    },
+}
+
+impl std::fmt::Display for InvoiceTotalsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingAmount(field) => write!(f, "missing amount: {}", field),
+            Self::CurrencyMismatch { expected, found } => {
+                write!(
+                    f,
+                    "currency mismatch: expected {:?}, found {:?}",
+                    expected, found
+                )
+            }
+            Self::GrossMismatch { expected, actual } => write!(
+                f,
+                "gross amount {} does not equal net + tax {}",
+                actual, expected
+            ),
+            Self::PositionsMismatch { expected, actual } => write!(
+                f,
+                "sum of positions {} does not equal net amount {}",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvoiceTotalsError {}
+
+impl Validate for Invoice {
+    /// Requires an invoice number and consistent totals
+    /// (see [`Invoice::validate_totals`]).
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        if self.invoice_number.is_none() {
+            issues.push(ValidationIssue::missing("invoice_number"));
+        }
+        if let Err(err) = self.validate_totals() {
+            let path = match err {
+                InvoiceTotalsError::MissingAmount(field) => field,
+                InvoiceTotalsError::PositionsMismatch { .. } => "positions",
+                _ => "gross_amount",
+            };
+            issues.push(ValidationIssue::new(path, err.to_string()));
+        }
+        finish(issues)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +330,83 @@ mod tests {
         assert_eq!(Invoice::type_name_german(), "Rechnung");
         assert_eq!(Invoice::type_name_english(), "Invoice");
     }
+
+    #[test]
+    fn test_validate_totals_consistent() {
+        let invoice = Invoice {
+            net_amount: Some(Amount::eur(1000.00)),
+            tax_amount: Some(Amount::eur(190.00)),
+            gross_amount: Some(Amount::eur(1190.00)),
+            positions: vec![
+                InvoicePosition {
+                    total_price_value: Some(800.0),
+                    ..Default::default()
+                },
+                InvoicePosition {
+                    total_price_value: Some(200.0),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(invoice.validate_totals(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_totals_gross_off_by_a_cent() {
+        let invoice = Invoice {
+            net_amount: Some(Amount::eur(1000.00)),
+            tax_amount: Some(Amount::eur(190.00)),
+            gross_amount: Some(Amount::eur(1190.01)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            invoice.validate_totals(),
+            Err(InvoiceTotalsError::GrossMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_totals_positions_and_currency() {
+        let mut invoice = Invoice {
+            net_amount: Some(Amount::eur(100.00)),
+            tax_amount: Some(Amount::eur(19.00)),
+            gross_amount: Some(Amount::eur(119.00)),
+            positions: vec![InvoicePosition {
+                total_price_value: Some(90.0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(matches!(
+            invoice.validate_totals(),
+            Err(InvoiceTotalsError::PositionsMismatch { .. })
+        ));
+
+        invoice.positions.clear();
+        invoice.tax_amount = Some(Amount {
+            value: Some(19.0),
+            currency: Some(Currency::Usd),
+            ..Default::default()
+        });
+        assert!(matches!(
+            invoice.validate_totals(),
+            Err(InvoiceTotalsError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_collects_issues() {
+        let invoice = Invoice {
+            net_amount: Some(Amount::eur(1000.00)),
+            tax_amount: Some(Amount::eur(190.00)),
+            gross_amount: Some(Amount::eur(1200.00)),
+            ..Default::default()
+        };
+        let issues = invoice.validate().unwrap_err();
+        let paths: Vec<&str> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, vec!["invoice_number", "gross_amount"]);
+    }
 }
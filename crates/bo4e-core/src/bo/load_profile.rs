@@ -38,66 +38,100 @@ pub struct LoadProfile {
     pub meta: Bo4eMeta,
 
     /// Load profile ID (Lastgang-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lastgangId"))]
     pub load_profile_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Energy direction (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Measurement type (Messart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messart"))]
     pub measurement_type: Option<MeasurementType>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Load profile values (Lastgangwerte)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lastgangwerte"))]
     pub values: Vec<LoadProfileValue>,
 
     /// Associated market location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
     pub market_location_id: Option<String>,
 
     /// Associated metering location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
     pub metering_location_id: Option<String>,
 
     /// OBIS code
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 
     /// Interval duration in minutes (Intervalllaenge)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "intervalllaenge"))]
     pub interval_minutes: Option<i32>,
 
     /// Standard load profile type (Standardlastprofil)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "standardlastprofil"))]
     pub standard_profile_type: Option<String>,
 }
 
+impl LoadProfile {
+    /// Highest power value of the profile.
+    ///
+    /// Values without a number are skipped here and in the other metrics.
+    /// Returns `None` for a profile without values.
+    pub fn peak_power(&self) -> Option<f64> {
+        self.numbers().reduce(f64::max)
+    }
+
+    /// Mean of the power values, or `None` for a profile without values.
+    ///
+    /// All intervals are weighted equally, which matches profiles with a
+    /// fixed interval length.
+    pub fn average_power(&self) -> Option<f64> {
+        let (sum, count) = self
+            .numbers()
+            .fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Ratio of average to peak power (Benutzungsgrad).
+    ///
+    /// Returns `None` for a profile without values or without a positive
+    /// peak.
+    pub fn load_factor(&self) -> Option<f64> {
+        let peak = self.peak_power().filter(|peak| *peak > 0.0)?;
+        Some(self.average_power()? / peak)
+    }
+
+    fn numbers(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values.iter().filter_map(|v| v.value)
+    }
+}
+
 impl Bo4eObject for LoadProfile {
     fn type_name_german() -> &'static str {
         "Lastgang"
@@ -166,4 +200,30 @@ mod tests {
         assert_eq!(LoadProfile::type_name_german(), "Lastgang");
         assert_eq!(LoadProfile::type_name_english(), "LoadProfile");
     }
+
+    #[test]
+    fn test_peak_average_load_factor() {
+        let profile = LoadProfile {
+            unit: Some(Unit::Kilowatt),
+            interval_minutes: Some(15),
+            values: [20.0, 40.0, 80.0, 60.0]
+                .iter()
+                .map(|value| LoadProfileValue {
+                    value: Some(*value),
+                    ..Default::default()
+                })
+                .chain(std::iter::once(LoadProfileValue::default()))
+                .collect(),
+            ..Default::default()
+        };
+
+        assert_eq!(profile.peak_power(), Some(80.0));
+        assert_eq!(profile.average_power(), Some(50.0));
+        assert_eq!(profile.load_factor(), Some(0.625));
+
+        let empty = LoadProfile::default();
+        assert_eq!(empty.peak_power(), None);
+        assert_eq!(empty.average_power(), None);
+        assert_eq!(empty.load_factor(), None);
+    }
 }
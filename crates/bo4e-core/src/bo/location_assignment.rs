@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::TimePeriod;
 use crate::enums::{ArithmeticOperation, LocationType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{is_market_location_id, is_metering_location_id};
 
 /// An assignment between locations in the energy market.
 ///
@@ -36,47 +37,47 @@ pub struct LocationAssignment {
     pub meta: Bo4eMeta,
 
     /// Market location ID (Marktlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
     pub market_location_id: Option<String>,
 
     /// Metering location ID (Messlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
     pub metering_location_id: Option<String>,
 
     /// Network location ID (Netzlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzlokationsId"))]
     pub network_location_id: Option<String>,
 
     /// Technical resource ID (Technische-Ressource-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "technischeRessourceId"))]
     pub technical_resource_id: Option<String>,
 
     /// Controllable resource ID (Steuerbare-Ressource-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerbareRessourceId"))]
     pub controllable_resource_id: Option<String>,
 
     /// Location type (Lokationstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lokationstyp"))]
     pub location_type: Option<LocationType>,
 
     /// Arithmetic operation for combination (Rechenoperation)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "rechenoperation"))]
     pub arithmetic_operation: Option<ArithmeticOperation>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Sequence/order number (Reihenfolge)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "reihenfolge"))]
     pub sequence: Option<i32>,
 }
@@ -99,6 +100,86 @@ impl Bo4eObject for LocationAssignment {
     }
 }
 
+impl LocationAssignment {
+    /// Check the referenced location IDs for cross-wired data.
+    ///
+    /// The market location ID must have 11 digits and the metering location
+    /// ID 33 alphanumeric characters. No ID may be referenced by more than
+    /// one role, e.g. as both market and network location.
+    pub fn validate(&self) -> Result<(), AssignmentError> {
+        if let Some(id) = &self.market_location_id {
+            if !is_market_location_id(id) {
+                return Err(AssignmentError::InvalidMarketLocationId(id.clone()));
+            }
+        }
+        if let Some(id) = &self.metering_location_id {
+            if !is_metering_location_id(id) {
+                return Err(AssignmentError::InvalidMeteringLocationId(id.clone()));
+            }
+        }
+
+        let roles = [
+            ("market_location_id", &self.market_location_id),
+            ("metering_location_id", &self.metering_location_id),
+            ("network_location_id", &self.network_location_id),
+            ("technical_resource_id", &self.technical_resource_id),
+            ("controllable_resource_id", &self.controllable_resource_id),
+        ];
+        for (i, (first, id)) in roles.iter().enumerate() {
+            let Some(id) = id else { continue };
+            if let Some((second, _)) = roles[i + 1..]
+                .iter()
+                .find(|(_, other)| other.as_ref() == Some(id))
+            {
+                return Err(AssignmentError::ConflictingRoles {
+                    id: id.clone(),
+                    first,
+                    second,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`LocationAssignment::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssignmentError {
+    /// The market location ID is not 11 digits
+    InvalidMarketLocationId(String),
+    /// The metering location ID is not 33 alphanumeric characters
+    InvalidMeteringLocationId(String),
+    /// The same ID is referenced by two roles
+    ConflictingRoles {
+        /// The shared ID
+        id: String,
+        /// Field of the first role
+        first: &'static str,
+        /// Field of the second role
+        second: &'static str,
+    },
+}
+
+impl std::fmt::Display for AssignmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMarketLocationId(id) => {
+                write!(f, "market location ID {:?} is not 11 digits", id)
+            }
+            Self::InvalidMeteringLocationId(id) => write!(
+                f,
+                "metering location ID {:?} is not 33 alphanumeric characters",
+                id
+            ),
+            Self::ConflictingRoles { id, first, second } => {
+                write!(f, "ID {:?} is used as both {} and {}", id, first, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssignmentError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +232,47 @@ mod tests {
             "LocationAssignment"
         );
     }
+
+    #[test]
+    fn test_validate_clean_assignment() {
+        let assignment = LocationAssignment {
+            market_location_id: Some("51238696781".to_string()),
+            metering_location_id: Some("DE0001234567890123456789012345678".to_string()),
+            network_location_id: Some("E1234567890".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(assignment.validate(), Ok(()));
+        assert_eq!(LocationAssignment::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_melo_in_malo_slot() {
+        let melo = "DE0001234567890123456789012345678".to_string();
+        let assignment = LocationAssignment {
+            market_location_id: Some(melo.clone()),
+            metering_location_id: Some(melo.clone()),
+            ..Default::default()
+        };
+        assert_eq!(
+            assignment.validate(),
+            Err(AssignmentError::InvalidMarketLocationId(melo))
+        );
+    }
+
+    #[test]
+    fn test_validate_conflicting_roles() {
+        let assignment = LocationAssignment {
+            market_location_id: Some("51238696781".to_string()),
+            technical_resource_id: Some("51238696781".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            assignment.validate(),
+            Err(AssignmentError::ConflictingRoles {
+                id: "51238696781".to_string(),
+                first: "market_location_id",
+                second: "technical_resource_id",
+            })
+        );
+    }
 }
@@ -35,72 +35,72 @@ pub struct LocationProperties {
     pub meta: Bo4eMeta,
 
     /// Location properties ID (Standorteigenschaften-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "standorteigenschaftenId"))]
     pub location_properties_id: Option<String>,
 
     /// Location address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Geographic coordinates (Geokoordinaten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geokoordinaten"))]
     pub coordinates: Option<GeoCoordinates>,
 
     /// Building type (Gebaeudeart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gebaeudeart"))]
     pub building_type: Option<String>,
 
     /// Construction year (Baujahr)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "baujahr"))]
     pub construction_year: Option<i32>,
 
     /// Floor area in square meters (Flaeche)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "flaeche"))]
     pub floor_area: Option<f64>,
 
     /// Number of floors (Anzahl Etagen)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlEtagen"))]
     pub number_of_floors: Option<i32>,
 
     /// Number of residential units (Anzahl Wohneinheiten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlWohneinheiten"))]
     pub number_of_units: Option<i32>,
 
     /// Heating type (Heizungsart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "heizungsart"))]
     pub heating_type: Option<String>,
 
     /// Energy efficiency class (Energieeffizienzklasse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energieeffizienzklasse"))]
     pub energy_efficiency_class: Option<String>,
 
     /// Has solar installation (Hat Solaranlage)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hatSolaranlage"))]
     pub has_solar: Option<bool>,
 
     /// Has electric vehicle charging (Hat E-Ladestation)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hatELadestation"))]
     pub has_ev_charging: Option<bool>,
 
     /// Has heat pump (Hat Waermepumpe)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hatWaermepumpe"))]
     pub has_heat_pump: Option<bool>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 }
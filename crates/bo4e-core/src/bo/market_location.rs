@@ -2,11 +2,16 @@
 //!
 //! Represents the point of energy delivery or receipt in the energy market.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::com::Address;
 use crate::enums::{CustomerType, Division, EnergyDirection};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{
+    finish, is_market_location_id, validate_bdew_code, Validate, ValidationIssue,
+};
 
 /// A market location (MaLo) - the point of energy delivery/receipt.
 ///
@@ -31,6 +36,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Marktlokation"))]
 #[serde(rename_all = "camelCase")]
 pub struct MarketLocation {
@@ -39,105 +45,119 @@ pub struct MarketLocation {
     pub meta: Bo4eMeta,
 
     /// Market location ID - 11 digits (Marktlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "marktlokationsId")]
+    #[serde(
+        skip_serializing_if = "crate::ser::is_none",
+        alias = "marktlokationsId"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::malo_id))]
     pub market_location_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "sparte")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "sparte")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Energy direction (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "energierichtung")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "energierichtung")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Customer type (Kundentyp)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "kundentyp")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "kundentyp")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kundentyp"))]
     pub customer_type: Option<CustomerType>,
 
     /// Location address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "adresse")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "adresse")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Supply start date (Lieferbeginn)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "lieferbeginn")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "lieferbeginn")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferbeginn"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub supply_start: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Supply end date (Lieferende)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "lieferende")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "lieferende")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferende"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub supply_end: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Annual consumption in kWh (Jahresverbrauchsprognose)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "jahresverbrauchsprognose"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "jahresverbrauchsprognose"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::quantity))]
     pub annual_consumption: Option<f64>,
 
     /// Network operator code (Netzbetreiber-Codenummer)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "netzbetreiberCodenummer"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzbetreiberCodenummer"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub network_operator_code: Option<String>,
 
     /// Basic supplier code (Grundversorger-Codenummer)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "grundversorgerCodenummer"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "grundversorgerCodenummer"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub basic_supplier_code: Option<String>,
 
     /// Metering point operator code (Messstellenbetreiber-Codenummer)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "messstellenbetreiberCodenummer"
     )]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "messstellenbetreiberCodenummer")
     )]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub metering_operator_code: Option<String>,
 
     /// Transmission system operator code (Übertragungsnetzbetreiber-Codenummer)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "uebertragungsnetzbetreiberCodenummer"
     )]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "uebertragungsnetzbetreiberCodenummer")
     )]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub transmission_operator_code: Option<String>,
 
     /// Grid connection level (Netzebene)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "netzebene")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "netzebene")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzebene"))]
     pub grid_level: Option<String>,
 
     /// Network area (Netzgebiet)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "netzgebiet")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "netzgebiet")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzgebiet"))]
     pub network_area: Option<String>,
 
     /// Billing balance area (Bilanzierungsgebiet)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "bilanzierungsgebiet")]
+    #[serde(
+        skip_serializing_if = "crate::ser::is_none",
+        alias = "bilanzierungsgebiet"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bilanzierungsgebiet"))]
     pub balancing_area: Option<String>,
 
     /// Associated metering location IDs
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
+        skip_serializing_if = "crate::ser::is_empty",
         alias = "messlokationsIds"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsIds"))]
@@ -145,11 +165,17 @@ pub struct MarketLocation {
 
     /// Is Controllable Resource (Steuerbare Ressource)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "istSteuerbareRessource"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "istSteuerbareRessource"))]
     pub is_controllable_resource: Option<bool>,
+
+    /// Fields not modelled by this struct, kept so they survive a round trip
+    #[serde(flatten)]
+    #[cfg_attr(feature = "json-schema", schemars(skip))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Bo4eObject for MarketLocation {
@@ -170,6 +196,52 @@ impl Bo4eObject for MarketLocation {
     }
 }
 
+impl Validate for MarketLocation {
+    /// Requires an 11-digit market location ID and a division. Market
+    /// partner codes that are set must be valid BDEW code numbers.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        match &self.market_location_id {
+            None => issues.push(ValidationIssue::missing("market_location_id")),
+            Some(id) if !is_market_location_id(id) => issues.push(ValidationIssue::new(
+                "market_location_id",
+                format!("expected 11 digits, got {:?}", id),
+            )),
+            Some(_) => {}
+        }
+        if self.division.is_none() {
+            issues.push(ValidationIssue::missing("division"));
+        }
+        let codes = [
+            ("network_operator_code", &self.network_operator_code),
+            ("basic_supplier_code", &self.basic_supplier_code),
+            ("metering_operator_code", &self.metering_operator_code),
+            (
+                "transmission_operator_code",
+                &self.transmission_operator_code,
+            ),
+        ];
+        for (path, code) in codes {
+            if let Some(Err(e)) = code.as_deref().map(validate_bdew_code) {
+                issues.push(ValidationIssue::new(path, e.to_string()));
+            }
+        }
+        finish(issues)
+    }
+}
+
+impl MarketLocation {
+    /// Create a market location from its ID and division.
+    pub fn new(market_location_id: impl Into<String>, division: Division) -> Self {
+        Self {
+            meta: Bo4eMeta::for_type::<Self>(),
+            market_location_id: Some(market_location_id.into()),
+            division: Some(division),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,9 +288,73 @@ mod tests {
         assert_eq!(malo, parsed);
     }
 
+    #[test]
+    fn test_unknown_fields_roundtrip() {
+        let json = r#"{"marktlokationsId":"12345678901","lokationsbuendel":{"id":7}}"#;
+        let malo: MarketLocation = serde_json::from_str(json).unwrap();
+        assert_eq!(malo.market_location_id, Some("12345678901".to_string()));
+
+        let value = serde_json::to_value(&malo).unwrap();
+        assert_eq!(value["lokationsbuendel"]["id"], 7);
+    }
+
     #[test]
     fn test_bo4e_object_impl() {
         assert_eq!(MarketLocation::type_name_german(), "Marktlokation");
         assert_eq!(MarketLocation::type_name_english(), "MarketLocation");
     }
+
+    #[test]
+    fn test_validate_bdew_codes() {
+        let malo = MarketLocation {
+            market_location_id: Some("12345678901".to_string()),
+            division: Some(Division::Electricity),
+            network_operator_code: Some("9907248000001".to_string()),
+            basic_supplier_code: Some("9907248000005".to_string()),
+            ..Default::default()
+        };
+
+        let issues = malo.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "basic_supplier_code");
+        assert_eq!(issues[0].message, "check digit is 5, expected 1");
+    }
+
+    #[test]
+    fn test_new() {
+        let value = MarketLocation::new("51238696781", Division::Electricity);
+
+        assert_eq!(value.market_location_id.as_deref(), Some("51238696781"));
+        assert_eq!(value.division, Some(Division::Electricity));
+        assert_eq!(
+            value.meta.typ.as_deref(),
+            Some(MarketLocation::type_name_german())
+        );
+
+        assert_eq!(value.meta.version.as_deref(), Some(crate::BO4E_VERSION));
+
+        let json = serde_json::to_string(&value).unwrap();
+        let parsed: MarketLocation = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, parsed);
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let malo = MarketLocation::new("51238696781", Division::Electricity);
+        assert_eq!(malo.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_all_issues() {
+        let malo = MarketLocation {
+            market_location_id: Some("5123869678".to_string()),
+            ..Default::default()
+        };
+
+        let issues = malo.validate().unwrap_err();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "market_location_id");
+        assert_eq!(issues[0].message, r#"expected 11 digits, got "5123869678""#);
+        assert_eq!(issues[1], ValidationIssue::missing("division"));
+    }
 }
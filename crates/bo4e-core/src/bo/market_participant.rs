@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::{Address, ContactMethod};
 use crate::enums::{Division, MarketRole};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{validate_bdew_code, validate_eic};
 
 /// A market participant in the energy market.
 ///
@@ -33,41 +34,130 @@ pub struct MarketParticipant {
     pub meta: Bo4eMeta,
 
     /// Market partner ID (Marktpartner-ID) - typically BDEW code number
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktpartnerId"))]
     pub market_partner_id: Option<String>,
 
     /// Name of the market participant (Name)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name"))]
     pub name: Option<String>,
 
     /// Market role (Marktrolle)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktrolle"))]
     pub market_role: Option<MarketRole>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Primary address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Contact methods (Kontaktwege)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kontaktwege"))]
     pub contact_methods: Vec<ContactMethod>,
 
     /// Associated business partner (Geschaeftspartner)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geschaeftspartner"))]
     pub business_partner: Option<Box<super::BusinessPartner>>,
 }
 
+impl MarketParticipant {
+    /// Check that the market partner ID fits the market role.
+    ///
+    /// A participant with a role must have a market partner ID. Network
+    /// operators, metering point operators and suppliers are identified by
+    /// a BDEW code number, balance responsible parties by an EIC. Other
+    /// roles, and participants without a role, may use either.
+    pub fn validate(&self) -> Result<(), ParticipantError> {
+        let Some(id) = self.market_partner_id.as_deref() else {
+            return match self.market_role {
+                Some(role) => Err(ParticipantError::MissingId { role }),
+                None => Ok(()),
+            };
+        };
+        let is_bdew = validate_bdew_code(id).is_ok();
+        let is_eic = validate_eic(id).is_ok();
+        match self.market_role {
+            Some(
+                role @ (MarketRole::NetworkOperator
+                | MarketRole::MeteringPointOperator
+                | MarketRole::Supplier),
+            ) if !is_bdew => Err(ParticipantError::ExpectedBdewCode {
+                role,
+                id: id.to_string(),
+            }),
+            Some(role @ MarketRole::BalanceResponsibleParty) if !is_eic => {
+                Err(ParticipantError::ExpectedEic {
+                    role,
+                    id: id.to_string(),
+                })
+            }
+            _ if !is_bdew && !is_eic => Err(ParticipantError::InvalidId(id.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Error returned by [`MarketParticipant::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParticipantError {
+    /// The participant has a role but no market partner ID
+    MissingId {
+        /// Market role of the participant
+        role: MarketRole,
+    },
+    /// The role requires a BDEW code number
+    ExpectedBdewCode {
+        /// Market role of the participant
+        role: MarketRole,
+        /// The market partner ID
+        id: String,
+    },
+    /// The role requires an EIC
+    ExpectedEic {
+        /// Market role of the participant
+        role: MarketRole,
+        /// The market partner ID
+        id: String,
+    },
+    /// The market partner ID is neither a BDEW code number nor an EIC
+    InvalidId(String),
+}
+
+impl std::fmt::Display for ParticipantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingId { role } => {
+                write!(f, "{} has no market partner ID", role.german_name())
+            }
+            Self::ExpectedBdewCode { role, id } => write!(
+                f,
+                "{} needs a BDEW code number, got {:?}",
+                role.german_name(),
+                id
+            ),
+            Self::ExpectedEic { role, id } => {
+                write!(f, "{} needs an EIC, got {:?}", role.german_name(), id)
+            }
+            Self::InvalidId(id) => write!(
+                f,
+                "market partner ID {:?} is neither a BDEW code number nor an EIC",
+                id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParticipantError {}
+
 impl Bo4eObject for MarketParticipant {
     fn type_name_german() -> &'static str {
         "Marktteilnehmer"
@@ -155,4 +245,51 @@ mod tests {
         assert_eq!(MarketParticipant::type_name_german(), "Marktteilnehmer");
         assert_eq!(MarketParticipant::type_name_english(), "MarketParticipant");
     }
+
+    #[test]
+    fn test_validate_supplier() {
+        let supplier = MarketParticipant {
+            market_partner_id: Some("9907248000001".to_string()),
+            market_role: Some(MarketRole::Supplier),
+            division: Some(Division::Electricity),
+            ..Default::default()
+        };
+        assert_eq!(supplier.validate(), Ok(()));
+
+        let eic_supplier = MarketParticipant {
+            market_partner_id: Some("10YDE-RWENET---I".to_string()),
+            ..supplier
+        };
+        assert!(matches!(
+            eic_supplier.validate(),
+            Err(ParticipantError::ExpectedBdewCode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_balance_responsible_without_eic() {
+        let bkv = MarketParticipant {
+            market_partner_id: Some("9907248000001".to_string()),
+            market_role: Some(MarketRole::BalanceResponsibleParty),
+            ..Default::default()
+        };
+        assert_eq!(
+            bkv.validate(),
+            Err(ParticipantError::ExpectedEic {
+                role: MarketRole::BalanceResponsibleParty,
+                id: "9907248000001".to_string(),
+            })
+        );
+
+        let without_id = MarketParticipant {
+            market_partner_id: None,
+            ..bkv
+        };
+        assert_eq!(
+            without_id.validate(),
+            Err(ParticipantError::MissingId {
+                role: MarketRole::BalanceResponsibleParty
+            })
+        );
+    }
 }
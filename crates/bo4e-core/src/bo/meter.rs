@@ -2,6 +2,8 @@
 //!
 //! Represents a metering device for measuring energy consumption or production.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::com::{Address, Hardware, MeterRegister};
@@ -27,6 +29,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Zaehler"))]
 #[serde(rename_all = "camelCase")]
 pub struct Meter {
@@ -35,91 +38,114 @@ pub struct Meter {
     pub meta: Bo4eMeta,
 
     /// Unique meter identification number (Zaehlernummer)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "zaehlernummer")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "zaehlernummer")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlernummer"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::meter_number))]
     pub meter_number: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "sparte")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "sparte")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Type of meter (Zaehlertyp)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "zaehlertyp")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "zaehlertyp")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlertyp"))]
     pub meter_type: Option<MeterType>,
 
     /// Meter size classification (Zaehlergroesse)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "zaehlergroesse")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "zaehlergroesse")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlergroesse"))]
     pub meter_size: Option<MeterSize>,
 
     /// Installation location address (Standort)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "standort")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "standort")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "standort"))]
     pub location: Option<Address>,
 
     /// Registers on this meter (Zaehlwerke)
-    #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "zaehlwerke")]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::ser::is_empty",
+        alias = "zaehlwerke"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlwerke"))]
     pub registers: Vec<MeterRegister>,
 
     /// Hardware components (Geraeteeigenschaften)
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
+        skip_serializing_if = "crate::ser::is_empty",
         alias = "geraeteeigenschaften"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraeteeigenschaften"))]
     pub hardware: Vec<Hardware>,
 
     /// Reference to associated market location ID (Marktlokation)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "marktlokationsId")]
+    #[serde(
+        skip_serializing_if = "crate::ser::is_none",
+        alias = "marktlokationsId"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::malo_id))]
     pub market_location_id: Option<String>,
 
     /// Reference to associated metering location ID (Messlokation)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "messlokationsId")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "messlokationsId")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::melo_id))]
     pub metering_location_id: Option<String>,
 
     /// Ownership status (Eigentumsverhaeltnis)
     #[serde(
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "crate::ser::is_none",
         alias = "eigentumsverhaeltnis"
     )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "eigentumsverhaeltnis"))]
     pub ownership: Option<String>,
 
     /// Manufacturer (Hersteller)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "hersteller")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "hersteller")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hersteller"))]
     pub manufacturer: Option<String>,
 
     /// Manufacturing year (Herstellungsjahr)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "herstellungsjahr")]
+    #[serde(
+        skip_serializing_if = "crate::ser::is_none",
+        alias = "herstellungsjahr"
+    )]
     #[cfg_attr(feature = "json-schema", schemars(rename = "herstellungsjahr"))]
     pub manufacturing_year: Option<i32>,
 
     /// Installation date (Einbaudatum)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "einbaudatum")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "einbaudatum")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einbaudatum"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub installation_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Removal date (Ausbaudatum)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "ausbaudatum")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "ausbaudatum")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausbaudatum"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub removal_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Calibration date (Eichdatum)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "eichdatum")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "eichdatum")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "eichdatum"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub calibration_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Calibration expiry date (Eichablaufdatum)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "eichablaufdatum")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "eichablaufdatum")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "eichablaufdatum"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::timestamp))]
     pub calibration_expiry_date: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Fields not modelled by this struct, kept so they survive a round trip
+    #[serde(flatten)]
+    #[cfg_attr(feature = "json-schema", schemars(skip))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Bo4eObject for Meter {
@@ -140,6 +166,21 @@ impl Bo4eObject for Meter {
     }
 }
 
+impl Meter {
+    /// Create a meter with the minimally required fields set.
+    ///
+    /// Prefer this over `Default` to avoid serializing an object that is
+    /// structurally valid but carries no meter number or division.
+    pub fn new(meter_number: impl Into<String>, division: Division) -> Self {
+        Self {
+            meta: Bo4eMeta::for_type::<Self>(),
+            meter_number: Some(meter_number.into()),
+            division: Some(division),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,9 +245,37 @@ mod tests {
         assert_eq!(meter, parsed);
     }
 
+    #[test]
+    fn test_unknown_fields_roundtrip() {
+        let json = r#"{"_typ":"Zaehler","meterNumber":"TEST123","customField":"x"}"#;
+        let meter: Meter = serde_json::from_str(json).unwrap();
+        assert_eq!(meter.meter_number, Some("TEST123".to_string()));
+        assert_eq!(meter.meta.typ, Some("Zaehler".to_string()));
+        assert_eq!(meter.extra.len(), 1);
+
+        let value = serde_json::to_value(&meter).unwrap();
+        assert_eq!(value["customField"], "x");
+        assert_eq!(value["meterNumber"], "TEST123");
+    }
+
     #[test]
     fn test_bo4e_object_impl() {
         assert_eq!(Meter::type_name_german(), "Zaehler");
         assert_eq!(Meter::type_name_english(), "Meter");
     }
+
+    #[test]
+    fn test_new() {
+        let value = Meter::new("1EMH0012345678", Division::Electricity);
+
+        assert_eq!(value.meter_number.as_deref(), Some("1EMH0012345678"));
+        assert_eq!(value.division, Some(Division::Electricity));
+        assert_eq!(value.meta.typ.as_deref(), Some(Meter::type_name_german()));
+
+        assert_eq!(value.meta.version.as_deref(), Some(crate::BO4E_VERSION));
+
+        let json = serde_json::to_string(&value).unwrap();
+        let parsed: Meter = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, parsed);
+    }
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::{Address, GeoCoordinates, Hardware};
 use crate::enums::Division;
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{finish, is_metering_location_id, Validate, ValidationIssue};
 
 /// A metering location (MeLo) - where measurement takes place.
 ///
@@ -29,6 +30,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Messlokation"))]
 #[serde(rename_all = "camelCase")]
 pub struct MeteringLocation {
@@ -37,60 +39,63 @@ pub struct MeteringLocation {
     pub meta: Bo4eMeta,
 
     /// Metering location ID - 33 characters (Messlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::melo_id))]
     pub metering_location_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Location address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Geographic coordinates (Geokoordinaten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geokoordinaten"))]
     pub coordinates: Option<GeoCoordinates>,
 
     /// Metering point operator code (Messstellenbetreiber-Codenummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "messstellenbetreiberCodenummer")
     )]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub metering_operator_code: Option<String>,
 
     /// Network operator code (Netzbetreiber-Codenummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzbetreiberCodenummer"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::bdew_code))]
     pub network_operator_code: Option<String>,
 
     /// Grid area (Regelzone)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regelzone"))]
     pub grid_area: Option<String>,
 
     /// Description of the metering location (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Hardware at this metering location (Geraete)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraete"))]
     pub hardware: Vec<Hardware>,
 
     /// Associated meter IDs (Zaehler)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehler"))]
     pub meter_ids: Vec<String>,
 
     /// Associated market location IDs (Marktlokationen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationen"))]
     pub market_location_ids: Vec<String>,
 }
@@ -113,6 +118,34 @@ impl Bo4eObject for MeteringLocation {
     }
 }
 
+impl Validate for MeteringLocation {
+    /// Requires a 33-character alphanumeric metering location ID.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        match &self.metering_location_id {
+            None => issues.push(ValidationIssue::missing("metering_location_id")),
+            Some(id) if !is_metering_location_id(id) => issues.push(ValidationIssue::new(
+                "metering_location_id",
+                format!("expected 33 alphanumeric characters, got {:?}", id),
+            )),
+            Some(_) => {}
+        }
+        finish(issues)
+    }
+}
+
+impl MeteringLocation {
+    /// Create a metering location from its ID and division.
+    pub fn new(metering_location_id: impl Into<String>, division: Division) -> Self {
+        Self {
+            meta: Bo4eMeta::for_type::<Self>(),
+            metering_location_id: Some(metering_location_id.into()),
+            division: Some(division),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +195,46 @@ mod tests {
         assert_eq!(MeteringLocation::type_name_german(), "Messlokation");
         assert_eq!(MeteringLocation::type_name_english(), "MeteringLocation");
     }
+
+    #[test]
+    fn test_new() {
+        let value =
+            MeteringLocation::new("DE00014545768S0000000000000003054", Division::Electricity);
+
+        assert_eq!(
+            value.metering_location_id.as_deref(),
+            Some("DE00014545768S0000000000000003054")
+        );
+        assert_eq!(value.division, Some(Division::Electricity));
+        assert_eq!(
+            value.meta.typ.as_deref(),
+            Some(MeteringLocation::type_name_german())
+        );
+
+        assert_eq!(value.meta.version.as_deref(), Some(crate::BO4E_VERSION));
+
+        let json = serde_json::to_string(&value).unwrap();
+        let parsed: MeteringLocation = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, parsed);
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let melo = MeteringLocation::new(format!("DE{}", "0".repeat(31)), Division::Electricity);
+        assert_eq!(melo.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_invalid_id() {
+        let melo = MeteringLocation::new("DE-123", Division::Electricity);
+        let issues = melo.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "metering_location_id");
+
+        let issues = MeteringLocation::default().validate().unwrap_err();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::missing("metering_location_id")]
+        );
+    }
 }
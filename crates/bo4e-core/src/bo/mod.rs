@@ -49,6 +49,13 @@
 //! - [`Costs`] - Cost breakdown
 //! - [`TariffCosts`] - Tariff-related costs
 //! - [`ExternalCosts`] - External/third-party costs
+//!
+//! # Polymorphic access
+//!
+//! - [`Bo4eAny`] - Any business object, resolved at runtime via `_typ`
+
+// Polymorphic container
+mod any;
 
 // Epic 4.1: Locations & Technical Business Objects
 mod controllable_resource;
@@ -91,26 +98,28 @@ mod tariff_costs;
 mod tariff_info;
 mod tariff_price_sheet;
 
+pub use any::Bo4eAny;
+
 // Epic 4.1 exports
 pub use controllable_resource::ControllableResource;
-pub use device::Device;
+pub use device::{Device, DeviceError};
 pub use energy_amount::EnergyAmount;
 pub use load_profile::LoadProfile;
-pub use location_assignment::LocationAssignment;
+pub use location_assignment::{AssignmentError, LocationAssignment};
 pub use location_properties::LocationProperties;
 pub use market_location::MarketLocation;
 pub use meter::Meter;
 pub use metering_location::MeteringLocation;
-pub use network_location::NetworkLocation;
-pub use technical_resource::TechnicalResource;
+pub use network_location::{NetworkLocation, NetworkLocationError};
+pub use technical_resource::{PowerError, TechnicalResource};
 pub use time_series::TimeSeries;
 
 // Epic 4.2 exports
 pub use balancing::Balancing;
-pub use bundle_contract::BundleContract;
+pub use bundle_contract::{BundleContract, BundleError};
 pub use business_partner::BusinessPartner;
 pub use contract::Contract;
-pub use market_participant::MarketParticipant;
+pub use market_participant::{MarketParticipant, ParticipantError};
 pub use offer::Offer;
 pub use person::Person;
 pub use region::Region;
@@ -122,7 +131,7 @@ pub use concession_fee_price_sheet::ConcessionFeePriceSheet;
 pub use costs::Costs;
 pub use external_costs::ExternalCosts;
 pub use hardware_price_sheet::HardwarePriceSheet;
-pub use invoice::Invoice;
+pub use invoice::{Invoice, InvoiceTotalsError};
 pub use metering_price_sheet::MeteringPriceSheet;
 pub use network_usage_price_sheet::NetworkUsagePriceSheet;
 pub use price_sheet::PriceSheet;
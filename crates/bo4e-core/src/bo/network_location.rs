@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::Address;
 use crate::enums::{Division, NetworkLevel};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::validate_bdew_code;
 
 /// A network location - a point in the electricity or gas network.
 ///
@@ -37,46 +38,106 @@ pub struct NetworkLocation {
     pub meta: Bo4eMeta,
 
     /// Network location ID (Netzlokations-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzlokationsId"))]
     pub network_location_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Network level (Netzebene)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzebene"))]
     pub network_level: Option<NetworkLevel>,
 
     /// Location address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Network operator code (Netzbetreiber-Codenummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netzbetreiberCodenummer"))]
     pub network_operator_code: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Associated metering location IDs
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsIds"))]
     pub metering_location_ids: Vec<String>,
 
     /// Associated technical resource IDs
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "technischeRessourceIds"))]
     pub technical_resource_ids: Vec<String>,
 }
 
+impl NetworkLocation {
+    /// Check the grid references for import errors.
+    ///
+    /// A network location ID has 13 characters: `E`, eleven uppercase
+    /// letters or digits, and a digit. The network operator code must be a
+    /// code issued by the BDEW (prefix `99`) or the DVGW (prefix `98`) that
+    /// passes [`validate_bdew_code`].
+    pub fn validate(&self) -> Result<(), NetworkLocationError> {
+        if let Some(id) = &self.network_location_id {
+            if !is_network_location_id(id) {
+                return Err(NetworkLocationError::InvalidNetworkLocationId(id.clone()));
+            }
+        }
+        if let Some(code) = &self.network_operator_code {
+            let plausible = validate_bdew_code(code).is_ok()
+                && (code.starts_with("99") || code.starts_with("98"));
+            if !plausible {
+                return Err(NetworkLocationError::InvalidOperatorCode(code.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_network_location_id(id: &str) -> bool {
+    let bytes = id.as_bytes();
+    bytes.len() == 13
+        && bytes[0] == b'E'
+        && bytes[1..12]
+            .iter()
+            .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+        && bytes[12].is_ascii_digit()
+}
+
+/// Error returned by [`NetworkLocation::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkLocationError {
+    /// The network location ID does not have the `E` + 12 character format
+    InvalidNetworkLocationId(String),
+    /// The network operator code is not a 13-digit BDEW or DVGW code
+    InvalidOperatorCode(String),
+}
+
+impl std::fmt::Display for NetworkLocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNetworkLocationId(id) => {
+                write!(f, "network location ID {:?} is malformed", id)
+            }
+            Self::InvalidOperatorCode(code) => write!(
+                f,
+                "network operator code {:?} is not a BDEW or DVGW code",
+                code
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetworkLocationError {}
+
 impl Bo4eObject for NetworkLocation {
     fn type_name_german() -> &'static str {
         "Netzlokation"
@@ -142,4 +203,42 @@ mod tests {
         assert_eq!(NetworkLocation::type_name_german(), "Netzlokation");
         assert_eq!(NetworkLocation::type_name_english(), "NetworkLocation");
     }
+
+    #[test]
+    fn test_validate_operator_code() {
+        let nelo = NetworkLocation {
+            network_location_id: Some("E1234567890A5".to_string()),
+            network_operator_code: Some("9907248000001".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(nelo.validate(), Ok(()));
+
+        let wrong_prefix = NetworkLocation {
+            network_operator_code: Some("4012345000001".to_string()),
+            ..nelo.clone()
+        };
+        assert_eq!(
+            wrong_prefix.validate(),
+            Err(NetworkLocationError::InvalidOperatorCode(
+                "4012345000001".to_string()
+            ))
+        );
+
+        let short = NetworkLocation {
+            network_operator_code: Some("99072480".to_string()),
+            ..nelo.clone()
+        };
+        assert!(short.validate().is_err());
+
+        let legacy_id = NetworkLocation {
+            network_location_id: Some("NELO12345".to_string()),
+            ..nelo
+        };
+        assert_eq!(
+            legacy_id.validate(),
+            Err(NetworkLocationError::InvalidNetworkLocationId(
+                "NELO12345".to_string()
+            ))
+        );
+    }
 }
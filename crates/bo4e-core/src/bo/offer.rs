@@ -34,57 +34,57 @@ pub struct Offer {
     pub meta: Bo4eMeta,
 
     /// Offer number (Angebotsnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "angebotsnummer"))]
     pub offer_number: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Status of offer (Angebotsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "angebotsstatus"))]
     pub status: Option<OfferStatus>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Date the offer was created (Angebotsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "angebotsdatum"))]
     pub offer_date: Option<DateTime<Utc>>,
 
     /// Date until which the offer is valid (Gueltig bis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigBis"))]
     pub valid_until: Option<DateTime<Utc>>,
 
     /// Delivery period (Lieferzeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferzeitraum"))]
     pub delivery_period: Option<TimePeriod>,
 
     /// Offer variants (Angebotsvarianten)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "angebotsvarianten"))]
     pub variants: Vec<OfferVariant>,
 
     /// Reference to the bidder/supplier (Anbieter)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anbieter"))]
     pub bidder: Option<Box<super::BusinessPartner>>,
 
     /// Reference to the customer (Kunde)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kunde"))]
     pub customer: Option<Box<super::BusinessPartner>>,
 
     /// Reference to associated tender (Ausschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausschreibungId"))]
     pub tender_id: Option<String>,
 }
@@ -107,9 +107,26 @@ impl Bo4eObject for Offer {
     }
 }
 
+impl Offer {
+    /// Return the binding variant with the lowest total cost.
+    ///
+    /// Only variants with status [`OfferStatus::Binding`] are considered.
+    /// A variant's cost is [`OfferVariant::total`]; variants without a
+    /// known cost are skipped. Returns `None` if no variant has a cost.
+    pub fn cheapest_binding_variant(&self) -> Option<&OfferVariant> {
+        self.variants
+            .iter()
+            .filter(|v| v.offer_status == Some(OfferStatus::Binding))
+            .filter_map(|v| Some((v, v.total()?.value?)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(v, _)| v)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::com::{OfferPart, OfferPosition};
 
     #[test]
     fn test_offer_creation() {
@@ -174,4 +191,70 @@ mod tests {
         assert_eq!(Offer::type_name_german(), "Angebot");
         assert_eq!(Offer::type_name_english(), "Offer");
     }
+
+    #[test]
+    fn test_cheapest_binding_variant() {
+        let variant = |status, cost| OfferVariant {
+            offer_status: Some(status),
+            total_cost_value: Some(cost),
+            ..Default::default()
+        };
+        let offer = Offer {
+            variants: vec![
+                variant(OfferStatus::Binding, 1200.0),
+                variant(OfferStatus::NonBinding, 900.0),
+                variant(OfferStatus::Binding, 1100.0),
+            ],
+            ..Default::default()
+        };
+        let cheapest = offer.cheapest_binding_variant().unwrap();
+        assert_eq!(cheapest.total_cost_value, Some(1100.0));
+
+        // Only has parts; their total beats the stated values
+        let itemized = OfferVariant {
+            offer_status: Some(OfferStatus::Binding),
+            parts: vec![OfferPart {
+                positions: vec![OfferPosition {
+                    position_price_value: Some(0.25),
+                    position_quantity_value: Some(4000.0),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut with_parts = offer.clone();
+        with_parts.variants.push(itemized.clone());
+        assert_eq!(with_parts.cheapest_binding_variant(), Some(&itemized));
+
+        // Parts that state their cost count; unpriced parts give no cost
+        let stated = OfferVariant {
+            offer_status: Some(OfferStatus::Binding),
+            parts: vec![OfferPart {
+                total_cost_value: Some(950.0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let unpriced = OfferVariant {
+            offer_status: Some(OfferStatus::Binding),
+            parts: vec![OfferPart {
+                positions: vec![OfferPosition {
+                    position_quantity_value: Some(4000.0),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        with_parts.variants.push(stated.clone());
+        with_parts.variants.push(unpriced);
+        assert_eq!(with_parts.cheapest_binding_variant(), Some(&stated));
+
+        let non_binding = Offer {
+            variants: vec![variant(OfferStatus::NonBinding, 900.0)],
+            ..Default::default()
+        };
+        assert!(non_binding.cheapest_binding_variant().is_none());
+    }
 }
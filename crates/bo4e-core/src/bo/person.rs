@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::{Address, ContactMethod};
 use crate::enums::{Salutation, Title};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::JsonLanguage;
 
 /// A natural person.
 ///
@@ -34,56 +35,125 @@ pub struct Person {
     pub meta: Bo4eMeta,
 
     /// Salutation (Anrede)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anrede"))]
     pub salutation: Option<Salutation>,
 
     /// Title (Titel)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "titel"))]
     pub title: Option<Title>,
 
     /// First name (Vorname)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vorname"))]
     pub first_name: Option<String>,
 
     /// Last name (Nachname)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "nachname"))]
     pub last_name: Option<String>,
 
     /// Name suffix (Namenszusatz)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "namenszusatz"))]
     pub name_suffix: Option<String>,
 
     /// Name prefix (Namenspraefix)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "namenspraefix"))]
     pub name_prefix: Option<String>,
 
     /// Company name if representing a company (Firma)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "firma"))]
     pub company_name: Option<String>,
 
     /// Birth date (Geburtsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geburtsdatum"))]
     pub birth_date: Option<NaiveDate>,
 
     /// Primary address (Adresse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresse"))]
     pub address: Option<Address>,
 
     /// Contact methods (Kontaktwege)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kontaktwege"))]
     pub contact_methods: Vec<ContactMethod>,
 }
 
+impl Person {
+    /// Returns the letter salutation line for this person in `lang`.
+    ///
+    /// German lines read "Sehr geehrte Frau Dr. Müller", English lines
+    /// "Dear Dr. Müller" (an academic title replaces Mr/Ms in English).
+    /// Without a salutation the first name is used instead; without a last
+    /// name, or for companies and communities of heirs or owners, the
+    /// generic "Sehr geehrte Damen und Herren" / "Dear Sir or Madam" is
+    /// returned.
+    pub fn formatted_name(&self, lang: JsonLanguage) -> String {
+        let generic = match lang {
+            JsonLanguage::German => "Sehr geehrte Damen und Herren",
+            JsonLanguage::English => "Dear Sir or Madam",
+        };
+        let Some(last_name) = self.last_name.as_deref() else {
+            return generic.to_string();
+        };
+        let family_name = match self.name_prefix.as_deref() {
+            Some(prefix) => format!("{} {}", prefix, last_name),
+            None => last_name.to_string(),
+        };
+        let titled_name = match self.title {
+            Some(title) => format!("{} {}", title.german_name(), family_name),
+            None => family_name.clone(),
+        };
+
+        match (lang, self.salutation) {
+            (JsonLanguage::German, Some(Salutation::Mr)) => {
+                format!("Sehr geehrter Herr {}", titled_name)
+            }
+            (JsonLanguage::German, Some(Salutation::Ms)) => {
+                format!("Sehr geehrte Frau {}", titled_name)
+            }
+            (JsonLanguage::German, Some(Salutation::MarriedCouple)) => {
+                format!("Sehr geehrte Eheleute {}", titled_name)
+            }
+            (JsonLanguage::German, Some(Salutation::Family)) => {
+                format!("Sehr geehrte Familie {}", family_name)
+            }
+            (JsonLanguage::English, Some(Salutation::Mr)) if self.title.is_none() => {
+                format!("Dear Mr {}", family_name)
+            }
+            (JsonLanguage::English, Some(Salutation::Ms)) if self.title.is_none() => {
+                format!("Dear Ms {}", family_name)
+            }
+            (JsonLanguage::English, Some(Salutation::Mr | Salutation::Ms)) => {
+                format!("Dear {}", titled_name)
+            }
+            (JsonLanguage::English, Some(Salutation::MarriedCouple)) => {
+                format!("Dear Mr and Mrs {}", family_name)
+            }
+            (JsonLanguage::English, Some(Salutation::Family)) => {
+                format!("Dear {} family", family_name)
+            }
+            (_, Some(_)) => generic.to_string(),
+            (lang, None) => {
+                let full_name = match self.first_name.as_deref() {
+                    Some(first_name) => format!("{} {}", first_name, titled_name),
+                    None => titled_name,
+                };
+                match lang {
+                    JsonLanguage::German => format!("Guten Tag {}", full_name),
+                    JsonLanguage::English => format!("Dear {}", full_name),
+                }
+            }
+        }
+    }
+}
+
 impl Bo4eObject for Person {
     fn type_name_german() -> &'static str {
         "Person"
@@ -132,6 +202,69 @@ mod tests {
         assert_eq!(person.title, Some(Title::Dr));
     }
 
+    #[test]
+    fn test_formatted_name_german_with_doctorate() {
+        let person = Person {
+            salutation: Some(Salutation::Ms),
+            title: Some(Title::Dr),
+            first_name: Some("Anna".to_string()),
+            last_name: Some("Müller".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            person.formatted_name(JsonLanguage::German),
+            "Sehr geehrte Frau Dr. Müller"
+        );
+        assert_eq!(
+            person.formatted_name(JsonLanguage::English),
+            "Dear Dr. Müller"
+        );
+    }
+
+    #[test]
+    fn test_formatted_name_english_without_title() {
+        let person = Person {
+            salutation: Some(Salutation::Mr),
+            first_name: Some("Max".to_string()),
+            last_name: Some("Mustermann".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            person.formatted_name(JsonLanguage::English),
+            "Dear Mr Mustermann"
+        );
+        assert_eq!(
+            person.formatted_name(JsonLanguage::German),
+            "Sehr geehrter Herr Mustermann"
+        );
+    }
+
+    #[test]
+    fn test_formatted_name_missing_parts() {
+        let unnamed = Person {
+            salutation: Some(Salutation::Ms),
+            ..Default::default()
+        };
+        assert_eq!(
+            unnamed.formatted_name(JsonLanguage::German),
+            "Sehr geehrte Damen und Herren"
+        );
+
+        let no_salutation = Person {
+            first_name: Some("Erika".to_string()),
+            last_name: Some("Musterfrau".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            no_salutation.formatted_name(JsonLanguage::English),
+            "Dear Erika Musterfrau"
+        );
+        assert_eq!(
+            no_salutation.formatted_name(JsonLanguage::German),
+            "Guten Tag Erika Musterfrau"
+        );
+    }
+
     #[test]
     fn test_serialize() {
         let person = Person {
@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::RegionCriterion;
-use crate::enums::RegionType;
+use crate::com::{Address, GeoCoordinates, RegionCriterion};
+use crate::enums::{RegionType, ValidityType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// A geographical region in the energy market.
@@ -33,41 +33,94 @@ pub struct Region {
     pub meta: Bo4eMeta,
 
     /// Region code (Regionscode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionscode"))]
     pub region_code: Option<String>,
 
     /// Region name (Name)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name"))]
     pub name: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Type of region (Gebietstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gebietstyp"))]
     pub region_type: Option<RegionType>,
 
     /// Criteria that define this region (Regionskriterien)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionskriterien"))]
     pub criteria: Vec<RegionCriterion>,
 
     /// Parent region (Uebergeordnete Region)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "uebergeordneteRegion"))]
     pub parent_region: Option<Box<Region>>,
 
     /// Sub-regions (Unterregionen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "unterregionen"))]
     pub sub_regions: Vec<Box<Region>>,
 }
 
+impl Region {
+    /// Returns `true` if `address` lies in this region.
+    ///
+    /// Radius criteria need the position of the address and never match
+    /// here; use [`Region::matches_at`] for them.
+    pub fn matches(&self, address: &Address) -> bool {
+        self.matches_at(address, None)
+    }
+
+    /// Returns `true` if `address`, located at `position`, lies in this region.
+    ///
+    /// Criteria are combined by their validity type:
+    ///
+    /// - "only in" criteria (also those without a validity type) are
+    ///   alternatives, at least one of them must match
+    /// - "only in combination with" criteria must all match
+    /// - "not in" criteria exclude every address they match
+    ///
+    /// A region without criteria matches nothing by itself. In either case
+    /// the address also lies in the region if it lies in one of the
+    /// sub-regions. See [`RegionCriterion::matches`] for how single criteria
+    /// are evaluated.
+    pub fn matches_at(&self, address: &Address, position: Option<&GeoCoordinates>) -> bool {
+        self.criteria_match(address, position)
+            || self
+                .sub_regions
+                .iter()
+                .any(|region| region.matches_at(address, position))
+    }
+
+    fn criteria_match(&self, address: &Address, position: Option<&GeoCoordinates>) -> bool {
+        if self.criteria.is_empty() {
+            return false;
+        }
+        let mut any_alternative = false;
+        let mut alternative_matched = false;
+        for criterion in &self.criteria {
+            let matched = criterion.matches(address, position);
+            match criterion.validity_type {
+                Some(ValidityType::NotIn) if matched => return false,
+                Some(ValidityType::NotIn) => {}
+                Some(ValidityType::OnlyInCombinationWith) if !matched => return false,
+                Some(ValidityType::OnlyInCombinationWith) => {}
+                _ => {
+                    any_alternative = true;
+                    alternative_matched |= matched;
+                }
+            }
+        }
+        alternative_matched || !any_alternative
+    }
+}
+
 impl Bo4eObject for Region {
     fn type_name_german() -> &'static str {
         "Region"
@@ -89,6 +142,7 @@ impl Bo4eObject for Region {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::RegionCriterionType;
 
     #[test]
     fn test_region_creation() {
@@ -169,4 +223,99 @@ mod tests {
         assert_eq!(Region::type_name_german(), "Region");
         assert_eq!(Region::type_name_english(), "Region");
     }
+
+    fn criterion(
+        validity_type: ValidityType,
+        criterion_type: RegionCriterionType,
+        value: &str,
+    ) -> RegionCriterion {
+        RegionCriterion {
+            validity_type: Some(validity_type),
+            criterion_type: Some(criterion_type),
+            value: Some(value.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn address(postal_code: &str, city: &str) -> Address {
+        Address::builder()
+            .postal_code(postal_code)
+            .city(city)
+            .build()
+    }
+
+    #[test]
+    fn test_matches_postal_code_range() {
+        let region = Region {
+            name: Some("Köln und Umgebung".to_string()),
+            criteria: vec![
+                criterion(
+                    ValidityType::OnlyIn,
+                    RegionCriterionType::PostalCodeRange,
+                    "50000-51999",
+                ),
+                criterion(
+                    ValidityType::NotIn,
+                    RegionCriterionType::PostalCode,
+                    "50999",
+                ),
+            ],
+            ..Default::default()
+        };
+
+        assert!(region.matches(&address("50667", "Köln")));
+        assert!(region.matches(&address("51065", "Köln")));
+        assert!(!region.matches(&address("50999", "Köln")));
+        assert!(!region.matches(&address("80331", "München")));
+        assert!(!region.matches(&Address::default()));
+    }
+
+    #[test]
+    fn test_non_matching_municipality() {
+        let region = Region {
+            criteria: vec![criterion(
+                ValidityType::OnlyIn,
+                RegionCriterionType::MunicipalityName,
+                "Köln",
+            )],
+            ..Default::default()
+        };
+
+        assert!(region.matches(&address("50667", "köln")));
+        assert!(!region.matches(&address("40213", "Düsseldorf")));
+
+        let parent = Region {
+            sub_regions: vec![Box::new(region)],
+            ..Default::default()
+        };
+        assert!(parent.matches(&address("50667", "Köln")));
+        assert!(!parent.matches(&address("40213", "Düsseldorf")));
+    }
+
+    #[test]
+    fn test_matches_radius() {
+        let region = Region {
+            criteria: vec![criterion(
+                ValidityType::OnlyIn,
+                RegionCriterionType::RadiusKm,
+                "30;50.9413;6.9583",
+            )],
+            ..Default::default()
+        };
+        let bonn = GeoCoordinates {
+            latitude: Some(50.7374),
+            longitude: Some(7.0982),
+            ..Default::default()
+        };
+        let duesseldorf = GeoCoordinates {
+            latitude: Some(51.2277),
+            longitude: Some(6.7735),
+            ..Default::default()
+        };
+
+        let address = address("53111", "Bonn");
+        assert!(region.matches_at(&address, Some(&bonn)));
+        assert!(!region.matches_at(&address, Some(&duesseldorf)));
+        assert!(!region.matches(&address));
+    }
 }
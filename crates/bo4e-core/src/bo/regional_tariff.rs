@@ -3,8 +3,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::com::{RegionalPriceTier, RegionalSurcharge, TimePeriod};
-use crate::enums::Division;
+use crate::com::{Address, Amount, PriceTier, RegionalPriceTier, RegionalSurcharge, TimePeriod};
+use crate::enums::{CalculationMethod, Division};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// A regional tariff definition.
@@ -34,61 +34,96 @@ pub struct RegionalTariff {
     pub meta: Bo4eMeta,
 
     /// Tariff code (Tarifcode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifcode"))]
     pub tariff_code: Option<String>,
 
     /// Tariff name (Tarifname)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifname"))]
     pub name: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Tariff provider (Tarifanbieter)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifanbieter"))]
     pub provider: Option<Box<super::BusinessPartner>>,
 
     /// Region this tariff applies to (Region)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "region"))]
     pub region: Option<Box<super::Region>>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Start date (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start_date: Option<DateTime<Utc>>,
 
     /// End date (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end_date: Option<DateTime<Utc>>,
 
     /// Regional price tiers (Regionale Preisstufen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionalePreisstufen"))]
     pub price_tiers: Vec<RegionalPriceTier>,
 
     /// Regional surcharges (Regionale Aufschlaege)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionaleAufschlaege"))]
     pub surcharges: Vec<RegionalSurcharge>,
 }
 
+impl RegionalTariff {
+    /// Compute the price of `quantity` for a customer at `address`.
+    ///
+    /// Each price tier is linked by its region code to the tariff's region
+    /// or one of its sub-regions. The first tier whose region contains the
+    /// address (see [`Region::matches`](super::Region::matches)) is used, and
+    /// the whole quantity is priced at the unit price of the bracket it
+    /// falls into. The result is in EUR; regional surcharges are not applied.
+    ///
+    /// Returns `None` if the address lies in none of the regions or no
+    /// bracket of the matching tier covers the quantity.
+    pub fn price_for(&self, address: &Address, quantity: f64) -> Option<Amount> {
+        let region = self.region.as_deref()?;
+        let tier = self.price_tiers.iter().find(|tier| {
+            tier.region_code
+                .as_deref()
+                .and_then(|code| find_region(region, code))
+                .is_some_and(|region| region.matches(address))
+        })?;
+        PriceTier::price_for_quantity(&tier.tiers, quantity, CalculationMethod::Steps)
+            .map(Amount::eur)
+    }
+}
+
+/// Find the region with `code` in the tree below and including `region`.
+fn find_region<'a>(region: &'a super::Region, code: &str) -> Option<&'a super::Region> {
+    if region.region_code.as_deref() == Some(code) {
+        return Some(region);
+    }
+    region
+        .sub_regions
+        .iter()
+        .find_map(|sub_region| find_region(sub_region, code))
+}
+
 impl Bo4eObject for RegionalTariff {
     fn type_name_german() -> &'static str {
         "Regionaltarif"
@@ -181,4 +216,56 @@ mod tests {
         assert_eq!(RegionalTariff::type_name_german(), "Regionaltarif");
         assert_eq!(RegionalTariff::type_name_english(), "RegionalTariff");
     }
+
+    #[test]
+    fn test_price_for_matching_region() {
+        use crate::bo::Region;
+        use crate::com::RegionCriterion;
+        use crate::enums::{RegionCriterionType, ValidityType};
+
+        let postal_area = |code: &str, range: &str| {
+            Box::new(Region {
+                region_code: Some(code.to_string()),
+                criteria: vec![RegionCriterion {
+                    validity_type: Some(ValidityType::OnlyIn),
+                    criterion_type: Some(RegionCriterionType::PostalCodeRange),
+                    value: Some(range.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+        };
+        let price_tier = |code: &str, unit_price: f64| RegionalPriceTier {
+            region_code: Some(code.to_string()),
+            tiers: vec![PriceTier {
+                lower_limit: Some(0.0),
+                unit_price: Some(unit_price),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let tariff = RegionalTariff {
+            region: Some(Box::new(Region {
+                region_code: Some("DE-NRW".to_string()),
+                sub_regions: vec![
+                    postal_area("KOELN", "50000-51999"),
+                    postal_area("DUESSELDORF", "40000-40999"),
+                ],
+                ..Default::default()
+            })),
+            price_tiers: vec![price_tier("KOELN", 0.30), price_tier("DUESSELDORF", 0.28)],
+            ..Default::default()
+        };
+
+        let cologne = Address::builder().postal_code("50667").city("Köln").build();
+        let price = tariff.price_for(&cologne, 1000.0).unwrap();
+        assert!((price.value.unwrap() - 300.0).abs() < 1e-9);
+
+        let munich = Address::builder()
+            .postal_code("80331")
+            .city("München")
+            .build();
+        assert_eq!(tariff.price_for(&munich, 1000.0), None);
+    }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::com::{EnergyMix, Price, PriceTier, TariffCalculationParameter, TimePeriod};
 use crate::enums::{CustomerType, Division};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{finish, validate_nested, Validate, ValidationIssue};
 
 /// A tariff definition.
 ///
@@ -36,47 +37,47 @@ pub struct Tariff {
     pub meta: Bo4eMeta,
 
     /// Tariff name (Tarifname)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifname"))]
     pub tariff_name: Option<String>,
 
     /// Tariff description (Tarifbeschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifbeschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Target customer type (Kundentyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kundentyp"))]
     pub customer_type: Option<CustomerType>,
 
     /// Validity period (Gueltigkeitszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitszeitraum"))]
     pub validity_period: Option<TimePeriod>,
 
     /// Base price (Grundpreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "grundpreis"))]
     pub base_price: Option<Price>,
 
     /// Working price (Arbeitspreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "arbeitspreis"))]
     pub working_price: Option<Price>,
 
     /// Price tiers (Preisstaffeln)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preisstaffeln"))]
     pub price_tiers: Vec<PriceTier>,
 
     /// Calculation parameters (Tarifberechnungsparameter)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "tarifberechnungsparameter")
@@ -84,12 +85,12 @@ pub struct Tariff {
     pub calculation_parameters: Option<TariffCalculationParameter>,
 
     /// Energy mix composition (Energiemix)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energiemix"))]
     pub energy_mix: Option<EnergyMix>,
 
     /// Provider/supplier
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anbieter"))]
     pub supplier: Option<Box<super::BusinessPartner>>,
 }
@@ -112,6 +113,15 @@ impl Bo4eObject for Tariff {
     }
 }
 
+impl Validate for Tariff {
+    /// Validates the attached energy mix.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        validate_nested(self.energy_mix.as_ref(), "energy_mix", &mut issues);
+        finish(issues)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,9 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::com::{Amount, CostBlock, Price, TimePeriod};
+use crate::com::{Amount, CostBlock, CurrencyMismatch, Price, TimePeriod};
 use crate::enums::Division;
-use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::traits::{Bo4eMeta, Bo4eObject, CostReport};
 
 /// Tariff-related costs.
 ///
@@ -37,62 +37,62 @@ pub struct TariffCosts {
     pub meta: Bo4eMeta,
 
     /// Name/designation of the tariff costs (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub designation: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Period the costs apply to (Abrechnungszeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungszeitraum"))]
     pub period: Option<TimePeriod>,
 
     /// Total amount (Gesamtbetrag)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtbetrag"))]
     pub total_amount: Option<Amount>,
 
     /// Base price applied (Grundpreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "grundpreis"))]
     pub base_price: Option<Price>,
 
     /// Base price cost (Grundpreiskosten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "grundpreiskosten"))]
     pub base_price_cost: Option<Amount>,
 
     /// Working price applied (Arbeitspreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "arbeitspreis"))]
     pub working_price: Option<Price>,
 
     /// Working price cost (Arbeitspreiskosten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "arbeitspreiskosten"))]
     pub working_price_cost: Option<Amount>,
 
     /// Consumption quantity (Verbrauchsmenge)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "verbrauchsmenge"))]
     pub consumption: Option<f64>,
 
     /// Cost blocks (Kostenbloecke)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenbloecke"))]
     pub cost_blocks: Vec<CostBlock>,
 
     /// Reference to the tariff
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarif"))]
     pub tariff: Option<Box<super::Tariff>>,
 }
@@ -115,6 +115,21 @@ impl Bo4eObject for TariffCosts {
     }
 }
 
+impl CostReport for TariffCosts {
+    /// Base and working price costs come first, followed by the cost blocks.
+    fn breakdown(&self) -> Result<Vec<(String, Amount)>, CurrencyMismatch> {
+        let prices = [
+            ("base price", &self.base_price_cost),
+            ("working price", &self.working_price_cost),
+        ];
+        Ok(prices
+            .into_iter()
+            .filter_map(|(label, cost)| Some((label.to_string(), cost.clone()?)))
+            .chain(CostBlock::breakdown(&self.cost_blocks)?)
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +187,26 @@ mod tests {
         assert_eq!(TariffCosts::type_name_german(), "Tarifkosten");
         assert_eq!(TariffCosts::type_name_english(), "TariffCosts");
     }
+
+    #[test]
+    fn test_breakdown() {
+        let costs = TariffCosts {
+            base_price_cost: Some(Amount::eur(120.0)),
+            working_price_cost: Some(Amount::eur(900.0)),
+            cost_blocks: vec![CostBlock {
+                total_amount: Some(Amount::eur(30.0)),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let labels: Vec<_> = costs
+            .breakdown()
+            .unwrap()
+            .into_iter()
+            .map(|(l, _)| l)
+            .collect();
+        assert_eq!(labels, ["base price", "working price", "cost block 1"]);
+        assert_eq!(costs.grand_total().unwrap(), Amount::eur(1050.0));
+    }
 }
@@ -38,76 +38,153 @@ pub struct TechnicalResource {
     pub meta: Bo4eMeta,
 
     /// Technical resource ID (TechnischeRessource-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "technischeRessourceId"))]
     pub technical_resource_id: Option<String>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Usage type (Verwendungszweck)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "verwendungszweck"))]
     pub usage: Option<TechnicalResourceUsage>,
 
     /// Energy direction (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Location address (Standort)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "standort"))]
     pub address: Option<Address>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Nominal power in kW (Nennleistung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "nennleistung"))]
     pub nominal_power: Option<f64>,
 
     /// Maximum power in kW (Maximalleistung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "maximalleistung"))]
     pub max_power: Option<f64>,
 
     /// Minimum power in kW (Minimalleistung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "minimalleistung"))]
     pub min_power: Option<f64>,
 
     /// Energy capacity in kWh (Speicherkapazitaet)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "speicherkapazitaet"))]
     pub energy_capacity: Option<f64>,
 
     /// Associated metering location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messlokationsId"))]
     pub metering_location_id: Option<String>,
 
     /// Associated market location ID
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "marktlokationsId"))]
     pub market_location_id: Option<String>,
 
     /// Commissioning date (Inbetriebnahmedatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "inbetriebnahmedatum"))]
     pub commissioning_date: Option<chrono::DateTime<chrono::Utc>>,
 
     /// Decommissioning date (Stilllegungsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "stilllegungsdatum"))]
     pub decommissioning_date: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+impl TechnicalResource {
+    /// Check that the power values are consistent.
+    ///
+    /// Requires `min_power <= nominal_power <= max_power` for the values
+    /// that are set. A storage resource, one with an energy capacity, must
+    /// also have both a minimum and a maximum power.
+    pub fn validate_power(&self) -> Result<(), PowerError> {
+        if let (Some(min), Some(max)) = (self.min_power, self.max_power) {
+            if min > max {
+                return Err(PowerError::MinAboveMax { min, max });
+            }
+        }
+        if let Some(nominal) = self.nominal_power {
+            if let Some(min) = self.min_power.filter(|min| *min > nominal) {
+                return Err(PowerError::NominalOutOfBounds {
+                    nominal,
+                    bound: min,
+                });
+            }
+            if let Some(max) = self.max_power.filter(|max| *max < nominal) {
+                return Err(PowerError::NominalOutOfBounds {
+                    nominal,
+                    bound: max,
+                });
+            }
+        }
+        if self.energy_capacity.is_some() && (self.min_power.is_none() || self.max_power.is_none())
+        {
+            return Err(PowerError::StorageWithoutBounds);
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`TechnicalResource::validate_power`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PowerError {
+    /// The minimum power exceeds the maximum power
+    MinAboveMax {
+        /// Minimum power in kW
+        min: f64,
+        /// Maximum power in kW
+        max: f64,
+    },
+    /// The nominal power lies outside the minimum and maximum power
+    NominalOutOfBounds {
+        /// Nominal power in kW
+        nominal: f64,
+        /// The violated bound in kW
+        bound: f64,
+    },
+    /// A storage resource lacks a minimum or maximum power
+    StorageWithoutBounds,
+}
+
+impl std::fmt::Display for PowerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinAboveMax { min, max } => write!(
+                f,
+                "minimum power {} kW exceeds maximum power {} kW",
+                min, max
+            ),
+            Self::NominalOutOfBounds { nominal, bound } => write!(
+                f,
+                "nominal power {} kW lies outside the power bound {} kW",
+                nominal, bound
+            ),
+            Self::StorageWithoutBounds => {
+                write!(f, "storage resource needs a minimum and maximum power")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PowerError {}
+
 impl Bo4eObject for TechnicalResource {
     fn type_name_german() -> &'static str {
         "TechnischeRessource"
@@ -176,4 +253,56 @@ mod tests {
         assert_eq!(TechnicalResource::type_name_german(), "TechnischeRessource");
         assert_eq!(TechnicalResource::type_name_english(), "TechnicalResource");
     }
+
+    #[test]
+    fn test_validate_power_consistent() {
+        let battery = TechnicalResource {
+            nominal_power: Some(10.0),
+            max_power: Some(12.0),
+            min_power: Some(-12.0),
+            energy_capacity: Some(20.0),
+            ..Default::default()
+        };
+        assert_eq!(battery.validate_power(), Ok(()));
+        assert_eq!(TechnicalResource::default().validate_power(), Ok(()));
+
+        let unbounded = TechnicalResource {
+            energy_capacity: Some(20.0),
+            max_power: Some(12.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            unbounded.validate_power(),
+            Err(PowerError::StorageWithoutBounds)
+        );
+    }
+
+    #[test]
+    fn test_validate_power_min_above_max() {
+        let resource = TechnicalResource {
+            min_power: Some(50.0),
+            max_power: Some(30.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            resource.validate_power(),
+            Err(PowerError::MinAboveMax {
+                min: 50.0,
+                max: 30.0
+            })
+        );
+
+        let resource = TechnicalResource {
+            nominal_power: Some(40.0),
+            max_power: Some(30.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            resource.validate_power(),
+            Err(PowerError::NominalOutOfBounds {
+                nominal: 40.0,
+                bound: 30.0
+            })
+        );
+    }
 }
@@ -35,57 +35,57 @@ pub struct Tender {
     pub meta: Bo4eMeta,
 
     /// Tender number (Ausschreibungsnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausschreibungsnummer"))]
     pub tender_number: Option<String>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Type of tender (Ausschreibungstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausschreibungstyp"))]
     pub tender_type: Option<TenderType>,
 
     /// Status/phase of tender (Ausschreibungsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausschreibungsstatus"))]
     pub status: Option<TenderStatus>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Publication date (Veroeffentlichungsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "veroeffentlichungsdatum"))]
     pub publication_date: Option<DateTime<Utc>>,
 
     /// Submission deadline (Abgabefrist)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abgabefrist"))]
     pub submission_deadline: Option<DateTime<Utc>>,
 
     /// Delivery period (Lieferzeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferzeitraum"))]
     pub delivery_period: Option<TimePeriod>,
 
     /// Tendering party (Ausschreibender)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ausschreibender"))]
     pub tendering_party: Option<Box<super::BusinessPartner>>,
 
     /// Estimated annual consumption in kWh (Jahresverbrauch)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "jahresverbrauch"))]
     pub estimated_annual_consumption: Option<f64>,
 
     /// Number of delivery points (Anzahl Lieferstellen)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlLieferstellen"))]
     pub number_of_delivery_points: Option<i32>,
 }
@@ -108,6 +108,43 @@ impl Bo4eObject for Tender {
     }
 }
 
+impl Tender {
+    /// Check whether offers can be submitted at `date`.
+    ///
+    /// The tender must not have passed the offer phase (phase 3 and later
+    /// are closed), `date` must not lie before the publication date, and
+    /// it must not lie after the submission deadline. Missing dates do not
+    /// restrict the window.
+    pub fn is_open_on(&self, date: DateTime<Utc>) -> bool {
+        let open_phase = self
+            .status
+            .map_or(true, |s| phase(s) <= phase(TenderStatus::Phase2));
+        let published = self.publication_date.map_or(true, |p| date >= p);
+        let before_deadline = self.submission_deadline.map_or(true, |d| date <= d);
+        open_phase && published && before_deadline
+    }
+
+    /// Check whether the tender may move to status `new`.
+    ///
+    /// Phases only advance; skipping phases is allowed since participation
+    /// competition and negotiation are optional in many procedures. A
+    /// tender without a status may enter any phase.
+    pub fn can_transition_to(&self, new: TenderStatus) -> bool {
+        self.status
+            .map_or(true, |current| phase(new) > phase(current))
+    }
+}
+
+/// Position of a status in the tender process.
+fn phase(status: TenderStatus) -> u8 {
+    match status {
+        TenderStatus::Phase1 => 1,
+        TenderStatus::Phase2 => 2,
+        TenderStatus::Phase3 => 3,
+        TenderStatus::Phase4 => 4,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +214,41 @@ mod tests {
         assert_eq!(Tender::type_name_german(), "Ausschreibung");
         assert_eq!(Tender::type_name_english(), "Tender");
     }
+
+    #[test]
+    fn test_is_open_on() {
+        use chrono::TimeZone;
+
+        let deadline = Utc.with_ymd_and_hms(2024, 6, 30, 12, 0, 0).unwrap();
+        let tender = Tender {
+            status: Some(TenderStatus::Phase2),
+            publication_date: Some(Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap()),
+            submission_deadline: Some(deadline),
+            ..Default::default()
+        };
+
+        assert!(tender.is_open_on(Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap()));
+        assert!(tender.is_open_on(deadline));
+        assert!(!tender.is_open_on(Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()));
+        assert!(!tender.is_open_on(Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()));
+
+        let awarded = Tender {
+            status: Some(TenderStatus::Phase4),
+            ..tender
+        };
+        assert!(!awarded.is_open_on(Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_can_transition_to() {
+        let tender = Tender {
+            status: Some(TenderStatus::Phase2),
+            ..Default::default()
+        };
+        assert!(tender.can_transition_to(TenderStatus::Phase3));
+        assert!(tender.can_transition_to(TenderStatus::Phase4));
+        assert!(!tender.can_transition_to(TenderStatus::Phase1));
+        assert!(!tender.can_transition_to(TenderStatus::Phase2));
+        assert!(Tender::default().can_transition_to(TenderStatus::Phase1));
+    }
 }
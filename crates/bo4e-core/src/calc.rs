@@ -0,0 +1,465 @@
+//! Calculation helpers for monetary and tariff values.
+//!
+//! Rounding operates on the shortest decimal representation of a value
+//! (the one printed by `Display`), so `2.675` is treated as exactly 2.675
+//! rather than its binary approximation 2.67499999….
+
+use crate::com::{Amount, CurrencyMismatch, Discount, Surcharge};
+use crate::enums::{ArithmeticOperation, Currency, RoundingMode, SurchargeTarget, SurchargeType};
+
+/// Round a monetary value to `decimals` decimal places using a BO4E rounding mode.
+///
+/// - [`RoundingMode::None`] returns the value unchanged
+/// - [`RoundingMode::Commercial`] rounds half away from zero (kaufmännisch)
+/// - [`RoundingMode::Floor`] rounds towards negative infinity
+/// - [`RoundingMode::Ceiling`] rounds towards positive infinity
+///
+/// Non-finite values are returned unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::calc::round_money;
+/// use bo4e_core::enums::RoundingMode;
+///
+/// assert_eq!(round_money(2.675, 2, RoundingMode::Commercial), 2.68);
+/// assert_eq!(round_money(2.679, 2, RoundingMode::Floor), 2.67);
+/// ```
+pub fn round_money(value: f64, decimals: u32, mode: RoundingMode) -> f64 {
+    match mode {
+        RoundingMode::None => value,
+        RoundingMode::Commercial => round_decimal(value, decimals, Rule::HalfUp),
+        RoundingMode::Floor => round_decimal(value, decimals, Rule::Floor),
+        RoundingMode::Ceiling => round_decimal(value, decimals, Rule::Ceiling),
+    }
+}
+
+/// Round a value to `decimals` decimal places using banker's rounding.
+///
+/// Ties are rounded to the nearest even digit. This mode is required by
+/// some billing regulations but has no `RoundingMode` value in BO4E.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::calc::round_half_even;
+///
+/// assert_eq!(round_half_even(2.665, 2), 2.66);
+/// assert_eq!(round_half_even(2.675, 2), 2.68);
+/// ```
+pub fn round_half_even(value: f64, decimals: u32) -> f64 {
+    round_decimal(value, decimals, Rule::HalfEven)
+}
+
+/// Apply an arithmetic operation to two operands.
+///
+/// Returns `None` for a division by zero instead of producing an infinite
+/// or NaN result.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::calc::apply;
+/// use bo4e_core::enums::ArithmeticOperation;
+///
+/// assert_eq!(apply(ArithmeticOperation::Multiplication, 3.0, 0.25), Some(0.75));
+/// assert_eq!(apply(ArithmeticOperation::Division, 1.0, 0.0), None);
+/// ```
+pub fn apply(op: ArithmeticOperation, lhs: f64, rhs: f64) -> Option<f64> {
+    match op {
+        ArithmeticOperation::Addition => Some(lhs + rhs),
+        ArithmeticOperation::Subtraction => Some(lhs - rhs),
+        ArithmeticOperation::Multiplication => Some(lhs * rhs),
+        ArithmeticOperation::Division if rhs == 0.0 => None,
+        ArithmeticOperation::Division => Some(lhs / rhs),
+    }
+}
+
+/// German VAT rate category under the UStG.
+///
+/// BO4E only has tax codes that combine the tax type and rate
+/// (`Steuerkennzeichen`), so this is a calculation helper rather than a
+/// BO4E enumeration and is not serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VatRate {
+    /// Standard rate of 19% (Regelsteuersatz)
+    Standard,
+    /// Reduced rate of 7% (ermaessigter Steuersatz)
+    Reduced,
+    /// Zero rate, e.g. for reverse charge invoices (Nullsteuersatz)
+    Zero,
+}
+
+impl VatRate {
+    /// Returns the German name.
+    pub fn german_name(&self) -> &'static str {
+        match self {
+            Self::Standard => "Regelsteuersatz",
+            Self::Reduced => "Ermaessigter Steuersatz",
+            Self::Zero => "Nullsteuersatz",
+        }
+    }
+
+    /// Returns the rate in percent.
+    pub fn percent(&self) -> f64 {
+        match self {
+            Self::Standard => 19.0,
+            Self::Reduced => 7.0,
+            Self::Zero => 0.0,
+        }
+    }
+}
+
+/// Apply a surcharge to `base`.
+///
+/// A [`SurchargeType::Relative`] value is a percentage of the base, an
+/// absolute value (also assumed when the type is missing) is added as is.
+/// Negative values reduce the base. The target and tiers of the surcharge
+/// are not considered; see [`apply_surcharge_to`]. A missing base value
+/// counts as zero, so a percentage surcharge on it yields zero.
+///
+/// Fails if an absolute surcharge is in a different currency than the base.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::calc::apply_surcharge;
+/// use bo4e_core::com::{Amount, Surcharge};
+/// use bo4e_core::enums::SurchargeType;
+///
+/// let surcharge = Surcharge {
+///     surcharge_type: Some(SurchargeType::Relative),
+///     value: Some(25.0),
+///     ..Default::default()
+/// };
+/// assert_eq!(apply_surcharge(&Amount::eur(200.0), &surcharge).unwrap().value, Some(250.0));
+/// ```
+pub fn apply_surcharge(base: &Amount, surcharge: &Surcharge) -> Result<Amount, CurrencyMismatch> {
+    adjust(
+        base,
+        surcharge.surcharge_type,
+        surcharge.value.unwrap_or(0.0),
+        surcharge.currency,
+    )
+}
+
+/// Apply a surcharge to `base` if it targets the price component `price`.
+///
+/// Surcharges without a target apply to every price. Otherwise `base` is
+/// returned unchanged unless the target [covers](SurchargeTarget::covers)
+/// `price`.
+pub fn apply_surcharge_to(
+    base: &Amount,
+    surcharge: &Surcharge,
+    price: SurchargeTarget,
+) -> Result<Amount, CurrencyMismatch> {
+    match surcharge.target {
+        Some(target) if !target.covers(price) => Ok(base.clone()),
+        _ => apply_surcharge(base, surcharge),
+    }
+}
+
+/// Apply a discount to `base`.
+///
+/// The discount value is the reduction: a percentage of the base for
+/// [`SurchargeType::Relative`], otherwise an absolute amount. The result
+/// is not clamped and may become negative. Fails if an absolute discount is
+/// in a different currency than the base.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::calc::apply_discount;
+/// use bo4e_core::com::{Amount, Discount};
+/// use bo4e_core::enums::SurchargeType;
+///
+/// let discount = Discount {
+///     discount_type: Some(SurchargeType::Absolute),
+///     value: Some(5.0),
+///     ..Default::default()
+/// };
+/// assert_eq!(apply_discount(&Amount::eur(30.0), &discount).unwrap().value, Some(25.0));
+/// ```
+pub fn apply_discount(base: &Amount, discount: &Discount) -> Result<Amount, CurrencyMismatch> {
+    adjust(
+        base,
+        discount.discount_type,
+        -discount.value.unwrap_or(0.0),
+        discount.currency,
+    )
+}
+
+/// Add `value` (a percentage for relative adjustments) to `base`.
+///
+/// The currency of an absolute `value` must match the base currency if
+/// both are set; a percentage has no currency to compare.
+fn adjust(
+    base: &Amount,
+    kind: Option<SurchargeType>,
+    value: f64,
+    currency: Option<Currency>,
+) -> Result<Amount, CurrencyMismatch> {
+    let amount = base.value.unwrap_or(0.0);
+    let adjusted = match kind {
+        Some(SurchargeType::Relative) => amount * (1.0 + value / 100.0),
+        _ => match (base.currency, currency) {
+            (Some(expected), Some(found)) if expected != found => {
+                return Err(CurrencyMismatch { expected, found });
+            }
+            _ => amount + value,
+        },
+    };
+    Ok(Amount {
+        value: Some(adjusted),
+        currency: base.currency.or(currency),
+        ..Default::default()
+    })
+}
+
+#[derive(Clone, Copy)]
+enum Rule {
+    HalfUp,
+    HalfEven,
+    Floor,
+    Ceiling,
+}
+
+fn round_decimal(value: f64, decimals: u32, rule: Rule) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+
+    let repr = value.abs().to_string();
+    let (int_part, frac_part) = repr.split_once('.').unwrap_or((&repr, ""));
+    let decimals = decimals as usize;
+    if frac_part.len() <= decimals {
+        return value;
+    }
+
+    let negative = value.is_sign_negative();
+    let (kept_frac, dropped) = frac_part.split_at(decimals);
+    let mut digits: Vec<u8> = int_part
+        .bytes()
+        .chain(kept_frac.bytes())
+        .map(|b| b - b'0')
+        .collect();
+
+    let first_dropped = dropped.as_bytes()[0] - b'0';
+    let rest_nonzero = dropped.bytes().skip(1).any(|b| b != b'0');
+    let any_dropped = first_dropped != 0 || rest_nonzero;
+    let last_kept_odd = digits.last().is_some_and(|d| d % 2 == 1);
+
+    let increment = match rule {
+        Rule::HalfUp => first_dropped >= 5,
+        Rule::HalfEven => {
+            first_dropped > 5 || (first_dropped == 5 && (rest_nonzero || last_kept_odd))
+        }
+        Rule::Floor => negative && any_dropped,
+        Rule::Ceiling => !negative && any_dropped,
+    };
+
+    if increment {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == 9 {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, 1);
+        }
+    }
+
+    let split = digits.len() - decimals;
+    let mut rounded = String::with_capacity(digits.len() + 2);
+    if negative {
+        rounded.push('-');
+    }
+    rounded.extend(digits[..split].iter().map(|d| char::from(b'0' + d)));
+    if decimals > 0 {
+        rounded.push('.');
+        rounded.extend(digits[split..].iter().map(|d| char::from(b'0' + d)));
+    }
+    rounded.parse().unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vat_rate_percent() {
+        assert_eq!(VatRate::Standard.percent(), 19.0);
+        assert_eq!(VatRate::Reduced.percent(), 7.0);
+        assert_eq!(VatRate::Zero.percent(), 0.0);
+    }
+
+    #[test]
+    fn test_half_up_vs_half_even() {
+        // 2.675 is stored as 2.67499999...; naive `(x * 100.0).round()` yields 2.67
+        assert_eq!(round_money(2.675, 2, RoundingMode::Commercial), 2.68);
+        assert_eq!(round_half_even(2.675, 2), 2.68);
+
+        // A tie on an even digit separates the two modes
+        assert_eq!(round_money(2.665, 2, RoundingMode::Commercial), 2.67);
+        assert_eq!(round_half_even(2.665, 2), 2.66);
+    }
+
+    #[test]
+    fn test_floor_and_ceiling() {
+        assert_eq!(round_money(2.67, 2, RoundingMode::Floor), 2.67);
+        assert_eq!(round_money(2.671, 2, RoundingMode::Floor), 2.67);
+        assert_eq!(round_money(2.671, 2, RoundingMode::Ceiling), 2.68);
+        assert_eq!(round_money(-2.671, 2, RoundingMode::Floor), -2.68);
+        assert_eq!(round_money(-2.671, 2, RoundingMode::Ceiling), -2.67);
+    }
+
+    #[test]
+    fn test_negative_and_carry() {
+        assert_eq!(round_money(-1.005, 2, RoundingMode::Commercial), -1.01);
+        assert_eq!(round_money(9.995, 2, RoundingMode::Commercial), 10.0);
+        assert_eq!(round_money(0.5, 0, RoundingMode::Commercial), 1.0);
+        assert_eq!(round_half_even(0.5, 0), 0.0);
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(apply(ArithmeticOperation::Addition, 2.0, 3.0), Some(5.0));
+        assert_eq!(
+            apply(ArithmeticOperation::Subtraction, 2.0, 3.0),
+            Some(-1.0)
+        );
+        assert_eq!(
+            apply(ArithmeticOperation::Multiplication, 2.0, 3.0),
+            Some(6.0)
+        );
+        assert_eq!(apply(ArithmeticOperation::Division, 3.0, 2.0), Some(1.5));
+    }
+
+    #[test]
+    fn test_apply_surcharge() {
+        let relative = Surcharge {
+            surcharge_type: Some(SurchargeType::Relative),
+            value: Some(10.0),
+            ..Default::default()
+        };
+        let result = apply_surcharge(&Amount::eur(50.0), &relative).unwrap();
+        assert!((result.value.unwrap() - 55.0).abs() < 1e-9);
+        assert_eq!(result.currency, Some(Currency::Eur));
+
+        // A percentage of nothing stays nothing
+        assert_eq!(
+            apply_surcharge(&Amount::eur(0.0), &relative).unwrap().value,
+            Some(0.0)
+        );
+        assert_eq!(
+            apply_surcharge(&Amount::default(), &relative)
+                .unwrap()
+                .value,
+            Some(0.0)
+        );
+
+        let absolute = Surcharge {
+            surcharge_type: Some(SurchargeType::Absolute),
+            value: Some(2.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply_surcharge(&Amount::eur(0.0), &absolute).unwrap().value,
+            Some(2.5)
+        );
+    }
+
+    #[test]
+    fn test_apply_surcharge_to_target() {
+        let surcharge = Surcharge {
+            surcharge_type: Some(SurchargeType::Absolute),
+            value: Some(1.0),
+            target: Some(SurchargeTarget::WorkingPriceHTNT),
+            ..Default::default()
+        };
+        let base = Amount::eur(10.0);
+        assert_eq!(
+            apply_surcharge_to(&base, &surcharge, SurchargeTarget::WorkingPriceNT)
+                .unwrap()
+                .value,
+            Some(11.0)
+        );
+        assert_eq!(
+            apply_surcharge_to(&base, &surcharge, SurchargeTarget::BasePrice).unwrap(),
+            base
+        );
+    }
+
+    #[test]
+    fn test_apply_discount() {
+        let fixed = Discount {
+            discount_type: Some(SurchargeType::Absolute),
+            value: Some(5.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply_discount(&Amount::eur(20.0), &fixed).unwrap().value,
+            Some(15.0)
+        );
+
+        let relative = Discount {
+            discount_type: Some(SurchargeType::Relative),
+            value: Some(25.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply_discount(&Amount::eur(20.0), &relative).unwrap().value,
+            Some(15.0)
+        );
+    }
+
+    #[test]
+    fn test_absolute_adjustment_currency_mismatch() {
+        let surcharge = Surcharge {
+            surcharge_type: Some(SurchargeType::Absolute),
+            value: Some(2.0),
+            currency: Some(Currency::Usd),
+            ..Default::default()
+        };
+        assert_eq!(
+            apply_surcharge(&Amount::eur(10.0), &surcharge),
+            Err(CurrencyMismatch {
+                expected: Currency::Eur,
+                found: Currency::Usd,
+            })
+        );
+
+        let discount = Discount {
+            value: Some(1.0),
+            currency: Some(Currency::Usd),
+            ..Default::default()
+        };
+        assert!(apply_discount(&Amount::eur(10.0), &discount).is_err());
+
+        // Percentages apply regardless of the currency they were given in
+        let relative = Surcharge {
+            surcharge_type: Some(SurchargeType::Relative),
+            ..surcharge
+        };
+        assert!(apply_surcharge(&Amount::eur(10.0), &relative).is_ok());
+    }
+
+    #[test]
+    fn test_apply_divide_by_zero() {
+        assert_eq!(apply(ArithmeticOperation::Division, 3.0, 0.0), None);
+        assert_eq!(apply(ArithmeticOperation::Division, 0.0, -0.0), None);
+    }
+
+    #[test]
+    fn test_no_rounding() {
+        assert_eq!(round_money(2.675, 2, RoundingMode::None), 2.675);
+        assert_eq!(round_money(1.5, 3, RoundingMode::Commercial), 1.5);
+        assert!(round_money(f64::NAN, 2, RoundingMode::Commercial).is_nan());
+    }
+}
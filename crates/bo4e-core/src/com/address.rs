@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::enums::Country;
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{finish, Validate, ValidationIssue};
 
 /// Physical or postal address.
 ///
@@ -21,9 +22,19 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 ///     city: Some("Köln".to_string()),
 ///     ..Default::default()
 /// };
+///
+/// // or, equivalently
+/// let built = Address::builder()
+///     .street("Musterstraße")
+///     .house_number("42")
+///     .postal_code("50667")
+///     .city("Köln")
+///     .build();
+/// assert_eq!(address, built);
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Adresse"))]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
@@ -32,47 +43,48 @@ pub struct Address {
     pub meta: Bo4eMeta,
 
     /// Street name (Strasse)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "strasse")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "strasse")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "strasse"))]
     pub street: Option<String>,
 
     /// House number (Hausnummer)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "hausnummer")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "hausnummer")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hausnummer"))]
     pub house_number: Option<String>,
 
     /// Postal code (Postleitzahl)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "postleitzahl")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "postleitzahl")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "postleitzahl"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::postal_code))]
     pub postal_code: Option<String>,
 
     /// City/town (Ort)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "ort")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "ort")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ort"))]
     pub city: Option<String>,
 
     /// District (Ortsteil)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "ortsteil")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "ortsteil")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ortsteil"))]
     pub district: Option<String>,
 
     /// PO Box number (Postfach)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "postfach")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "postfach")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "postfach"))]
     pub po_box: Option<String>,
 
     /// Address addition/note (Adresszusatz)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "adresszusatz")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "adresszusatz")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "adresszusatz"))]
     pub address_addition: Option<String>,
 
     /// Co-location info - c/o address (CoErgaenzung)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "coErgaenzung")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "coErgaenzung")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "coErgaenzung"))]
     pub co_ergaenzung: Option<String>,
 
     /// Country code (Landescode)
-    #[serde(skip_serializing_if = "Option::is_none", alias = "landescode")]
+    #[serde(skip_serializing_if = "crate::ser::is_none", alias = "landescode")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "landescode"))]
     pub country_code: Option<Country>,
 }
@@ -95,6 +107,112 @@ impl Bo4eObject for Address {
     }
 }
 
+impl Address {
+    /// Start building an address.
+    pub fn builder() -> AddressBuilder {
+        AddressBuilder::default()
+    }
+}
+
+/// Builder for [`Address`], created by [`Address::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct AddressBuilder {
+    address: Address,
+}
+
+impl AddressBuilder {
+    /// Set the street name.
+    pub fn street(mut self, street: impl Into<String>) -> Self {
+        self.address.street = Some(street.into());
+        self
+    }
+
+    /// Set the house number.
+    pub fn house_number(mut self, house_number: impl Into<String>) -> Self {
+        self.address.house_number = Some(house_number.into());
+        self
+    }
+
+    /// Set the postal code.
+    pub fn postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.address.postal_code = Some(postal_code.into());
+        self
+    }
+
+    /// Set the city.
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.address.city = Some(city.into());
+        self
+    }
+
+    /// Set the district.
+    pub fn district(mut self, district: impl Into<String>) -> Self {
+        self.address.district = Some(district.into());
+        self
+    }
+
+    /// Set the PO box number.
+    pub fn po_box(mut self, po_box: impl Into<String>) -> Self {
+        self.address.po_box = Some(po_box.into());
+        self
+    }
+
+    /// Set the address addition.
+    pub fn address_addition(mut self, addition: impl Into<String>) -> Self {
+        self.address.address_addition = Some(addition.into());
+        self
+    }
+
+    /// Set the c/o line.
+    pub fn co_ergaenzung(mut self, co: impl Into<String>) -> Self {
+        self.address.co_ergaenzung = Some(co.into());
+        self
+    }
+
+    /// Set the country.
+    pub fn country_code(mut self, country: Country) -> Self {
+        self.address.country_code = Some(country);
+        self
+    }
+
+    /// Finish building. The result is not validated; see [`Validate`].
+    pub fn build(self) -> Address {
+        self.address
+    }
+}
+
+impl Validate for Address {
+    /// Requires either a street or a PO box but not both, a house number
+    /// only together with a street, and postal code and city together.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        if self.street.is_some() && self.po_box.is_some() {
+            issues.push(ValidationIssue::new(
+                "po_box",
+                "a PO box cannot be combined with a street",
+            ));
+        }
+        if self.house_number.is_some() && self.street.is_none() {
+            issues.push(ValidationIssue::new(
+                "house_number",
+                "a house number requires a street",
+            ));
+        }
+        match (&self.postal_code, &self.city) {
+            (Some(_), None) => issues.push(ValidationIssue::new(
+                "city",
+                "required together with postal_code",
+            )),
+            (None, Some(_)) => issues.push(ValidationIssue::new(
+                "postal_code",
+                "required together with city",
+            )),
+            _ => {}
+        }
+        finish(issues)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,4 +294,39 @@ mod tests {
         assert_eq!(Address::type_name_german(), "Adresse");
         assert_eq!(Address::type_name_english(), "Address");
     }
+
+    #[test]
+    fn test_validate_street_address() {
+        let address = Address::builder()
+            .street("Hauptstraße")
+            .house_number("1")
+            .postal_code("10115")
+            .city("Berlin")
+            .country_code(Country::Germany)
+            .build();
+        assert_eq!(address.street, Some("Hauptstraße".to_string()));
+        assert!(address.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_po_box() {
+        let address = Address::builder()
+            .po_box("10 11 12")
+            .postal_code("50452")
+            .city("Köln")
+            .build();
+        assert!(address.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_street_and_po_box() {
+        let address = Address::builder()
+            .street("Hauptstraße")
+            .po_box("10 11 12")
+            .postal_code("50452")
+            .build();
+        let issues = address.validate().unwrap_err();
+        let paths: Vec<&str> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, vec!["po_box", "city"]);
+    }
 }
@@ -3,9 +3,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::enums::Unit;
+use crate::enums::{AggregationMethod, MeasuredValueStatus, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
+use super::MeasuredValue;
+
 /// An aggregated value computed from multiple source values.
 ///
 /// German: Aggregiertwert
@@ -35,42 +37,50 @@ pub struct AggregatedValue {
     pub meta: Bo4eMeta,
 
     /// Timestamp for the aggregated value (Zeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitpunkt"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// The aggregated value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of the value (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
+    /// Worst status among the source values
+    ///
+    /// Not part of BO4E: set by [`AggregatedValue::aggregate`] for callers
+    /// and never serialized, so the JSON output stays BO4E conformant.
+    #[serde(skip)]
+    #[cfg_attr(feature = "json-schema", schemars(skip))]
+    pub status: Option<MeasuredValueStatus>,
+
     /// Aggregation method (Aggregationsmethode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aggregationsmethode"))]
     pub aggregation_method: Option<String>,
 
     /// Period start for aggregation (Periodenbeginn)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "periodenbeginn"))]
     pub period_start: Option<DateTime<Utc>>,
 
     /// Period end for aggregation (Periodenende)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "periodenende"))]
     pub period_end: Option<DateTime<Utc>>,
 
     /// Number of source values aggregated (Anzahl Quellwerte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlQuellwerte"))]
     pub source_count: Option<i32>,
 
     /// OBIS code (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 }
@@ -93,6 +103,71 @@ impl Bo4eObject for AggregatedValue {
     }
 }
 
+impl AggregatedValue {
+    /// Aggregate measured values with `method`.
+    ///
+    /// Values without a number are ignored; `source_count` is the number of
+    /// values used. Values are converted to the unit of the first one with
+    /// [`Unit::convert`]; if a unit cannot be converted, or only some values
+    /// have a unit, the result has no unit and, except for a count, no
+    /// value. The OBIS code is carried over if all values agree on it, the
+    /// status is the worst one by
+    /// [`MeasuredValueStatus::quality_rank`], and the period spans the
+    /// earliest to the latest timestamp.
+    ///
+    /// For an empty input, sum and count are zero while average, minimum and
+    /// maximum have no value.
+    pub fn aggregate(values: &[MeasuredValue], method: AggregationMethod) -> Self {
+        let used: Vec<&MeasuredValue> = values.iter().filter(|v| v.value.is_some()).collect();
+        let unit = used.iter().find_map(|v| v.unit);
+        let numbers: Option<Vec<f64>> = used
+            .iter()
+            .map(|v| match (v.unit, unit) {
+                (Some(from), Some(to)) => Unit::convert(v.value?, from, to),
+                (None, None) => v.value,
+                _ => None,
+            })
+            .collect();
+        let unit = unit.filter(|_| numbers.is_some());
+
+        let value = match (method, numbers) {
+            (AggregationMethod::Count, _) => Some(used.len() as f64),
+            (_, None) => None,
+            (AggregationMethod::Sum, Some(numbers)) => Some(numbers.iter().sum()),
+            (AggregationMethod::Average, Some(numbers)) if numbers.is_empty() => None,
+            (AggregationMethod::Average, Some(numbers)) => {
+                Some(numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+            (AggregationMethod::Min, Some(numbers)) => numbers.into_iter().reduce(f64::min),
+            (AggregationMethod::Max, Some(numbers)) => numbers.into_iter().reduce(f64::max),
+        };
+
+        let timestamps = used.iter().filter_map(|v| v.timestamp);
+        Self {
+            value,
+            unit,
+            status: used
+                .iter()
+                .filter_map(|v| v.status)
+                .max_by_key(MeasuredValueStatus::quality_rank),
+            aggregation_method: Some(method.code().to_string()),
+            period_start: timestamps.clone().min(),
+            period_end: timestamps.max(),
+            source_count: Some(used.len() as i32),
+            obis_code: common(used.iter().map(|v| v.obis_code.clone())),
+            ..Default::default()
+        }
+    }
+}
+
+/// The value shared by all items, or `None` if they differ or are all unset.
+fn common<T: PartialEq>(mut items: impl Iterator<Item = Option<T>>) -> Option<T> {
+    let first = items.next()??;
+    items
+        .all(|item| item.as_ref() == Some(&first))
+        .then_some(first)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +208,85 @@ mod tests {
         assert!(json.contains("2976"));
     }
 
+    fn reading(hour: u32, value: f64, status: MeasuredValueStatus) -> MeasuredValue {
+        MeasuredValue {
+            timestamp: Some(Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap()),
+            value: Some(value),
+            unit: Some(Unit::KilowattHour),
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_aggregate_sum_and_average() {
+        let values = vec![
+            reading(0, 1.5, MeasuredValueStatus::Read),
+            reading(1, 2.5, MeasuredValueStatus::Substitute),
+            reading(2, 5.0, MeasuredValueStatus::Preliminary),
+            MeasuredValue::default(),
+        ];
+
+        let sum = AggregatedValue::aggregate(&values, AggregationMethod::Sum);
+        assert_eq!(sum.value, Some(9.0));
+        assert_eq!(sum.unit, Some(Unit::KilowattHour));
+        assert_eq!(sum.status, Some(MeasuredValueStatus::Substitute));
+        assert!(serde_json::to_value(&sum).unwrap().get("status").is_none());
+        assert_eq!(sum.source_count, Some(3));
+        assert_eq!(sum.aggregation_method, Some("SUM".to_string()));
+        assert_eq!(sum.period_start, values[0].timestamp);
+        assert_eq!(sum.period_end, values[2].timestamp);
+
+        let average = AggregatedValue::aggregate(&values, AggregationMethod::Average);
+        assert_eq!(average.value, Some(3.0));
+
+        let min = AggregatedValue::aggregate(&values, AggregationMethod::Min);
+        let max = AggregatedValue::aggregate(&values, AggregationMethod::Max);
+        assert_eq!((min.value, max.value), (Some(1.5), Some(5.0)));
+        assert_eq!(
+            AggregatedValue::aggregate(&values, AggregationMethod::Count).value,
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_empty_and_mixed_units() {
+        let sum = AggregatedValue::aggregate(&[], AggregationMethod::Sum);
+        assert_eq!(sum.value, Some(0.0));
+        assert_eq!(sum.source_count, Some(0));
+        assert_eq!(sum.unit, None);
+        assert_eq!(
+            AggregatedValue::aggregate(&[], AggregationMethod::Average).value,
+            None
+        );
+        assert_eq!(
+            AggregatedValue::aggregate(&[], AggregationMethod::Max).value,
+            None
+        );
+
+        let mut mixed = vec![reading(0, 1.0, MeasuredValueStatus::Read)];
+        mixed.push(MeasuredValue {
+            unit: Some(Unit::MegawattHour),
+            ..reading(1, 1.0, MeasuredValueStatus::Read)
+        });
+        let sum = AggregatedValue::aggregate(&mixed, AggregationMethod::Sum);
+        assert_eq!(sum.value, Some(1001.0));
+        assert_eq!(sum.unit, Some(Unit::KilowattHour));
+
+        mixed[1].unit = Some(Unit::Kilowatt);
+        let sum = AggregatedValue::aggregate(&mixed, AggregationMethod::Sum);
+        assert_eq!(sum.value, None);
+        assert_eq!(sum.unit, None);
+        let count = AggregatedValue::aggregate(&mixed, AggregationMethod::Count);
+        assert_eq!(count.value, Some(2.0));
+
+        mixed[1].unit = None;
+        assert_eq!(
+            AggregatedValue::aggregate(&mixed, AggregationMethod::Max).value,
+            None
+        );
+    }
+
     #[test]
     fn test_roundtrip() {
         let value = AggregatedValue {
@@ -31,12 +31,12 @@ pub struct Amount {
     pub meta: Bo4eMeta,
 
     /// The amount value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Currency (Waehrung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "waehrung"))]
     pub currency: Option<Currency>,
 }
@@ -68,8 +68,56 @@ impl Amount {
             ..Default::default()
         }
     }
+
+    /// Add up amounts that all share the same currency.
+    ///
+    /// Missing values count as zero and amounts without a currency are
+    /// accepted alongside any currency. The result carries the common
+    /// currency, if any.
+    pub fn sum<'a>(
+        amounts: impl IntoIterator<Item = &'a Amount>,
+    ) -> Result<Amount, CurrencyMismatch> {
+        let mut currency: Option<Currency> = None;
+        let mut total = 0.0;
+        for amount in amounts {
+            match (currency, amount.currency) {
+                (Some(expected), Some(found)) if expected != found => {
+                    return Err(CurrencyMismatch { expected, found });
+                }
+                (None, Some(found)) => currency = Some(found),
+                _ => {}
+            }
+            total += amount.value.unwrap_or(0.0);
+        }
+        Ok(Amount {
+            value: Some(total),
+            currency,
+            ..Default::default()
+        })
+    }
+}
+
+/// Error returned when amounts in different currencies are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyMismatch {
+    /// Currency of the first amount
+    pub expected: Currency,
+    /// Conflicting currency that was encountered
+    pub found: Currency,
 }
 
+impl std::fmt::Display for CurrencyMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "currency mismatch: expected {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for CurrencyMismatch {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,6 +3,7 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::com::DateRange;
 use crate::enums::Unit;
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -35,47 +36,47 @@ pub struct BillingPeriodData {
     pub meta: Bo4eMeta,
 
     /// Start of billing period (Abrechnungsbeginn)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungsbeginn"))]
     pub period_start: Option<NaiveDate>,
 
     /// End of billing period (Abrechnungsende)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abrechnungsende"))]
     pub period_end: Option<NaiveDate>,
 
     /// Starting meter reading (Anfangsstand)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anfangsstand"))]
     pub start_reading: Option<f64>,
 
     /// Starting reading timestamp (Anfangsablesung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anfangsablesung"))]
     pub start_reading_timestamp: Option<DateTime<Utc>>,
 
     /// Ending meter reading (Endstand)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "endstand"))]
     pub end_reading: Option<f64>,
 
     /// Ending reading timestamp (Endablesung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "endablesung"))]
     pub end_reading_timestamp: Option<DateTime<Utc>>,
 
     /// Consumption value for the period (Verbrauchswert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "verbrauchswert"))]
     pub consumption_value: Option<f64>,
 
     /// Unit of consumption (Verbrauchseinheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "verbrauchseinheit"))]
     pub consumption_unit: Option<Unit>,
 
     /// Number of days in period (Anzahl Tage)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlTage"))]
     pub days_in_period: Option<i32>,
 }
@@ -98,6 +99,25 @@ impl Bo4eObject for BillingPeriodData {
     }
 }
 
+impl BillingPeriodData {
+    /// Fraction of the billing period covered by an actual supply window.
+    ///
+    /// Both ranges count their start and end day. Parts of the window that
+    /// lie outside the billing period are ignored, so the result is always
+    /// between 0.0 and 1.0. Returns 0.0 if the period is not fully defined.
+    pub fn prorate(&self, actual_start: NaiveDate, actual_end: NaiveDate) -> f64 {
+        let (Some(start), Some(end)) = (self.period_start, self.period_end) else {
+            return 0.0;
+        };
+        let period_days = DateRange::new(start, end).days();
+        if period_days == 0 {
+            return 0.0;
+        }
+        let covered = DateRange::new(actual_start.max(start), actual_end.min(end)).days();
+        covered as f64 / period_days as f64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +161,25 @@ mod tests {
         );
         assert_eq!(BillingPeriodData::type_name_english(), "BillingPeriodData");
     }
+
+    #[test]
+    fn test_prorate_half_month() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let april = BillingPeriodData {
+            period_start: Some(date(4, 1)),
+            period_end: Some(date(4, 30)),
+            ..Default::default()
+        };
+
+        // Supply starts mid-month and continues past the period end
+        let fraction = april.prorate(date(4, 16), date(5, 10));
+        assert!((fraction - 0.5).abs() < 1e-9);
+
+        assert_eq!(april.prorate(date(3, 1), date(6, 30)), 1.0);
+        assert_eq!(april.prorate(date(5, 1), date(5, 31)), 0.0);
+        assert_eq!(
+            BillingPeriodData::default().prorate(date(4, 1), date(4, 30)),
+            0.0
+        );
+    }
 }
@@ -31,12 +31,12 @@ pub struct CadastralAddress {
     pub meta: Bo4eMeta,
 
     /// Cadastral district and parcel (Gemarkung/Flur)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gemarkungFlur"))]
     pub gemarkung_flur: Option<String>,
 
     /// Plot/parcel number (Flurstück)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "flurstueck"))]
     pub flurstueck: Option<String>,
 }
@@ -59,6 +59,88 @@ impl Bo4eObject for CadastralAddress {
     }
 }
 
+impl CadastralAddress {
+    /// Parse a parcel identifier in the `Gemarkung-Flur-Flurstück` format.
+    ///
+    /// Gemarkung and Flur must be numeric; the Flurstück is a numeric
+    /// counter with an optional `/`-separated denominator. Gemarkung and
+    /// Flur are stored together in `gemarkung_flur`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bo4e_core::com::CadastralAddress;
+    ///
+    /// let parcel = CadastralAddress::parse_parcel("012345-001-00042/3").unwrap();
+    /// assert_eq!(parcel.gemarkung_flur.as_deref(), Some("012345-001"));
+    /// assert_eq!(parcel.flurstueck.as_deref(), Some("00042/3"));
+    /// ```
+    pub fn parse_parcel(s: &str) -> Result<Self, ParcelError> {
+        let error = || ParcelError {
+            input: s.to_string(),
+        };
+        let mut parts = s.trim().split('-');
+        let (Some(gemarkung), Some(flur), Some(flurstueck), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error());
+        };
+        if !is_number(gemarkung) || !is_number(flur) || !is_parcel_number(flurstueck) {
+            return Err(error());
+        }
+        Ok(Self {
+            gemarkung_flur: Some(format!("{}-{}", gemarkung, flur)),
+            flurstueck: Some(flurstueck.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Reconstruct the `Gemarkung-Flur-Flurstück` identifier.
+    ///
+    /// Returns `None` unless both fields are set and `gemarkung_flur` has
+    /// the numeric `Gemarkung-Flur` form produced by [`parse_parcel`](Self::parse_parcel).
+    pub fn parcel_identifier(&self) -> Option<String> {
+        let gemarkung_flur = self.gemarkung_flur.as_deref()?;
+        let flurstueck = self.flurstueck.as_deref()?;
+        let (gemarkung, flur) = gemarkung_flur.split_once('-')?;
+        if !is_number(gemarkung) || !is_number(flur) || !is_parcel_number(flurstueck) {
+            return None;
+        }
+        Some(format!("{}-{}", gemarkung_flur, flurstueck))
+    }
+}
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Counter with optional denominator, e.g. `42` or `00042/3`.
+fn is_parcel_number(s: &str) -> bool {
+    match s.split_once('/') {
+        Some((counter, denominator)) => is_number(counter) && is_number(denominator),
+        None => is_number(s),
+    }
+}
+
+/// Error returned by [`CadastralAddress::parse_parcel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParcelError {
+    /// The rejected input
+    pub input: String,
+}
+
+impl std::fmt::Display for ParcelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid parcel identifier {:?}, expected Gemarkung-Flur-Flurstück",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParcelError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +183,43 @@ mod tests {
         assert_eq!(CadastralAddress::type_name_german(), "Katasteradresse");
         assert_eq!(CadastralAddress::type_name_english(), "CadastralAddress");
     }
+
+    #[test]
+    fn test_parse_parcel_roundtrip() {
+        let parcel = CadastralAddress::parse_parcel("012345-001-00042/3").unwrap();
+        assert_eq!(parcel.gemarkung_flur, Some("012345-001".to_string()));
+        assert_eq!(parcel.flurstueck, Some("00042/3".to_string()));
+        assert_eq!(
+            parcel.parcel_identifier(),
+            Some("012345-001-00042/3".to_string())
+        );
+
+        let parcel = CadastralAddress::parse_parcel("4711-12-815").unwrap();
+        assert_eq!(parcel.parcel_identifier(), Some("4711-12-815".to_string()));
+    }
+
+    #[test]
+    fn test_parse_parcel_invalid() {
+        for input in [
+            "",
+            "012345-001",
+            "012345-001-42/3-1",
+            "01234A-001-42",
+            "012345-001-42/",
+        ] {
+            assert_eq!(
+                CadastralAddress::parse_parcel(input),
+                Err(ParcelError {
+                    input: input.to_string()
+                })
+            );
+        }
+
+        let free_text = CadastralAddress {
+            gemarkung_flur: Some("Flur 5".to_string()),
+            flurstueck: Some("789/10".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(free_text.parcel_identifier(), None);
+    }
 }
@@ -1,8 +1,11 @@
 //! Consumption (Verbrauch) component.
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::com::ConsumedQuantity;
 use crate::enums::{MeasuredValueStatus, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -33,32 +36,32 @@ pub struct Consumption {
     pub meta: Bo4eMeta,
 
     /// Consumption value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Start date of consumption period inclusive (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start_date: Option<DateTime<Utc>>,
 
     /// End date of consumption period exclusive (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end_date: Option<DateTime<Utc>>,
 
     /// OBIS code identifying the measured value (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 
     /// Status of the measured value (Messwertstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "messwertstatus"))]
     pub measured_value_status: Option<MeasuredValueStatus>,
 }
@@ -81,6 +84,71 @@ impl Bo4eObject for Consumption {
     }
 }
 
+impl Consumption {
+    /// Sum consumed quantities per unit.
+    ///
+    /// Quantities without a value or without a unit are skipped, since they
+    /// cannot be attributed to any total.
+    pub fn total_by_unit(quantities: &[ConsumedQuantity]) -> HashMap<Unit, f64> {
+        let mut totals = HashMap::new();
+        for quantity in quantities {
+            if let (Some(value), Some(unit)) = (quantity.value, quantity.unit) {
+                *totals.entry(unit).or_insert(0.0) += value;
+            }
+        }
+        totals
+    }
+
+    /// Sum consumed quantities that all share the same unit.
+    ///
+    /// Returns an error instead of silently adding up values of different
+    /// units (e.g. kWh and m³). Like [`Self::total_by_unit`], quantities
+    /// without a unit are skipped, so both agree on the same data.
+    pub fn sum(quantities: &[ConsumedQuantity]) -> Result<ConsumedQuantity, UnitMismatch> {
+        let mut unit: Option<Unit> = None;
+        let mut total = 0.0;
+        for quantity in quantities {
+            let Some(found) = quantity.unit else {
+                continue;
+            };
+            match unit {
+                Some(expected) if expected != found => {
+                    return Err(UnitMismatch { expected, found });
+                }
+                None => unit = Some(found),
+                _ => {}
+            }
+            total += quantity.value.unwrap_or(0.0);
+        }
+        Ok(ConsumedQuantity {
+            value: Some(total),
+            unit,
+            ..Default::default()
+        })
+    }
+}
+
+/// Error returned when quantities with different units are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitMismatch {
+    /// Unit of the first quantity
+    pub expected: Unit,
+    /// Conflicting unit that was encountered
+    pub found: Unit,
+}
+
+impl std::fmt::Display for UnitMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unit mismatch: expected {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for UnitMismatch {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +231,59 @@ mod tests {
         assert_eq!(Consumption::type_name_german(), "Verbrauch");
         assert_eq!(Consumption::type_name_english(), "Consumption");
     }
+
+    #[test]
+    fn test_total_by_unit_same_unit() {
+        let quantities = vec![
+            ConsumedQuantity::kwh(100.0),
+            ConsumedQuantity::kwh(200.0),
+            ConsumedQuantity::kwh(300.5),
+        ];
+
+        let totals = Consumption::total_by_unit(&quantities);
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals[&Unit::KilowattHour], 600.5);
+
+        let sum = Consumption::sum(&quantities).unwrap();
+        assert_eq!(sum.value, Some(600.5));
+        assert_eq!(sum.unit, Some(Unit::KilowattHour));
+    }
+
+    #[test]
+    fn test_total_by_unit_mixed_units() {
+        let quantities = vec![
+            ConsumedQuantity::kwh(100.0),
+            ConsumedQuantity::cubic_meters(50.0),
+            ConsumedQuantity::kwh(25.0),
+        ];
+
+        let totals = Consumption::total_by_unit(&quantities);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&Unit::KilowattHour], 125.0);
+        assert_eq!(totals[&Unit::CubicMeter], 50.0);
+
+        let err = Consumption::sum(&quantities).unwrap_err();
+        assert_eq!(err.expected, Unit::KilowattHour);
+        assert_eq!(err.found, Unit::CubicMeter);
+    }
+
+    #[test]
+    fn test_sum_skips_quantities_without_unit() {
+        let quantities = vec![
+            ConsumedQuantity::kwh(100.0),
+            ConsumedQuantity {
+                value: Some(40.0),
+                ..Default::default()
+            },
+        ];
+
+        let sum = Consumption::sum(&quantities).unwrap();
+        assert_eq!(sum.value, Some(100.0));
+        assert_eq!(
+            sum.value,
+            Consumption::total_by_unit(&quantities)
+                .get(&Unit::KilowattHour)
+                .copied()
+        );
+    }
 }
@@ -34,22 +34,22 @@ pub struct ContactMethod {
     pub meta: Bo4eMeta,
 
     /// Type of contact (Kontaktart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kontaktart"))]
     pub contact_type: Option<ContactType>,
 
     /// Contact value - phone number, email address, etc. (Kontaktwert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kontaktwert"))]
     pub contact_value: Option<String>,
 
     /// Description/specification, e.g., "direct line", "switchboard" (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Whether this is the preferred contact method (IstBevorzugterKontaktweg)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "istBevorzugterKontaktweg"))]
     pub is_preferred: Option<bool>,
 }
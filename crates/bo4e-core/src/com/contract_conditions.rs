@@ -29,34 +29,34 @@ pub struct ContractConditions {
     pub meta: Bo4eMeta,
 
     /// Free text description of conditions (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 
     /// Number of agreed installments per year, e.g., 12 (AnzahlAbschlaege)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlAbschlaege"))]
     pub installment_count: Option<i32>,
 
     // Note: The following fields would typically reference Zeitraum COM type.
     // Using simplified string representations for now.
     /// Contract duration (Vertragslaufzeit) - ISO 8601 duration or description
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragslaufzeit"))]
     pub contract_duration: Option<String>,
 
     /// Notice period for termination (Kündigungsfrist)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kuendigungsfrist"))]
     pub notice_period: Option<String>,
 
     /// Automatic extension period if not terminated (Vertragsverlängerung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "vertragsverlaengerung"))]
     pub extension_period: Option<String>,
 
     /// Installment cycle (Abschlagszyklus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abschlagszyklus"))]
     pub installment_cycle: Option<String>,
 }
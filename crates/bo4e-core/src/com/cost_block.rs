@@ -1,11 +1,13 @@
 //! Cost block (Kostenblock) component.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::enums::CostClass;
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
-use super::{Amount, CostPosition};
+use super::{Amount, CostPosition, CurrencyMismatch};
 
 /// A block of costs containing multiple cost positions.
 ///
@@ -32,22 +34,22 @@ pub struct CostBlock {
     pub meta: Bo4eMeta,
 
     /// Name/designation of the cost block (Kostenblockbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenblockbezeichnung"))]
     pub designation: Option<String>,
 
     /// Cost class (Kostenklasse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenklasse"))]
     pub cost_class: Option<CostClass>,
 
     /// Sum of all cost positions in this block (Summe Kostenblock)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "summeKostenblock"))]
     pub total_amount: Option<Amount>,
 
     /// Individual cost positions (Kostenpositionen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenpositionen"))]
     pub positions: Vec<CostPosition>,
 }
@@ -70,10 +72,73 @@ impl Bo4eObject for CostBlock {
     }
 }
 
+impl CostBlock {
+    /// Sum the amounts of all positions.
+    ///
+    /// Positions without an amount are skipped. Fails if the positions use
+    /// different currencies.
+    pub fn total(&self) -> Result<Amount, CurrencyMismatch> {
+        Amount::sum(self.positions.iter().filter_map(|p| p.amount.as_ref()))
+    }
+
+    /// Sum the position amounts of several blocks per cost class.
+    ///
+    /// The cost class is a property of the block, not of its positions, so
+    /// this operates on a list of blocks (e.g. `Costs::cost_blocks`). Blocks
+    /// without a cost class are skipped. Fails if the positions of one class
+    /// use different currencies.
+    pub fn total_by_class(
+        blocks: &[CostBlock],
+    ) -> Result<HashMap<CostClass, Amount>, CurrencyMismatch> {
+        let mut by_class: HashMap<CostClass, Vec<&Amount>> = HashMap::new();
+        for block in blocks {
+            if let Some(class) = block.cost_class {
+                let amounts = block.positions.iter().filter_map(|p| p.amount.as_ref());
+                by_class.entry(class).or_default().extend(amounts);
+            }
+        }
+        by_class
+            .into_iter()
+            .map(|(class, amounts)| Ok((class, Amount::sum(amounts)?)))
+            .collect()
+    }
+
+    /// Label and amount of each block, for cost reports.
+    ///
+    /// A block's stated `total_amount` wins; otherwise the positions are
+    /// summed. Blocks with neither are left out. Unnamed blocks are labelled
+    /// by their position. Fails if the positions of a summed block use
+    /// different currencies.
+    pub fn breakdown(blocks: &[CostBlock]) -> Result<Vec<(String, Amount)>, CurrencyMismatch> {
+        let mut entries = Vec::new();
+        for (index, block) in blocks.iter().enumerate() {
+            let amount = match &block.total_amount {
+                Some(amount) => amount.clone(),
+                None if block.positions.is_empty() => continue,
+                None => block.total()?,
+            };
+            let label = block
+                .designation
+                .clone()
+                .unwrap_or_else(|| format!("cost block {}", index + 1));
+            entries.push((label, amount));
+        }
+        Ok(entries)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::com::Price;
+    use crate::enums::Currency;
+
+    fn position(value: f64) -> CostPosition {
+        CostPosition {
+            amount: Some(Amount::eur(value)),
+            ..Default::default()
+        }
+    }
 
     #[test]
     fn test_cost_block() {
@@ -144,4 +209,96 @@ mod tests {
         assert_eq!(CostBlock::type_name_german(), "Kostenblock");
         assert_eq!(CostBlock::type_name_english(), "CostBlock");
     }
+
+    #[test]
+    fn test_total() {
+        let block = CostBlock {
+            positions: vec![position(120.0), position(80.5), position(49.5)],
+            ..Default::default()
+        };
+        assert_eq!(block.total(), Ok(Amount::eur(250.0)));
+
+        let mixed = CostBlock {
+            positions: vec![
+                position(10.0),
+                CostPosition {
+                    amount: Some(Amount {
+                        value: Some(5.0),
+                        currency: Some(Currency::Chf),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            mixed.total(),
+            Err(CurrencyMismatch {
+                expected: Currency::Eur,
+                found: Currency::Chf,
+            })
+        );
+    }
+
+    #[test]
+    fn test_total_by_class() {
+        let blocks = vec![
+            CostBlock {
+                cost_class: Some(CostClass::ExternalCosts),
+                positions: vec![position(300.0), position(200.0)],
+                ..Default::default()
+            },
+            CostBlock {
+                cost_class: Some(CostClass::Procurement),
+                positions: vec![position(900.0)],
+                ..Default::default()
+            },
+            CostBlock {
+                cost_class: Some(CostClass::ExternalCosts),
+                positions: vec![position(50.0)],
+                ..Default::default()
+            },
+        ];
+
+        let totals = CostBlock::total_by_class(&blocks).unwrap();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&CostClass::ExternalCosts], Amount::eur(550.0));
+        assert_eq!(totals[&CostClass::Procurement], Amount::eur(900.0));
+    }
+
+    #[test]
+    fn test_breakdown_mixed_currencies() {
+        let mut blocks = vec![
+            CostBlock {
+                designation: Some("Energy".to_string()),
+                positions: vec![position(300.0), position(200.0)],
+                ..Default::default()
+            },
+            CostBlock::default(),
+        ];
+        assert_eq!(
+            CostBlock::breakdown(&blocks),
+            Ok(vec![("Energy".to_string(), Amount::eur(500.0))])
+        );
+
+        blocks[1].positions = vec![
+            position(10.0),
+            CostPosition {
+                amount: Some(Amount {
+                    value: Some(5.0),
+                    currency: Some(Currency::Chf),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            CostBlock::breakdown(&blocks),
+            Err(CurrencyMismatch {
+                expected: Currency::Eur,
+                found: Currency::Chf,
+            })
+        );
+    }
 }
@@ -31,12 +31,12 @@ pub struct DateRange {
     pub meta: Bo4eMeta,
 
     /// Start date (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start_date: Option<NaiveDate>,
 
     /// End date (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end_date: Option<NaiveDate>,
 }
@@ -84,6 +84,27 @@ impl DateRange {
         let before_end = self.end_date.map_or(true, |e| date <= e);
         after_start && before_end
     }
+
+    /// Number of days in the range, counting both start and end date.
+    ///
+    /// Returns 0 if either bound is missing or the end lies before the start.
+    pub fn days(&self) -> i64 {
+        match (self.start_date, self.end_date) {
+            (Some(start), Some(end)) if end >= start => (end - start).num_days() + 1,
+            _ => 0,
+        }
+    }
+
+    /// Iterate over every date in the range, start and end included.
+    ///
+    /// Yields nothing if either bound is missing or the range is inverted.
+    pub fn iter_days(&self) -> impl Iterator<Item = NaiveDate> {
+        let start = self.start_date.filter(|_| self.days() > 0);
+        let end = self.end_date;
+        std::iter::successors(start, move |d| {
+            d.succ_opt().filter(|next| Some(*next) <= end)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -115,6 +136,41 @@ mod tests {
 
         let before = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
         assert!(!range.contains(before));
+
+        let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        assert!(range.contains(end));
+        assert!(!range.contains(end.succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn test_iter_days() {
+        let start = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let range = DateRange::new(start, end);
+
+        assert_eq!(range.days(), 3);
+        let days: Vec<NaiveDate> = range.iter_days().collect();
+        assert_eq!(
+            days,
+            vec![start, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), end]
+        );
+        assert_eq!(DateRange::year(2024).days(), 366);
+    }
+
+    #[test]
+    fn test_inverted_range_is_empty() {
+        let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let range = DateRange::new(start, end);
+        assert_eq!(range.days(), 0);
+        assert_eq!(range.iter_days().count(), 0);
+
+        let open_ended = DateRange {
+            start_date: Some(start),
+            ..Default::default()
+        };
+        assert_eq!(open_ended.days(), 0);
+        assert_eq!(open_ended.iter_days().count(), 0);
     }
 
     #[test]
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::enums::{Division, EcoCertificate, EcoLabel};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::{finish, Validate, ValidationIssue};
 
 use super::EnergySource;
 
@@ -46,62 +47,62 @@ pub struct EnergyMix {
     pub meta: Bo4eMeta,
 
     /// Unique identifier for the energy mix (Energiemixnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energiemixnummer"))]
     pub energy_mix_number: Option<i32>,
 
     /// Energy type/division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Name/designation of the energy mix (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub designation: Option<String>,
 
     /// Year for which this mix applies (Gültigkeitsjahr)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitsjahr"))]
     pub valid_year: Option<i32>,
 
     /// Individual energy sources and their shares (Anteil)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anteil"))]
     pub sources: Vec<EnergySource>,
 
     /// Notes about the mix (Bemerkung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bemerkung"))]
     pub notes: Option<String>,
 
     /// CO₂ emissions in g/kWh (CO2-Emission)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "co2Emission"))]
     pub co2_emission: Option<f64>,
 
     /// Nuclear waste in g/kWh (Atommüll)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "atommuell"))]
     pub nuclear_waste: Option<f64>,
 
     /// Environmental certificates (Ökozertifikate)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "oekozertifikate"))]
     pub eco_certificates: Vec<EcoCertificate>,
 
     /// Eco-labels (Ökolabel)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "oekolabel"))]
     pub eco_labels: Vec<EcoLabel>,
 
     /// Whether provider is in eco top ten (Ist in Öko Top Ten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "istInOekoTopTen"))]
     pub in_eco_top_ten: Option<bool>,
 
     /// Website for published energy mix data (Website)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "website"))]
     pub website: Option<String>,
 }
@@ -124,6 +125,58 @@ impl Bo4eObject for EnergyMix {
     }
 }
 
+impl EnergyMix {
+    /// Returns the certificates that cover `year`.
+    ///
+    /// Certificates carry no validity period of their own; they are issued
+    /// for the mix of one year. They are therefore returned only if
+    /// `valid_year` is `year`, without duplicates and in their listed order.
+    /// A mix without a `valid_year` yields no certificates.
+    pub fn valid_certificates_for(&self, year: i32) -> Vec<EcoCertificate> {
+        if self.valid_year != Some(year) {
+            return Vec::new();
+        }
+        let mut certificates = Vec::new();
+        for certificate in &self.eco_certificates {
+            if !certificates.contains(certificate) {
+                certificates.push(*certificate);
+            }
+        }
+        certificates
+    }
+}
+
+/// Tolerance for the sum of source shares, in percentage points.
+const SHARE_EPSILON: f64 = 0.01;
+
+impl Validate for EnergyMix {
+    /// Requires every source share to lie within 0–100 % and, if any
+    /// sources are listed, the shares to add up to 100 %.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        for (i, source) in self.sources.iter().enumerate() {
+            if let Some(share) = source.percentage_share {
+                if !(0.0..=100.0).contains(&share) {
+                    issues.push(ValidationIssue::new(
+                        format!("sources[{}].percentage_share", i),
+                        format!("share {} is outside 0-100", share),
+                    ));
+                }
+            }
+        }
+        if !self.sources.is_empty() {
+            let total: f64 = self.sources.iter().filter_map(|s| s.percentage_share).sum();
+            if (total - 100.0).abs() > SHARE_EPSILON {
+                issues.push(ValidationIssue::new(
+                    "sources",
+                    format!("shares add up to {}, expected 100", total),
+                ));
+            }
+        }
+        finish(issues)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,4 +264,74 @@ mod tests {
         assert_eq!(EnergyMix::type_name_german(), "Energiemix");
         assert_eq!(EnergyMix::type_name_english(), "EnergyMix");
     }
+
+    #[test]
+    fn test_valid_certificates_for_year() {
+        let mix = EnergyMix {
+            valid_year: Some(2024),
+            eco_certificates: vec![
+                EcoCertificate::TuevSuedEe01,
+                EcoCertificate::Eecs,
+                EcoCertificate::TuevSuedEe01,
+            ],
+            eco_labels: vec![EcoLabel::OkPower],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mix.valid_certificates_for(2024),
+            vec![EcoCertificate::TuevSuedEe01, EcoCertificate::Eecs]
+        );
+        assert!(mix.valid_certificates_for(2023).is_empty());
+
+        let undated = EnergyMix {
+            valid_year: None,
+            ..mix
+        };
+        assert!(undated.valid_certificates_for(2024).is_empty());
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let mix = EnergyMix {
+            sources: vec![
+                EnergySource {
+                    generation_type: Some(GenerationType::Solar),
+                    percentage_share: Some(40.0),
+                    ..Default::default()
+                },
+                EnergySource {
+                    generation_type: Some(GenerationType::Wind),
+                    percentage_share: Some(60.0),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(mix.validate(), Ok(()));
+        assert_eq!(EnergyMix::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_all_issues() {
+        let mix = EnergyMix {
+            sources: vec![
+                EnergySource {
+                    percentage_share: Some(120.0),
+                    ..Default::default()
+                },
+                EnergySource {
+                    percentage_share: Some(10.0),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let issues = mix.validate().unwrap_err();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "sources[0].percentage_share");
+        assert_eq!(issues[1].path, "sources");
+        assert_eq!(issues[1].message, "shares add up to 130, expected 100");
+    }
 }
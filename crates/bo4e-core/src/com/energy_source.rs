@@ -31,12 +31,12 @@ pub struct EnergySource {
     pub meta: Bo4eMeta,
 
     /// Type of energy generation (Erzeugungsart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "erzeugungsart"))]
     pub generation_type: Option<GenerationType>,
 
     /// Percentage share of this generation type (Anteil in Prozent)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anteilProzent"))]
     pub percentage_share: Option<f64>,
 }
@@ -32,17 +32,17 @@ pub struct ExternalCostBlock {
     pub meta: Bo4eMeta,
 
     /// Name/designation of the cost block (Kostenblockbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenblockbezeichnung"))]
     pub designation: Option<String>,
 
     /// Cost class (Kostenklasse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kostenklasse"))]
     pub cost_class: Option<CostClass>,
 
     /// Sum of all costs in this block (Summe Kostenblock)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "summeKostenblock"))]
     pub total_amount: Option<Amount>,
 }
@@ -29,12 +29,12 @@ pub struct ExternalReference {
     pub meta: Bo4eMeta,
 
     /// Name of the external system (ExRefName)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "exRefName"))]
     pub external_ref_name: Option<String>,
 
     /// Value/ID in the external system (ExRefWert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "exRefWert"))]
     pub external_ref_value: Option<String>,
 }
@@ -71,8 +71,117 @@ impl ExternalReference {
             ..Default::default()
         }
     }
+
+    /// Identify the external system from the reference name.
+    ///
+    /// Returns `None` for systems not covered by [`ExternalSystem`].
+    pub fn parse_system(&self) -> Option<ExternalSystem> {
+        self.external_ref_name
+            .as_deref()
+            .and_then(ExternalSystem::from_name)
+    }
+
+    /// Check the reference value against the format of its system.
+    ///
+    /// References to unknown systems only need a value.
+    pub fn validate(&self) -> Result<(), ExternalReferenceError> {
+        let value = match self.external_ref_value.as_deref() {
+            Some(value) if !value.trim().is_empty() => value,
+            _ => return Err(ExternalReferenceError::MissingValue),
+        };
+        match self.parse_system() {
+            Some(system) if !system.is_valid_value(value) => {
+                Err(ExternalReferenceError::InvalidValue {
+                    system,
+                    value: value.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// External systems that references can be routed back to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExternalSystem {
+    /// SAP IS-U / S/4HANA Utilities
+    Sap,
+    /// LIMA
+    Lima,
+    /// powercloud
+    Powercloud,
 }
 
+impl ExternalSystem {
+    /// Look up a system by its reference name, ignoring case.
+    ///
+    /// Accepts the plain names as well as `SAP-ISU`/`SAP_ISU`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "sap" | "sap-isu" | "sap_isu" | "sap isu" => Some(Self::Sap),
+            "lima" => Some(Self::Lima),
+            "powercloud" => Some(Self::Powercloud),
+            _ => None,
+        }
+    }
+
+    /// Canonical reference name of the system.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sap => "SAP",
+            Self::Lima => "LIMA",
+            Self::Powercloud => "powercloud",
+        }
+    }
+
+    /// Check whether `value` has the identifier format of this system.
+    ///
+    /// SAP object numbers consist of up to 12 digits (leading zeros
+    /// included). The other systems use identifiers of letters, digits,
+    /// `-` and `_`.
+    pub fn is_valid_value(&self, value: &str) -> bool {
+        match self {
+            Self::Sap => {
+                (1..=12).contains(&value.len()) && value.bytes().all(|b| b.is_ascii_digit())
+            }
+            Self::Lima | Self::Powercloud => {
+                !value.is_empty()
+                    && value
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            }
+        }
+    }
+}
+
+/// Error returned by [`ExternalReference::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalReferenceError {
+    /// The reference has no value
+    MissingValue,
+    /// The value does not match the identifier format of the system
+    InvalidValue {
+        /// System named by the reference
+        system: ExternalSystem,
+        /// Offending value
+        value: String,
+    },
+}
+
+impl std::fmt::Display for ExternalReferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingValue => write!(f, "external reference has no value"),
+            Self::InvalidValue { system, value } => {
+                write!(f, "{:?} is not a valid {} identifier", value, system.name())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExternalReferenceError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +226,35 @@ mod tests {
         assert_eq!(ExternalReference::type_name_german(), "ExterneReferenz");
         assert_eq!(ExternalReference::type_name_english(), "ExternalReference");
     }
+
+    #[test]
+    fn test_parse_system() {
+        let ext_ref = ExternalReference::new("SAP", "0004711");
+        assert_eq!(ext_ref.parse_system(), Some(ExternalSystem::Sap));
+        assert_eq!(ext_ref.validate(), Ok(()));
+
+        let ext_ref = ExternalReference::new("Powercloud", "CT-2024-17");
+        assert_eq!(ext_ref.parse_system(), Some(ExternalSystem::Powercloud));
+        assert_eq!(ext_ref.validate(), Ok(()));
+
+        let ext_ref = ExternalReference::new("CRM", "CUST 12345");
+        assert_eq!(ext_ref.parse_system(), None);
+        assert_eq!(ext_ref.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_invalid_value() {
+        let ext_ref = ExternalReference::new("sap", "ABC-1");
+        assert_eq!(
+            ext_ref.validate(),
+            Err(ExternalReferenceError::InvalidValue {
+                system: ExternalSystem::Sap,
+                value: "ABC-1".to_string(),
+            })
+        );
+        assert_eq!(
+            ExternalReference::new("LIMA", " ").validate(),
+            Err(ExternalReferenceError::MissingValue)
+        );
+    }
 }
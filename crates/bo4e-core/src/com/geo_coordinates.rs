@@ -22,6 +22,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Geokoordinaten"))]
 #[serde(rename_all = "camelCase")]
 pub struct GeoCoordinates {
@@ -30,16 +31,36 @@ pub struct GeoCoordinates {
     pub meta: Bo4eMeta,
 
     /// Latitude in decimal degrees (Breitengrad)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "breitengrad"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::latitude))]
     pub latitude: Option<f64>,
 
     /// Longitude in decimal degrees (Laengengrad)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "laengengrad"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::longitude))]
     pub longitude: Option<f64>,
 }
 
+/// Mean earth radius in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+impl GeoCoordinates {
+    /// Great-circle distance to `other` in kilometers.
+    ///
+    /// Uses the haversine formula on a spherical earth, which is accurate
+    /// to well below one percent. Returns `None` if either point lacks a
+    /// latitude or longitude.
+    pub fn distance_km(&self, other: &GeoCoordinates) -> Option<f64> {
+        let (lat1, lon1) = (self.latitude?.to_radians(), self.longitude?.to_radians());
+        let (lat2, lon2) = (other.latitude?.to_radians(), other.longitude?.to_radians());
+        let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+        Some(2.0 * EARTH_RADIUS_KM * a.sqrt().asin())
+    }
+}
+
 impl Bo4eObject for GeoCoordinates {
     fn type_name_german() -> &'static str {
         "Geokoordinaten"
@@ -103,4 +124,23 @@ mod tests {
         assert_eq!(GeoCoordinates::type_name_german(), "Geokoordinaten");
         assert_eq!(GeoCoordinates::type_name_english(), "GeoCoordinates");
     }
+
+    #[test]
+    fn test_distance_km() {
+        let cologne = GeoCoordinates {
+            latitude: Some(50.9413),
+            longitude: Some(6.9583),
+            ..Default::default()
+        };
+        let berlin = GeoCoordinates {
+            latitude: Some(52.520008),
+            longitude: Some(13.404954),
+            ..Default::default()
+        };
+
+        let distance = cologne.distance_km(&berlin).unwrap();
+        assert!((distance - 478.0).abs() < 5.0, "{}", distance);
+        assert_eq!(cologne.distance_km(&cologne), Some(0.0));
+        assert!(cologne.distance_km(&GeoCoordinates::default()).is_none());
+    }
 }
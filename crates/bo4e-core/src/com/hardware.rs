@@ -25,6 +25,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Hardware"))]
 #[serde(rename_all = "camelCase")]
 pub struct Hardware {
@@ -33,22 +34,22 @@ pub struct Hardware {
     pub meta: Bo4eMeta,
 
     /// Device number assigned by the metering service operator (Gerätenummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraetenummer"))]
     pub device_number: Option<String>,
 
     /// Description of the device (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub description: Option<String>,
 
     /// Category/class of the device (Geräteklasse)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraeteklasse"))]
     pub device_category: Option<DeviceCategory>,
 
     /// Specific type of the device (Gerätetyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "geraetetyp"))]
     pub device_type: Option<DeviceType>,
 }
@@ -71,6 +72,85 @@ impl Bo4eObject for Hardware {
     }
 }
 
+impl Hardware {
+    /// Check the record for import errors.
+    ///
+    /// Physical devices must carry a device number (serial), and a declared
+    /// device category must match the category implied by the device type.
+    /// Connections and flat-rate systems have no serial of their own.
+    pub fn validate(&self) -> Result<(), HardwareError> {
+        let Some(device_type) = self.device_type else {
+            return Ok(());
+        };
+        if requires_serial_number(device_type)
+            && self.device_number.as_deref().map_or(true, str::is_empty)
+        {
+            return Err(HardwareError::MissingSerialNumber { device_type });
+        }
+        if let Some(declared) = self.device_category {
+            let implied = device_type.category();
+            if declared != implied {
+                return Err(HardwareError::CategoryMismatch {
+                    device_type,
+                    declared,
+                    implied,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn requires_serial_number(device_type: DeviceType) -> bool {
+    !matches!(
+        device_type,
+        DeviceType::FlatRateSystem
+            | DeviceType::CommunicationConnection
+            | DeviceType::TelephoneConnection
+            | DeviceType::OtherDevice
+    )
+}
+
+/// Error returned by [`Hardware::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HardwareError {
+    /// The device type requires a device number but none is set
+    MissingSerialNumber {
+        /// Declared device type
+        device_type: DeviceType,
+    },
+    /// The declared category does not match the device type
+    CategoryMismatch {
+        /// Declared device type
+        device_type: DeviceType,
+        /// Declared device category
+        declared: DeviceCategory,
+        /// Category implied by the device type
+        implied: DeviceCategory,
+    },
+}
+
+impl std::fmt::Display for HardwareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSerialNumber { device_type } => {
+                write!(f, "device type {:?} requires a device number", device_type)
+            }
+            Self::CategoryMismatch {
+                device_type,
+                declared,
+                implied,
+            } => write!(
+                f,
+                "device type {:?} belongs to category {:?}, not {:?}",
+                device_type, implied, declared
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HardwareError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +195,51 @@ mod tests {
         assert_eq!(Hardware::type_name_german(), "Hardware");
         assert_eq!(Hardware::type_name_english(), "Hardware");
     }
+
+    #[test]
+    fn test_validate_modem_serial() {
+        let modem = Hardware {
+            device_number: Some("MDM-4711".to_string()),
+            device_type: Some(DeviceType::ModemGsm),
+            device_category: Some(DeviceCategory::CommunicationEquipment),
+            ..Default::default()
+        };
+        assert_eq!(modem.validate(), Ok(()));
+
+        let missing = Hardware {
+            device_number: None,
+            ..modem.clone()
+        };
+        assert_eq!(
+            missing.validate(),
+            Err(HardwareError::MissingSerialNumber {
+                device_type: DeviceType::ModemGsm
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_category_mismatch() {
+        let hw = Hardware {
+            device_number: Some("W-1".to_string()),
+            device_type: Some(DeviceType::CurrentTransformer),
+            device_category: Some(DeviceCategory::VolumeConverter),
+            ..Default::default()
+        };
+        let err = hw.validate().unwrap_err();
+        assert_eq!(
+            err,
+            HardwareError::CategoryMismatch {
+                device_type: DeviceType::CurrentTransformer,
+                declared: DeviceCategory::VolumeConverter,
+                implied: DeviceCategory::Transformer,
+            }
+        );
+
+        let connection = Hardware {
+            device_type: Some(DeviceType::TelephoneConnection),
+            ..Default::default()
+        };
+        assert!(connection.validate().is_ok());
+    }
 }
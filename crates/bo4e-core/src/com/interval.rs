@@ -1,5 +1,6 @@
 //! Interval component.
 
+use chrono::Duration;
 use serde::{Deserialize, Serialize};
 
 use crate::enums::TimeUnit;
@@ -31,12 +32,12 @@ pub struct Interval {
     pub meta: Bo4eMeta,
 
     /// Duration value (Dauer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "dauer"))]
     pub duration: Option<i32>,
 
     /// Time unit (Zeiteinheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeiteinheit"))]
     pub unit: Option<TimeUnit>,
 }
@@ -86,6 +87,55 @@ impl Interval {
             ..Default::default()
         }
     }
+
+    /// Length of the interval, if it has a fixed duration.
+    ///
+    /// Returns `None` for calendar units (months, quarters, half years,
+    /// years), whose length varies, and if duration or unit is missing.
+    pub fn to_duration(&self) -> Option<Duration> {
+        let duration = i64::from(self.duration?);
+        match self.unit? {
+            TimeUnit::Second => Some(Duration::seconds(duration)),
+            TimeUnit::Minute => Some(Duration::minutes(duration)),
+            TimeUnit::QuarterHour => Some(Duration::minutes(15 * duration)),
+            TimeUnit::Hour => Some(Duration::hours(duration)),
+            TimeUnit::Day => Some(Duration::days(duration)),
+            TimeUnit::Week => Some(Duration::weeks(duration)),
+            _ => None,
+        }
+    }
+
+    /// Express the interval in the largest of seconds, minutes, hours or
+    /// days that represents it exactly, e.g. 120 minutes as 2 hours.
+    ///
+    /// Intervals without a fixed duration, or of zero length, are returned
+    /// unchanged.
+    pub fn normalize(&self) -> Interval {
+        const UNITS: [(TimeUnit, i64); 4] = [
+            (TimeUnit::Day, 86_400),
+            (TimeUnit::Hour, 3_600),
+            (TimeUnit::Minute, 60),
+            (TimeUnit::Second, 1),
+        ];
+        let Some(seconds) = self.to_duration().map(|d| d.num_seconds()) else {
+            return self.clone();
+        };
+        if seconds == 0 {
+            return self.clone();
+        }
+        UNITS
+            .iter()
+            .filter(|(_, size)| seconds % size == 0)
+            .find_map(|(unit, size)| {
+                let duration = i32::try_from(seconds / size).ok()?;
+                Some(Interval {
+                    duration: Some(duration),
+                    unit: Some(*unit),
+                    ..self.clone()
+                })
+            })
+            .unwrap_or_else(|| self.clone())
+    }
 }
 
 #[cfg(test)]
@@ -119,4 +169,54 @@ mod tests {
         assert_eq!(Interval::type_name_german(), "Intervall");
         assert_eq!(Interval::type_name_english(), "Interval");
     }
+
+    #[test]
+    fn test_to_duration() {
+        assert_eq!(
+            Interval::minutes_15().to_duration(),
+            Some(Duration::minutes(15))
+        );
+        let quarter_hours = Interval {
+            duration: Some(4),
+            unit: Some(TimeUnit::QuarterHour),
+            ..Default::default()
+        };
+        assert_eq!(quarter_hours.to_duration(), Some(Duration::hours(1)));
+
+        let monthly = Interval {
+            duration: Some(1),
+            unit: Some(TimeUnit::Month),
+            ..Default::default()
+        };
+        assert_eq!(monthly.to_duration(), None);
+        assert_eq!(Interval::default().to_duration(), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let sixty_minutes = Interval {
+            duration: Some(60),
+            unit: Some(TimeUnit::Minute),
+            ..Default::default()
+        };
+        assert_eq!(sixty_minutes.normalize(), Interval::hourly());
+
+        let ninety_minutes = Interval {
+            duration: Some(90),
+            ..sixty_minutes.clone()
+        };
+        assert_eq!(ninety_minutes.normalize(), ninety_minutes);
+        assert_eq!(Interval::minutes_15().normalize(), Interval::minutes_15());
+
+        let four_quarter_hours = Interval {
+            duration: Some(96),
+            unit: Some(TimeUnit::QuarterHour),
+            ..Default::default()
+        };
+        assert_eq!(four_quarter_hours.normalize(), Interval::daily());
+        assert_eq!(
+            four_quarter_hours.normalize().to_duration(),
+            four_quarter_hours.to_duration()
+        );
+    }
 }
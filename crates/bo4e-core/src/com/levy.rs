@@ -30,32 +30,32 @@ pub struct Levy {
     pub meta: Bo4eMeta,
 
     /// Description/name of the levy (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub description: Option<String>,
 
     /// Levy value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Currency (Waehrung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "waehrung"))]
     pub currency: Option<Currency>,
 
     /// Reference unit (Bezugseinheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezugseinheit"))]
     pub reference_unit: Option<Unit>,
 
     /// Legal reference (Gesetzliche Grundlage)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesetzlicheGrundlage"))]
     pub legal_reference: Option<String>,
 
     /// Website for more information (Website)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "website"))]
     pub website: Option<String>,
 }
@@ -78,6 +78,80 @@ impl Bo4eObject for Levy {
     }
 }
 
+/// Kind of statutory levy on electricity in Germany.
+///
+/// Used to itemize and total levies per category. BO4E describes levies
+/// only by free text, so this is a calculation helper rather than a BO4E
+/// enumeration and is not serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LevyKind {
+    /// Renewable energy levy (EEG-Umlage)
+    Eeg,
+    /// Combined heat and power levy (KWKG-Umlage)
+    Kwkg,
+    /// Levy for reduced network charges (§19 StromNEV-Umlage)
+    StromNev19,
+    /// Offshore grid levy (Offshore-Netzumlage)
+    Offshore,
+    /// Interruptible loads levy (AbLaV-Umlage)
+    Ablav,
+    /// Concession fee (Konzessionsabgabe)
+    ConcessionFee,
+}
+
+impl LevyKind {
+    /// Returns the German name.
+    pub fn german_name(&self) -> &'static str {
+        match self {
+            Self::Eeg => "EEG-Umlage",
+            Self::Kwkg => "KWKG-Umlage",
+            Self::StromNev19 => "§19 StromNEV-Umlage",
+            Self::Offshore => "Offshore-Netzumlage",
+            Self::Ablav => "AbLaV-Umlage",
+            Self::ConcessionFee => "Konzessionsabgabe",
+        }
+    }
+}
+
+/// Keywords identifying each levy kind, checked in order against the
+/// lowercased description and legal reference.
+const KIND_KEYWORDS: [(&str, LevyKind); 6] = [
+    ("offshore", LevyKind::Offshore),
+    ("ablav", LevyKind::Ablav),
+    ("stromnev", LevyKind::StromNev19),
+    ("kwk", LevyKind::Kwkg),
+    ("konzession", LevyKind::ConcessionFee),
+    ("eeg", LevyKind::Eeg),
+];
+
+impl Levy {
+    /// Guess the kind of levy from its description or legal reference.
+    ///
+    /// This is a best-effort heuristic: the texts are free-form, so they
+    /// are searched for keywords such as "eeg" or "konzession". A text
+    /// that mentions several levies, or one that only contains a keyword
+    /// by accident, may be misclassified. Returns `None` if neither text
+    /// names one of the known levies.
+    pub fn kind(&self) -> Option<LevyKind> {
+        [&self.description, &self.legal_reference]
+            .into_iter()
+            .flatten()
+            .find_map(|text| {
+                let text = text.to_lowercase();
+                KIND_KEYWORDS
+                    .iter()
+                    .find(|(keyword, _)| text.contains(keyword))
+                    .map(|(_, kind)| *kind)
+            })
+    }
+
+    /// Find the first levy of the given kind.
+    pub fn find_by_kind(levies: &[Levy], kind: LevyKind) -> Option<&Levy> {
+        levies.iter().find(|levy| levy.kind() == Some(kind))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,4 +213,32 @@ mod tests {
         assert_eq!(Levy::type_name_german(), "Umlage");
         assert_eq!(Levy::type_name_english(), "Levy");
     }
+
+    #[test]
+    fn test_find_by_kind() {
+        let levy = |description: &str, value: f64| Levy {
+            description: Some(description.to_string()),
+            value: Some(value),
+            ..Default::default()
+        };
+        let levies = vec![
+            levy("KWKG-Umlage", 0.275),
+            levy("§19 StromNEV-Umlage", 0.643),
+            levy("EEG-Umlage", 6.756),
+            levy("Offshore-Netzumlage", 0.591),
+        ];
+
+        let eeg = Levy::find_by_kind(&levies, LevyKind::Eeg).unwrap();
+        assert_eq!(eeg.value, Some(6.756));
+        assert_eq!(levies[1].kind(), Some(LevyKind::StromNev19));
+        assert_eq!(Levy::find_by_kind(&levies, LevyKind::Ablav), None);
+
+        let by_reference = Levy {
+            description: Some("Umlage".to_string()),
+            legal_reference: Some("§ 2 KAV (Konzessionsabgabe)".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(by_reference.kind(), Some(LevyKind::ConcessionFee));
+        assert_eq!(Levy::default().kind(), None);
+    }
 }
@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::enums::{MeasuredValueStatus, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::is_unit_compatible;
+
+use super::SubstitutionValue;
 
 /// A measured value at a specific timestamp.
 ///
@@ -34,27 +37,27 @@ pub struct MeasuredValue {
     pub meta: Bo4eMeta,
 
     /// Timestamp of measurement (Zeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitpunkt"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Measured value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Status/quality of the value (Status)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "status"))]
     pub status: Option<MeasuredValueStatus>,
 
     /// OBIS code identifying the measurement (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 }
@@ -77,6 +80,77 @@ impl Bo4eObject for MeasuredValue {
     }
 }
 
+impl MeasuredValue {
+    /// Check whether this value is a substitute rather than a real reading.
+    pub fn is_substituted(&self) -> bool {
+        self.status == Some(MeasuredValueStatus::Substitute)
+    }
+
+    /// Replace all substituted values in a series with `substitute`.
+    ///
+    /// Each flagged value takes the value (and, if set, the unit) of the
+    /// substitution while keeping its own timestamp and OBIS code. Returns
+    /// the cleaned series and the number of values replaced.
+    pub fn replace_substituted(
+        values: &[MeasuredValue],
+        substitute: &SubstitutionValue,
+    ) -> (Vec<MeasuredValue>, usize) {
+        let mut replaced = 0;
+        let cleaned = values
+            .iter()
+            .map(|value| {
+                let mut value = value.clone();
+                if value.is_substituted() {
+                    value.value = substitute.value;
+                    value.unit = substitute.unit.or(value.unit);
+                    replaced += 1;
+                }
+                value
+            })
+            .collect();
+        (cleaned, replaced)
+    }
+
+    /// Check that the unit fits the measurement given by the OBIS code.
+    ///
+    /// Values without a unit or OBIS code pass. See [`is_unit_compatible`].
+    pub fn validate(&self) -> Result<(), MeasuredValueError> {
+        if let (Some(obis_code), Some(unit)) = (&self.obis_code, self.unit) {
+            if !is_unit_compatible(obis_code, unit) {
+                return Err(MeasuredValueError::IncompatibleUnit {
+                    obis_code: obis_code.clone(),
+                    unit,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`MeasuredValue::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeasuredValueError {
+    /// The unit does not measure the quantity the OBIS code stands for
+    IncompatibleUnit {
+        /// OBIS code of the value
+        obis_code: String,
+        /// Unit of the value
+        unit: Unit,
+    },
+}
+
+impl std::fmt::Display for MeasuredValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IncompatibleUnit { obis_code, unit } => {
+                write!(f, "unit {:?} does not fit OBIS code {}", unit, obis_code)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MeasuredValueError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +191,51 @@ mod tests {
         assert_eq!(MeasuredValue::type_name_german(), "Messwert");
         assert_eq!(MeasuredValue::type_name_english(), "MeasuredValue");
     }
+
+    #[test]
+    fn test_replace_substituted() {
+        let reading = |value, status| MeasuredValue {
+            value: Some(value),
+            unit: Some(Unit::KilowattHour),
+            status: Some(status),
+            ..Default::default()
+        };
+        let series = vec![
+            reading(1.0, MeasuredValueStatus::Read),
+            reading(99.0, MeasuredValueStatus::Substitute),
+            reading(3.0, MeasuredValueStatus::Read),
+        ];
+        assert!(series[1].is_substituted());
+        assert!(!series[0].is_substituted());
+
+        let substitute = SubstitutionValue {
+            value: Some(2.0),
+            ..Default::default()
+        };
+        let (cleaned, count) = MeasuredValue::replace_substituted(&series, &substitute);
+        assert_eq!(count, 1);
+        let values: Vec<Option<f64>> = cleaned.iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![Some(1.0), Some(2.0), Some(3.0)]);
+        assert_eq!(cleaned[1].unit, Some(Unit::KilowattHour));
+    }
+
+    #[test]
+    fn test_validate_unit() {
+        let mut value = MeasuredValue {
+            value: Some(12.5),
+            unit: Some(Unit::KilowattHour),
+            obis_code: Some("1-0:1.8.0".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(value.validate(), Ok(()));
+
+        value.unit = Some(Unit::CubicMeter);
+        assert_eq!(
+            value.validate(),
+            Err(MeasuredValueError::IncompatibleUnit {
+                obis_code: "1-0:1.8.0".to_string(),
+                unit: Unit::CubicMeter,
+            })
+        );
+    }
 }
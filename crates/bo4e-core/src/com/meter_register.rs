@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::enums::{EnergyDirection, RegisterType, Unit};
+use crate::enums::{EnergyDirection, MeasurementType, RegisterType, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// A register on a meter that records consumption.
@@ -24,6 +24,7 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Zaehlwerk"))]
 #[serde(rename_all = "camelCase")]
 pub struct MeterRegister {
@@ -32,42 +33,44 @@ pub struct MeterRegister {
     pub meta: Bo4eMeta,
 
     /// Register number/ID (Zaehlwerkskennung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlwerkskennung"))]
     pub register_id: Option<String>,
 
     /// OBIS code (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::obis_code))]
     pub obis_code: Option<String>,
 
     /// Type of register (Registerart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "registerart"))]
     pub register_type: Option<RegisterType>,
 
     /// Direction of energy flow (Energierichtung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "energierichtung"))]
     pub energy_direction: Option<EnergyDirection>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Number of decimal places (Nachkommastellen)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "nachkommastellen"))]
     pub decimal_places: Option<i32>,
 
     /// Multiplier/transformer ratio (Wandlerfaktor)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wandlerfaktor"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::fuzzing::quantity))]
     pub transformer_ratio: Option<f64>,
 
     /// Description (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub description: Option<String>,
 }
@@ -90,6 +93,111 @@ impl Bo4eObject for MeterRegister {
     }
 }
 
+impl MeterRegister {
+    /// Guess the measurement type and unit from an OBIS code.
+    ///
+    /// Accepts full codes (`1-0:1.8.0`, `1-0:1.8.0*255`) and the short
+    /// `C.D.E` form (`1.8.0`, assumed to be electricity). Only the common
+    /// electricity energy and power registers and gas volume registers are
+    /// known; other codes yield `None`.
+    pub fn measurement_from_obis(obis: &str) -> Option<(MeasurementType, Unit)> {
+        let code = ObisCode::parse(obis)?;
+        match code.medium {
+            ELECTRICITY => {
+                let reactive = match code.channel {
+                    1 | 2 => false,
+                    3 | 4 => true,
+                    _ => return None,
+                };
+                let (measurement, energy) = match code.processing {
+                    8 => (MeasurementType::CurrentValue, true),
+                    7 => (MeasurementType::CurrentValue, false),
+                    4 | 5 => (MeasurementType::MeanValue, false),
+                    6 => (MeasurementType::MaximumValue, false),
+                    _ => return None,
+                };
+                let unit = match (energy, reactive) {
+                    (true, false) => Unit::KilowattHour,
+                    (true, true) => Unit::KilovoltAmpereReactiveHour,
+                    (false, false) => Unit::Kilowatt,
+                    (false, true) => Unit::KilovoltAmpereReactive,
+                };
+                Some((measurement, unit))
+            }
+            GAS => match (code.channel, code.processing) {
+                (3, 0..=2) => Some((MeasurementType::CurrentValue, Unit::CubicMeter)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Guess the energy direction from an electricity OBIS code.
+    ///
+    /// Channels 1 and 3 (import) map to [`EnergyDirection::FeedOut`],
+    /// channels 2 and 4 (export) to [`EnergyDirection::FeedIn`].
+    pub fn direction_from_obis(obis: &str) -> Option<EnergyDirection> {
+        let code = ObisCode::parse(obis)?;
+        match (code.medium, code.channel) {
+            (ELECTRICITY, 1 | 3) => Some(EnergyDirection::FeedOut),
+            (ELECTRICITY, 2 | 4) => Some(EnergyDirection::FeedIn),
+            _ => None,
+        }
+    }
+
+    /// Fill in unset unit and energy direction from the OBIS code.
+    ///
+    /// Fields that are already set are left untouched.
+    pub fn fill_from_obis(&mut self) {
+        let Some(obis) = self.obis_code.as_deref() else {
+            return;
+        };
+        if self.unit.is_none() {
+            self.unit = Self::measurement_from_obis(obis).map(|(_, unit)| unit);
+        }
+        if self.energy_direction.is_none() {
+            self.energy_direction = Self::direction_from_obis(obis);
+        }
+    }
+}
+
+/// OBIS medium (value group A) for electricity.
+const ELECTRICITY: u8 = 1;
+/// OBIS medium (value group A) for gas.
+const GAS: u8 = 7;
+
+/// The value groups of an OBIS code relevant for classification.
+struct ObisCode {
+    /// Value group A
+    medium: u8,
+    /// Value group C
+    channel: u8,
+    /// Value group D
+    processing: u8,
+}
+
+impl ObisCode {
+    fn parse(obis: &str) -> Option<Self> {
+        let obis = obis.trim();
+        let obis = obis.split_once('*').map_or(obis, |(code, _)| code);
+        let (medium, groups) = match obis.split_once(':') {
+            Some((prefix, groups)) => {
+                let medium = prefix.split('-').next()?.parse().ok()?;
+                (medium, groups)
+            }
+            None => (ELECTRICITY, obis),
+        };
+        let mut groups = groups.split('.');
+        let channel = groups.next()?.parse().ok()?;
+        let processing = groups.next()?.parse().ok()?;
+        Some(Self {
+            medium,
+            channel,
+            processing,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +235,50 @@ mod tests {
         assert_eq!(MeterRegister::type_name_german(), "Zaehlwerk");
         assert_eq!(MeterRegister::type_name_english(), "MeterRegister");
     }
+
+    #[test]
+    fn test_measurement_from_obis() {
+        assert_eq!(
+            MeterRegister::measurement_from_obis("1-0:1.8.0"),
+            Some((MeasurementType::CurrentValue, Unit::KilowattHour))
+        );
+        assert_eq!(
+            MeterRegister::measurement_from_obis("1.8.1"),
+            Some((MeasurementType::CurrentValue, Unit::KilowattHour))
+        );
+        assert_eq!(
+            MeterRegister::measurement_from_obis("1-0:1.6.0*255"),
+            Some((MeasurementType::MaximumValue, Unit::Kilowatt))
+        );
+        assert_eq!(
+            MeterRegister::measurement_from_obis("7-0:3.0.0"),
+            Some((MeasurementType::CurrentValue, Unit::CubicMeter))
+        );
+        assert_eq!(MeterRegister::measurement_from_obis("1-0:99.97.0"), None);
+        assert_eq!(MeterRegister::measurement_from_obis("not an obis"), None);
+    }
+
+    #[test]
+    fn test_export_from_obis() {
+        assert_eq!(
+            MeterRegister::measurement_from_obis("1-0:2.8.0"),
+            Some((MeasurementType::CurrentValue, Unit::KilowattHour))
+        );
+        assert_eq!(
+            MeterRegister::direction_from_obis("1-0:2.8.0"),
+            Some(EnergyDirection::FeedIn)
+        );
+        assert_eq!(
+            MeterRegister::direction_from_obis("1-0:1.8.0"),
+            Some(EnergyDirection::FeedOut)
+        );
+
+        let mut register = MeterRegister {
+            obis_code: Some("1-0:2.8.0".to_string()),
+            ..Default::default()
+        };
+        register.fill_from_obis();
+        assert_eq!(register.unit, Some(Unit::KilowattHour));
+        assert_eq!(register.energy_direction, Some(EnergyDirection::FeedIn));
+    }
 }
@@ -7,6 +7,11 @@ use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// Status information for a metering point.
 ///
+/// The lifecycle state is read from `status_code`, using the codes
+/// `GEPLANT`, `IN_BETRIEB`, `AUSSER_BETRIEB`, `STILLGELEGT` and
+/// `WIEDERINBETRIEBNAHME`. Without a known code, `is_active` decides between
+/// in operation and out of operation.
+///
 /// German: Messstellenstatus
 ///
 /// # Example
@@ -31,32 +36,32 @@ pub struct MeteringPointStatus {
     pub meta: Bo4eMeta,
 
     /// Timestamp of the status (Statuszeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "statuszeitpunkt"))]
     pub status_timestamp: Option<DateTime<Utc>>,
 
     /// Whether the metering point is active (Aktiv)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aktiv"))]
     pub is_active: Option<bool>,
 
     /// Status code (Statuscode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "statuscode"))]
     pub status_code: Option<String>,
 
     /// Status description (Statusbeschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "statusbeschreibung"))]
     pub status_description: Option<String>,
 
     /// Whether data is being transmitted (Datenübertragung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "datenuebertragung"))]
     pub data_transmission_active: Option<bool>,
 
     /// Installation status (Installationsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "installationsstatus"))]
     pub installation_status: Option<String>,
 }
@@ -79,6 +84,60 @@ impl Bo4eObject for MeteringPointStatus {
     }
 }
 
+impl MeteringPointStatus {
+    /// Check whether a metering point may move from this status to `next`.
+    ///
+    /// A decommissioned metering point can only return to operation through
+    /// a reactivation step. Repeating the current state is allowed, and a
+    /// status without a known state may be followed by any known state. A
+    /// `next` status without a known state is never accepted.
+    pub fn can_transition_to(&self, next: &MeteringPointStatus) -> bool {
+        use Lifecycle::*;
+
+        let Some(to) = next.lifecycle() else {
+            return false;
+        };
+        let Some(from) = self.lifecycle() else {
+            return true;
+        };
+        from == to
+            || matches!(
+                (from, to),
+                (Planned, InOperation | Decommissioned)
+                    | (InOperation, OutOfOperation | Decommissioned)
+                    | (OutOfOperation, InOperation | Decommissioned)
+                    | (Decommissioned, Reactivation)
+                    | (Reactivation, InOperation | Decommissioned)
+            )
+    }
+
+    fn lifecycle(&self) -> Option<Lifecycle> {
+        let from_code = match self.status_code.as_deref() {
+            Some("GEPLANT") => Some(Lifecycle::Planned),
+            Some("IN_BETRIEB") => Some(Lifecycle::InOperation),
+            Some("AUSSER_BETRIEB") => Some(Lifecycle::OutOfOperation),
+            Some("STILLGELEGT") => Some(Lifecycle::Decommissioned),
+            Some("WIEDERINBETRIEBNAHME") => Some(Lifecycle::Reactivation),
+            _ => None,
+        };
+        from_code.or(match self.is_active {
+            Some(true) => Some(Lifecycle::InOperation),
+            Some(false) => Some(Lifecycle::OutOfOperation),
+            None => None,
+        })
+    }
+}
+
+/// Lifecycle state of a metering point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lifecycle {
+    Planned,
+    InOperation,
+    OutOfOperation,
+    Decommissioned,
+    Reactivation,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +181,34 @@ mod tests {
             "MeteringPointStatus"
         );
     }
+
+    fn with_code(code: &str) -> MeteringPointStatus {
+        MeteringPointStatus {
+            status_code: Some(code.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_valid_transitions() {
+        let decommissioned = with_code("STILLGELEGT");
+        assert!(with_code("IN_BETRIEB").can_transition_to(&decommissioned));
+        assert!(decommissioned.can_transition_to(&with_code("WIEDERINBETRIEBNAHME")));
+        assert!(with_code("WIEDERINBETRIEBNAHME").can_transition_to(&with_code("IN_BETRIEB")));
+
+        let active = MeteringPointStatus {
+            is_active: Some(true),
+            ..Default::default()
+        };
+        assert!(with_code("GEPLANT").can_transition_to(&active));
+        assert!(MeteringPointStatus::default().can_transition_to(&active));
+    }
+
+    #[test]
+    fn test_invalid_transitions() {
+        let decommissioned = with_code("STILLGELEGT");
+        assert!(!decommissioned.can_transition_to(&with_code("IN_BETRIEB")));
+        assert!(!with_code("IN_BETRIEB").can_transition_to(&with_code("GEPLANT")));
+        assert!(!with_code("IN_BETRIEB").can_transition_to(&MeteringPointStatus::default()));
+    }
 }
@@ -153,28 +153,28 @@ mod time_series_value;
 mod validation_result;
 
 // Epic 3.1 exports
-pub use address::Address;
-pub use cadastral_address::CadastralAddress;
+pub use address::{Address, AddressBuilder};
+pub use cadastral_address::{CadastralAddress, ParcelError};
 pub use contact_method::ContactMethod;
 pub use contract_conditions::ContractConditions;
 pub use contract_part::ContractPart;
-pub use external_reference::ExternalReference;
+pub use external_reference::{ExternalReference, ExternalReferenceError, ExternalSystem};
 pub use geo_coordinates::GeoCoordinates;
-pub use hardware::Hardware;
+pub use hardware::{Hardware, HardwareError};
 pub use invoice_position::InvoicePosition;
 pub use offer_part::OfferPart;
 pub use offer_position::OfferPosition;
 pub use offer_variant::OfferVariant;
 pub use region_criterion::RegionCriterion;
 pub use responsibility::Responsibility;
-pub use signature::Signature;
+pub use signature::{Signature, SignatureError};
 
 // Epic 3.2 exports
-pub use amount::Amount;
+pub use amount::{Amount, CurrencyMismatch};
 pub use bonus::Bonus;
 pub use concession_fee::ConcessionFee;
 pub use consumed_quantity::ConsumedQuantity;
-pub use consumption::Consumption;
+pub use consumption::{Consumption, UnitMismatch};
 pub use cost_block::CostBlock;
 pub use cost_position::CostPosition;
 pub use discount::Discount;
@@ -182,17 +182,17 @@ pub use energy_mix::EnergyMix;
 pub use energy_source::EnergySource;
 pub use external_cost_block::ExternalCostBlock;
 pub use external_cost_position::ExternalCostPosition;
-pub use levy::Levy;
+pub use levy::{Levy, LevyKind};
 pub use margin_price::MarginPrice;
 pub use network_charge::NetworkCharge;
 pub use position_surcharge::PositionSurcharge;
 pub use price::Price;
 pub use price_guarantee::PriceGuarantee;
-pub use price_position::PricePosition;
+pub use price_position::{PriceError, PricePosition};
 pub use price_tier::PriceTier;
 pub use regional_price_tier::RegionalPriceTier;
 pub use regional_surcharge::RegionalSurcharge;
-pub use service_price::ServicePrice;
+pub use service_price::{cheapest_service_price, ServicePrice};
 pub use surcharge::Surcharge;
 pub use surcharge_per_location::SurchargePerLocation;
 pub use tariff_calculation_parameter::TariffCalculationParameter;
@@ -208,7 +208,7 @@ pub use date_range::DateRange;
 pub use interval::Interval;
 pub use load_curve_data::LoadCurveData;
 pub use load_profile_value::LoadProfileValue;
-pub use measured_value::MeasuredValue;
+pub use measured_value::{MeasuredValue, MeasuredValueError};
 pub use meter_reading::MeterReading;
 pub use meter_register::MeterRegister;
 pub use metering_point_status::MeteringPointStatus;
@@ -217,7 +217,7 @@ pub use quality_indicator::QualityIndicator;
 pub use quantity::Quantity;
 pub use seasonal_tariff::SeasonalTariff;
 pub use substitution_value::SubstitutionValue;
-pub use time_of_use_register::TimeOfUseRegister;
+pub use time_of_use_register::{active_tariff_time, TimeOfUseRegister};
 pub use time_period::TimePeriod;
 pub use time_series_value::TimeSeriesValue;
 pub use validation_result::ValidationResult;
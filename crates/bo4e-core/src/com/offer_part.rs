@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
+use super::{Amount, OfferPosition};
+
 /// Part of an offer variant.
 ///
 /// Aggregates offer positions. Offer parts are typically created for a market location
@@ -32,39 +34,74 @@ pub struct OfferPart {
     pub meta: Bo4eMeta,
 
     /// Sub-reference identifying a sub-chapter of a request, e.g., tender lot (AnfrageSubreferenz)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anfrageSubreferenz"))]
     pub request_sub_reference: Option<String>,
 
+    /// Positions of this offer part (Positionen)
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
+    #[cfg_attr(feature = "json-schema", schemars(rename = "positionen"))]
+    pub positions: Vec<OfferPosition>,
+
     // Note: The following fields would typically reference other COM types
-    // (Angebotsposition, Marktlokation, Menge, Betrag, Zeitraum) which will be added later.
+    // (Marktlokation, Menge, Betrag, Zeitraum) which will be added later.
     // For now, we use simplified representations.
     /// Number of positions in this offer part
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlPositionen"))]
     pub position_count: Option<i32>,
 
     /// Total quantity value for this offer part (simplified - Gesamtmengeangebotsteil)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtmengeAngebotsteil"))]
     pub total_quantity_value: Option<f64>,
 
     /// Total cost value for this offer part (simplified - Gesamtkostenangebotsteil)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtkostenAngebotsteil"))]
     pub total_cost_value: Option<f64>,
 
     /// Delivery period start (simplified - Lieferzeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferzeitraumBeginn"))]
     pub delivery_period_start: Option<String>,
 
     /// Delivery period end (simplified - Lieferzeitraum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "lieferzeitraumEnde"))]
     pub delivery_period_end: Option<String>,
 }
 
+impl OfferPart {
+    /// Sum of the position costs.
+    ///
+    /// A position without a cost value contributes price times quantity;
+    /// positions with neither are skipped. If no position is priced, the
+    /// stated `total_cost_value` is used instead, and `None` is returned if
+    /// that is missing too. Position values carry no currency, so the
+    /// result has none either.
+    pub fn total(&self) -> Option<Amount> {
+        let costs: Vec<f64> = self
+            .positions
+            .iter()
+            .filter_map(|position| {
+                position.position_cost_value.or_else(|| {
+                    Some(position.position_price_value? * position.position_quantity_value?)
+                })
+            })
+            .collect();
+        let value = if costs.is_empty() {
+            self.total_cost_value?
+        } else {
+            costs.iter().sum()
+        };
+        Some(Amount {
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+}
+
 impl Bo4eObject for OfferPart {
     fn type_name_german() -> &'static str {
         "Angebotsteil"
@@ -114,6 +151,7 @@ mod tests {
         let part = OfferPart {
             meta: Bo4eMeta::with_type("Angebotsteil"),
             request_sub_reference: Some("LOT-002".to_string()),
+            positions: vec![],
             position_count: Some(3),
             total_quantity_value: Some(30000.0),
             total_cost_value: Some(7500.0),
@@ -126,6 +164,25 @@ mod tests {
         assert_eq!(part, parsed);
     }
 
+    #[test]
+    fn test_total_without_priced_positions() {
+        let stated = OfferPart {
+            total_cost_value: Some(7500.0),
+            ..Default::default()
+        };
+        assert_eq!(stated.total().unwrap().value, Some(7500.0));
+
+        let unpriced = OfferPart {
+            positions: vec![OfferPosition {
+                position_quantity_value: Some(1000.0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(unpriced.total(), None);
+        assert_eq!(OfferPart::default().total(), None);
+    }
+
     #[test]
     fn test_bo4e_object_impl() {
         assert_eq!(OfferPart::type_name_german(), "Angebotsteil");
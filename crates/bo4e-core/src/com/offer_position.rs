@@ -33,7 +33,7 @@ pub struct OfferPosition {
     pub meta: Bo4eMeta,
 
     /// Description of the offer position (Positionsbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "positionsbezeichnung"))]
     pub position_description: Option<String>,
 
@@ -41,17 +41,17 @@ pub struct OfferPosition {
     // (Preis, Menge, Betrag) which will be added in a later epic.
     // For now, we use simplified representations.
     /// Position price value (simplified - Positionspreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "positionspreis"))]
     pub position_price_value: Option<f64>,
 
     /// Position quantity value (simplified - Positionsmenge)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "positionsmenge"))]
     pub position_quantity_value: Option<f64>,
 
     /// Position cost value (simplified - Positionskosten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "positionskosten"))]
     pub position_cost_value: Option<f64>,
 }
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use crate::enums::OfferStatus;
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
+use super::{Amount, OfferPart};
+
 /// Variant of an offer with different calculation options.
 ///
 /// German: Angebotsvariante
@@ -31,38 +33,68 @@ pub struct OfferVariant {
     pub meta: Bo4eMeta,
 
     /// Status of the offer (Angebotsstatus)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "angebotsstatus"))]
     pub offer_status: Option<OfferStatus>,
 
     /// Creation date of the offer variant (Erstellungsdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "erstellungsdatum"))]
     pub creation_date: Option<String>,
 
     /// Binding deadline - until this time the offer variant is valid (Bindefrist)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bindefrist"))]
     pub binding_deadline: Option<String>,
 
+    /// Parts of this variant (Teile)
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
+    #[cfg_attr(feature = "json-schema", schemars(rename = "teile"))]
+    pub parts: Vec<OfferPart>,
+
     // Note: The following fields would typically reference other COM types
-    // (Angebotsteil, Menge, Betrag) which will be added later.
+    // (Menge, Betrag) which will be added later.
     /// Number of offer parts in this variant
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "anzahlTeile"))]
     pub parts_count: Option<i32>,
 
     /// Total quantity value across all offer parts (simplified - Gesamtmenge)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtmenge"))]
     pub total_quantity_value: Option<f64>,
 
     /// Total cost value across all offer parts (simplified - Gesamtkosten)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gesamtkosten"))]
     pub total_cost_value: Option<f64>,
 }
 
+impl OfferVariant {
+    /// Sum of the part totals, see [`OfferPart::total`].
+    ///
+    /// A variant without parts falls back to its stated `total_cost_value`.
+    /// Returns `None` if that is missing or any part has no cost, since the
+    /// sum would understate the variant.
+    pub fn total(&self) -> Option<Amount> {
+        if self.parts.is_empty() {
+            return Some(Amount {
+                value: Some(self.total_cost_value?),
+                ..Default::default()
+            });
+        }
+        let value = self
+            .parts
+            .iter()
+            .map(|part| part.total()?.value)
+            .sum::<Option<f64>>()?;
+        Some(Amount {
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+}
+
 impl Bo4eObject for OfferVariant {
     fn type_name_german() -> &'static str {
         "Angebotsvariante"
@@ -114,6 +146,7 @@ mod tests {
             offer_status: Some(OfferStatus::NonBinding),
             creation_date: Some("2024-02-15T10:30:00+01:00".to_string()),
             binding_deadline: Some("2024-03-15T23:59:59+01:00".to_string()),
+            parts: vec![],
             parts_count: Some(2),
             total_quantity_value: Some(100000.0),
             total_cost_value: Some(25000.0),
@@ -129,4 +162,31 @@ mod tests {
         assert_eq!(OfferVariant::type_name_german(), "Angebotsvariante");
         assert_eq!(OfferVariant::type_name_english(), "OfferVariant");
     }
+
+    #[test]
+    fn test_total_sums_positions_across_parts() {
+        use crate::com::OfferPosition;
+
+        let variant = OfferVariant {
+            parts: vec![OfferPart {
+                positions: vec![
+                    OfferPosition {
+                        position_cost_value: Some(982.40),
+                        ..Default::default()
+                    },
+                    OfferPosition {
+                        position_price_value: Some(0.25),
+                        position_quantity_value: Some(1000.0),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let total = variant.total().unwrap();
+        assert!((total.value.unwrap() - 1232.40).abs() < 1e-9);
+        assert_eq!(total.currency, None);
+    }
 }
@@ -1,6 +1,6 @@
 //! Price guarantee (Preisgarantie) component.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::enums::PriceGuaranteeType;
@@ -32,22 +32,22 @@ pub struct PriceGuarantee {
     pub meta: Bo4eMeta,
 
     /// Type of price guarantee (Preisgarantietyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preisgarantietyp"))]
     pub guarantee_type: Option<PriceGuaranteeType>,
 
     /// Start of validity period (Zeitliche Gültigkeit - Von)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitlicheGueltigkeit"))]
     pub valid_from: Option<DateTime<Utc>>,
 
     /// End of validity period (Zeitliche Gültigkeit - Bis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitlicheGueltigkeitBis"))]
     pub valid_until: Option<DateTime<Utc>>,
 
     /// Description of the guarantee (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 }
@@ -70,6 +70,28 @@ impl Bo4eObject for PriceGuarantee {
     }
 }
 
+impl PriceGuarantee {
+    /// Returns true if the guarantee covers the given (UTC) calendar date.
+    ///
+    /// Both bounds are inclusive on date level. A missing bound means the
+    /// guarantee is unbounded on that side, so a guarantee without any dates
+    /// applies for the complete contract term.
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        let after_start = self.valid_from.map_or(true, |s| date >= s.date_naive());
+        let before_end = self.valid_until.map_or(true, |e| date <= e.date_naive());
+        after_start && before_end
+    }
+
+    /// Returns the number of days from `from` until the guarantee ends.
+    ///
+    /// Returns `None` for an unbounded guarantee (no `valid_until`), which
+    /// lasts for the complete contract term. An expired guarantee yields `Some(0)`.
+    pub fn days_remaining(&self, from: NaiveDate) -> Option<i64> {
+        let end = self.valid_until?.date_naive();
+        Some((end - from).num_days().max(0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +174,52 @@ mod tests {
         assert_eq!(PriceGuarantee::type_name_german(), "Preisgarantie");
         assert_eq!(PriceGuarantee::type_name_english(), "PriceGuarantee");
     }
+
+    fn guarantee_2024() -> PriceGuarantee {
+        PriceGuarantee {
+            valid_from: Some(
+                DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+                    .unwrap()
+                    .into(),
+            ),
+            valid_until: Some(
+                DateTime::parse_from_rfc3339("2024-12-31T23:59:59Z")
+                    .unwrap()
+                    .into(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_active_guarantee() {
+        let guarantee = guarantee_2024();
+        let date = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+        assert!(guarantee.is_active_on(date));
+        assert_eq!(guarantee.days_remaining(date), Some(30));
+        assert!(guarantee.is_active_on(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()));
+        assert!(!guarantee.is_active_on(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()));
+    }
+
+    #[test]
+    fn test_expired_guarantee() {
+        let guarantee = guarantee_2024();
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        assert!(!guarantee.is_active_on(date));
+        assert_eq!(guarantee.days_remaining(date), Some(0));
+    }
+
+    #[test]
+    fn test_unbounded_guarantee() {
+        let guarantee = PriceGuarantee {
+            guarantee_type: Some(PriceGuaranteeType::AllComponentsGross),
+            ..Default::default()
+        };
+        let date = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+
+        assert!(guarantee.is_active_on(date));
+        assert_eq!(guarantee.days_remaining(date), None);
+    }
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::enums::{CalculationMethod, PriceType, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
-use super::PriceTier;
+use super::{Amount, PriceTier};
 
 /// A position in a price sheet with its associated price tiers.
 ///
@@ -33,41 +33,149 @@ pub struct PricePosition {
     pub meta: Bo4eMeta,
 
     /// Description/name of the price position (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub description: Option<String>,
 
     /// Type of price (Preistyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preistyp"))]
     pub price_type: Option<PriceType>,
 
     /// Reference unit (Bezugseinheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezugseinheit"))]
     pub reference_unit: Option<Unit>,
 
     /// Calculation method (Berechnungsmethode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "berechnungsmethode"))]
     pub calculation_method: Option<CalculationMethod>,
 
     /// Price tiers (Preisstaffeln)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preisstaffeln"))]
     pub tiers: Vec<PriceTier>,
 
     /// Article ID (Artikel-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "artikelId"))]
     pub article_id: Option<String>,
 
     /// BDEW article number (BDEW Artikelnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bdewArtikelnummer"))]
     pub bdew_article_number: Option<String>,
 }
 
+impl PricePosition {
+    /// Amount charged by this position for `quantity`, given in the reference unit.
+    ///
+    /// Prices per period (a reference unit such as [`Unit::Month`]) and base
+    /// prices are charged once, so the quantity is ignored and the first tier's
+    /// unit price is returned. Everything else goes through the tiers with the
+    /// position's calculation method, defaulting to [`CalculationMethod::Steps`].
+    /// Tier prices carry no currency, so neither does the result.
+    ///
+    /// Fails with [`PriceError::MissingUnitPrice`] if there are no tiers or a
+    /// tier the quantity falls into has no unit price, and with
+    /// [`PriceError::NoApplicableTier`] if no tier covers the quantity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bo4e_core::com::{PricePosition, PriceTier};
+    /// use bo4e_core::enums::Unit;
+    ///
+    /// let position = PricePosition {
+    ///     reference_unit: Some(Unit::KilowattHour),
+    ///     tiers: vec![PriceTier {
+    ///         unit_price: Some(0.30),
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(position.compute_amount(1000.0).unwrap().value, Some(300.0));
+    /// ```
+    pub fn compute_amount(&self, quantity: f64) -> Result<Amount, PriceError> {
+        if self.tiers.is_empty() {
+            return Err(PriceError::MissingUnitPrice);
+        }
+        let value = if self.is_flat_charge() {
+            self.tiers
+                .first()
+                .and_then(|tier| tier.unit_price)
+                .ok_or(PriceError::MissingUnitPrice)?
+        } else {
+            let method = self.calculation_method.unwrap_or(CalculationMethod::Steps);
+            if !matches!(method, CalculationMethod::Steps | CalculationMethod::Zones) {
+                return Err(PriceError::UnsupportedCalculationMethod(method));
+            }
+            // A quantity inside the tiers can only fail on a missing price
+            PriceTier::price_for_quantity(&self.tiers, quantity, method).ok_or(
+                match PriceTier::find_applicable(&self.tiers, quantity) {
+                    Some(_) => PriceError::MissingUnitPrice,
+                    None => PriceError::NoApplicableTier { quantity },
+                },
+            )?
+        };
+        Ok(Amount {
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    /// Whether the position is charged once per period regardless of quantity.
+    fn is_flat_charge(&self) -> bool {
+        self.price_type == Some(PriceType::BasePrice)
+            || matches!(
+                self.reference_unit,
+                Some(
+                    Unit::Second
+                        | Unit::Minute
+                        | Unit::Hour
+                        | Unit::QuarterHour
+                        | Unit::Day
+                        | Unit::Week
+                        | Unit::Month
+                        | Unit::Quarter
+                        | Unit::HalfYear
+                        | Unit::Year
+                )
+            )
+    }
+}
+
+/// Error computing the amount of a [`PricePosition`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PriceError {
+    /// The position has no tiers, or the tier to charge has no unit price.
+    MissingUnitPrice,
+    /// No tier covers the quantity.
+    NoApplicableTier {
+        /// The quantity that was priced, in the position's reference unit.
+        quantity: f64,
+    },
+    /// The calculation method cannot be evaluated from tiers alone.
+    UnsupportedCalculationMethod(CalculationMethod),
+}
+
+impl std::fmt::Display for PriceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingUnitPrice => write!(f, "price position has no unit price"),
+            Self::NoApplicableTier { quantity } => {
+                write!(f, "no price tier applies to quantity {quantity}")
+            }
+            Self::UnsupportedCalculationMethod(method) => {
+                write!(f, "unsupported calculation method {method:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PriceError {}
+
 impl Bo4eObject for PricePosition {
     fn type_name_german() -> &'static str {
         "Preisposition"
@@ -137,4 +245,93 @@ mod tests {
         assert_eq!(PricePosition::type_name_german(), "Preisposition");
         assert_eq!(PricePosition::type_name_english(), "PricePosition");
     }
+
+    #[test]
+    fn test_compute_amount_working_price() {
+        let position = PricePosition {
+            price_type: Some(PriceType::WorkingPriceSingleTariff),
+            reference_unit: Some(Unit::KilowattHour),
+            tiers: vec![PriceTier {
+                unit_price: Some(0.30),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let amount = position.compute_amount(3500.0).unwrap();
+        assert!((amount.value.unwrap() - 1050.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compute_amount_base_price_ignores_quantity() {
+        let position = PricePosition {
+            price_type: Some(PriceType::BasePrice),
+            reference_unit: Some(Unit::Month),
+            tiers: vec![PriceTier {
+                unit_price: Some(12.50),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(position.compute_amount(3500.0).unwrap().value, Some(12.50));
+        assert_eq!(position.compute_amount(0.0).unwrap().value, Some(12.50));
+    }
+
+    #[test]
+    fn test_compute_amount_errors() {
+        assert_eq!(
+            PricePosition::default().compute_amount(10.0),
+            Err(PriceError::MissingUnitPrice)
+        );
+        let bounded = PricePosition {
+            tiers: vec![
+                PriceTier {
+                    lower_limit: Some(0.0),
+                    upper_limit: Some(100.0),
+                    unit_price: Some(0.30),
+                    ..Default::default()
+                },
+                PriceTier {
+                    lower_limit: Some(100.0),
+                    upper_limit: Some(1000.0),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            bounded.compute_amount(5000.0),
+            Err(PriceError::NoApplicableTier { quantity: 5000.0 })
+        );
+        assert_eq!(
+            bounded.compute_amount(500.0),
+            Err(PriceError::MissingUnitPrice)
+        );
+        let zoned = PricePosition {
+            calculation_method: Some(CalculationMethod::Zones),
+            ..bounded.clone()
+        };
+        assert_eq!(zoned.compute_amount(50.0).unwrap().value, Some(15.0));
+        assert_eq!(
+            zoned.compute_amount(500.0),
+            Err(PriceError::MissingUnitPrice)
+        );
+        let position = PricePosition {
+            calculation_method: Some(CalculationMethod::Sigmoid),
+            tiers: bounded.tiers,
+            ..Default::default()
+        };
+        assert_eq!(
+            position.compute_amount(10.0),
+            Err(PriceError::UnsupportedCalculationMethod(
+                CalculationMethod::Sigmoid
+            ))
+        );
+        let base = PricePosition {
+            reference_unit: Some(Unit::Year),
+            ..Default::default()
+        };
+        assert_eq!(base.compute_amount(1.0), Err(PriceError::MissingUnitPrice));
+    }
 }
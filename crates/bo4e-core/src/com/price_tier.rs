@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::enums::CalculationMethod;
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// A price tier based on consumption brackets.
@@ -31,27 +32,27 @@ pub struct PriceTier {
     pub meta: Bo4eMeta,
 
     /// Lower consumption limit inclusive (Staffelgrenze von)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "staffelgrenzeVon"))]
     pub lower_limit: Option<f64>,
 
     /// Upper consumption limit exclusive (Staffelgrenze bis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "staffelgrenzeBis"))]
     pub upper_limit: Option<f64>,
 
     /// Unit price for this tier (Einheitspreis)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheitspreis"))]
     pub unit_price: Option<f64>,
 
     /// Tier number/sequence (Staffelnummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "staffelnummer"))]
     pub tier_number: Option<i32>,
 
     /// Article ID reference (Artikel-ID)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "artikelId"))]
     pub article_id: Option<String>,
 }
@@ -74,10 +75,91 @@ impl Bo4eObject for PriceTier {
     }
 }
 
+impl PriceTier {
+    /// Check whether a quantity falls into this tier.
+    ///
+    /// The lower limit is inclusive and the upper limit exclusive, so a
+    /// quantity exactly on a boundary belongs to the higher tier. A missing
+    /// limit leaves that side of the tier open.
+    pub fn contains(&self, quantity: f64) -> bool {
+        let above_lower = self.lower_limit.map_or(true, |l| quantity >= l);
+        let below_upper = self.upper_limit.map_or(true, |u| quantity < u);
+        above_lower && below_upper
+    }
+
+    /// Find the tier that a quantity falls into.
+    pub fn find_applicable(tiers: &[PriceTier], quantity: f64) -> Option<&PriceTier> {
+        tiers.iter().find(|tier| tier.contains(quantity))
+    }
+
+    /// Compute the price for a quantity according to the calculation method.
+    ///
+    /// - [`CalculationMethod::Steps`]: the whole quantity is priced at the unit
+    ///   price of the tier it falls into.
+    /// - [`CalculationMethod::Zones`]: the quantity is split across the tiers
+    ///   and each share is priced at its tier's unit price.
+    ///
+    /// Returns `None` if no tier applies, a tier to be charged has no unit
+    /// price, part of the quantity lies beyond the tiers, or the method is
+    /// not tier-based.
+    pub fn price_for_quantity(
+        tiers: &[PriceTier],
+        quantity: f64,
+        method: CalculationMethod,
+    ) -> Option<f64> {
+        match method {
+            CalculationMethod::Steps => {
+                let unit_price = Self::find_applicable(tiers, quantity)?.unit_price?;
+                Some(unit_price * quantity)
+            }
+            CalculationMethod::Zones => {
+                let mut covered = 0.0;
+                let mut total = 0.0;
+                for tier in tiers {
+                    let lower = tier.lower_limit.unwrap_or(0.0);
+                    let upper = tier.upper_limit.map_or(quantity, |u| u.min(quantity));
+                    if upper > lower {
+                        covered += upper - lower;
+                        total += tier.unit_price? * (upper - lower);
+                    }
+                }
+                (covered >= quantity).then_some(total)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sample_tiers() -> Vec<PriceTier> {
+        vec![
+            PriceTier {
+                lower_limit: Some(0.0),
+                upper_limit: Some(1000.0),
+                unit_price: Some(0.30),
+                tier_number: Some(1),
+                ..Default::default()
+            },
+            PriceTier {
+                lower_limit: Some(1000.0),
+                upper_limit: Some(5000.0),
+                unit_price: Some(0.25),
+                tier_number: Some(2),
+                ..Default::default()
+            },
+            PriceTier {
+                lower_limit: Some(5000.0),
+                upper_limit: None,
+                unit_price: Some(0.20),
+                tier_number: Some(3),
+                ..Default::default()
+            },
+        ]
+    }
+
     #[test]
     fn test_consumption_tiers() {
         let tier1 = PriceTier {
@@ -145,4 +227,60 @@ mod tests {
         assert_eq!(PriceTier::type_name_german(), "Preisstaffel");
         assert_eq!(PriceTier::type_name_english(), "PriceTier");
     }
+
+    #[test]
+    fn test_find_applicable_boundary() {
+        let tiers = sample_tiers();
+        let tier = |q| PriceTier::find_applicable(&tiers, q).and_then(|t| t.tier_number);
+
+        assert_eq!(tier(999.9), Some(1));
+        assert_eq!(tier(1000.0), Some(2));
+        assert_eq!(tier(5000.0), Some(3));
+        assert_eq!(tier(-1.0), None);
+    }
+
+    #[test]
+    fn test_price_for_quantity_steps() {
+        let tiers = sample_tiers();
+        let price = PriceTier::price_for_quantity(&tiers, 2000.0, CalculationMethod::Steps);
+        assert_eq!(price, Some(500.0));
+
+        let boundary = PriceTier::price_for_quantity(&tiers, 1000.0, CalculationMethod::Steps);
+        assert_eq!(boundary, Some(250.0));
+    }
+
+    #[test]
+    fn test_price_for_quantity_zones() {
+        let tiers = sample_tiers();
+        // 1000 * 0.30 + 1000 * 0.25
+        let price = PriceTier::price_for_quantity(&tiers, 2000.0, CalculationMethod::Zones);
+        assert!((price.unwrap() - 550.0).abs() < 1e-9);
+
+        // Exactly on the boundary only the first zone is used
+        let boundary = PriceTier::price_for_quantity(&tiers, 1000.0, CalculationMethod::Zones);
+        assert!((boundary.unwrap() - 300.0).abs() < 1e-9);
+
+        let unsupported = PriceTier::price_for_quantity(&tiers, 10.0, CalculationMethod::Sigmoid);
+        assert_eq!(unsupported, None);
+    }
+
+    #[test]
+    fn test_price_for_quantity_zones_not_charged() {
+        let mut tiers = sample_tiers();
+        tiers.pop();
+        // Beyond the last zone
+        assert_eq!(
+            PriceTier::price_for_quantity(&tiers, 6000.0, CalculationMethod::Zones),
+            None
+        );
+
+        tiers[1].unit_price = None;
+        assert_eq!(
+            PriceTier::price_for_quantity(&tiers, 2000.0, CalculationMethod::Zones),
+            None
+        );
+        // The unpriced zone is not reached
+        let price = PriceTier::price_for_quantity(&tiers, 500.0, CalculationMethod::Zones);
+        assert!((price.unwrap() - 150.0).abs() < 1e-9);
+    }
 }
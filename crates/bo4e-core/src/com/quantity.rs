@@ -31,14 +31,20 @@ pub struct Quantity {
     pub meta: Bo4eMeta,
 
     /// Numeric value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
+
+    /// Absolute standard uncertainty of the value, in the same unit
+    /// (Messunsicherheit). Not part of the BO4E standard; omitted when unset.
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
+    #[cfg_attr(feature = "json-schema", schemars(rename = "messunsicherheit"))]
+    pub uncertainty: Option<f64>,
 }
 
 impl Bo4eObject for Quantity {
@@ -77,6 +83,58 @@ impl Quantity {
             ..Default::default()
         }
     }
+
+    /// Set the absolute uncertainty.
+    pub fn with_uncertainty(mut self, uncertainty: f64) -> Self {
+        self.uncertainty = Some(uncertainty);
+        self
+    }
+
+    /// Add two quantities of the same unit.
+    ///
+    /// Uncertainties are treated as independent and combined as the root of
+    /// the sum of squares; a missing uncertainty counts as zero. Returns
+    /// `None` if a value is missing or the units differ.
+    pub fn add(&self, other: &Quantity) -> Option<Quantity> {
+        if self.unit != other.unit {
+            return None;
+        }
+        let uncertainty = match (self.uncertainty, other.uncertainty) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0).hypot(b.unwrap_or(0.0))),
+        };
+        Some(Quantity {
+            value: Some(self.value? + other.value?),
+            unit: self.unit,
+            uncertainty,
+            ..Default::default()
+        })
+    }
+
+    /// Multiply by a dimensionless factor, such as a transformer ratio or a
+    /// correction factor, with an optional uncertainty of its own.
+    ///
+    /// The unit is kept. Relative uncertainties are combined as the root of
+    /// the sum of squares. Returns `None` if the value is missing.
+    pub fn multiply(&self, factor: f64, factor_uncertainty: Option<f64>) -> Option<Quantity> {
+        let value = self.value?;
+        let product = value * factor;
+        let uncertainty = match (self.uncertainty, factor_uncertainty) {
+            (None, None) => None,
+            (u, None) => u.map(|u| (u * factor).abs()),
+            (u, Some(uf)) => {
+                let own = u.unwrap_or(0.0) * factor;
+                let from_factor = uf * value;
+                Some(own.hypot(from_factor))
+            }
+        };
+        Some(Quantity {
+            value: Some(product),
+            unit: self.unit,
+            uncertainty,
+            ..Default::default()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -109,4 +167,46 @@ mod tests {
         assert_eq!(Quantity::type_name_german(), "Menge");
         assert_eq!(Quantity::type_name_english(), "Quantity");
     }
+
+    #[test]
+    fn test_add_propagates_uncertainty() {
+        let a = Quantity::kwh(100.0).with_uncertainty(3.0);
+        let b = Quantity::kwh(50.0).with_uncertainty(4.0);
+
+        let sum = a.add(&b).unwrap();
+        assert_eq!(sum.value, Some(150.0));
+        assert_eq!(sum.uncertainty, Some(5.0));
+
+        assert_eq!(
+            Quantity::kwh(1.0)
+                .add(&Quantity::kwh(2.0))
+                .unwrap()
+                .uncertainty,
+            None
+        );
+        assert!(a.add(&Quantity::cubic_meters(1.0)).is_none());
+    }
+
+    #[test]
+    fn test_multiply_propagates_uncertainty() {
+        let energy = Quantity::kwh(100.0).with_uncertainty(2.0);
+
+        let scaled = energy.multiply(3.0, None).unwrap();
+        assert_eq!(scaled.value, Some(300.0));
+        assert_eq!(scaled.uncertainty, Some(6.0));
+
+        // 2 % from the quantity and 1.5 % from the factor give 2.5 %.
+        let corrected = energy.multiply(2.0, Some(0.03)).unwrap();
+        assert_eq!(corrected.value, Some(200.0));
+        assert!((corrected.uncertainty.unwrap() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_uncertainty_omitted_when_unset() {
+        let json = serde_json::to_string(&Quantity::kwh(1.0)).unwrap();
+        assert!(!json.contains("uncertainty"));
+
+        let qty: Quantity = serde_json::from_str(r#"{"value":1.0,"uncertainty":0.1}"#).unwrap();
+        assert_eq!(qty.uncertainty, Some(0.1));
+    }
 }
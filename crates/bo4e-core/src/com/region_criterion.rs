@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::com::{Address, GeoCoordinates};
 use crate::enums::{RegionCriterionType, ValidityType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -35,22 +36,109 @@ pub struct RegionCriterion {
     pub meta: Bo4eMeta,
 
     /// Whether this is an inclusive or exclusive criterion (Gueltigkeitstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltigkeitstyp"))]
     pub validity_type: Option<ValidityType>,
 
     /// The type of criterion, e.g., federal state, postal code (Regionskriteriumtyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionskriteriumtyp"))]
     pub criterion_type: Option<RegionCriterionType>,
 
     /// The value the criterion takes, e.g., "NRW" or "50667" (Wert)
     /// Note: For BUNDESWEIT (nationwide), this value is not relevant.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<String>,
 }
 
+impl RegionCriterion {
+    /// Returns `true` if `address` satisfies this criterion.
+    ///
+    /// The validity type is not taken into account here; see
+    /// [`Region::matches`](crate::bo::Region::matches) for how criteria are
+    /// combined. Supported criterion types are:
+    ///
+    /// - postal code, compared exactly
+    /// - postal code range, written as `"50000-50999"`
+    /// - city and municipality name, compared case-insensitively with the
+    ///   city or district of the address
+    /// - postal city, written as `"50667 Köln"`
+    /// - radius, written as `"<km>;<latitude>;<longitude>"` for the center;
+    ///   matches if `position` lies within the radius
+    /// - nationwide, which matches every address
+    ///
+    /// Criteria that cannot be decided from an address, like network
+    /// operators or market areas, never match.
+    pub fn matches(&self, address: &Address, position: Option<&GeoCoordinates>) -> bool {
+        let Some(criterion_type) = self.criterion_type else {
+            return false;
+        };
+        if criterion_type == RegionCriterionType::Nationwide {
+            return true;
+        }
+        let Some(value) = self.value.as_deref().map(str::trim) else {
+            return false;
+        };
+        let postal_code = address.postal_code.as_deref().map(str::trim);
+        match criterion_type {
+            RegionCriterionType::PostalCode => postal_code == Some(value),
+            RegionCriterionType::PostalCodeRange => {
+                postal_code.is_some_and(|code| in_postal_code_range(code, value))
+            }
+            RegionCriterionType::City | RegionCriterionType::MunicipalityName => {
+                names_place(address, value)
+            }
+            RegionCriterionType::PostalCity => match value.split_once(char::is_whitespace) {
+                Some((code, place)) => postal_code == Some(code) && names_place(address, place),
+                None => false,
+            },
+            RegionCriterionType::RadiusKm => {
+                position.is_some_and(|position| within_radius(position, value))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns `true` if `code` lies in a range like `"50000-50999"`.
+fn in_postal_code_range(code: &str, range: &str) -> bool {
+    let Some((from, to)) = range.split_once('-') else {
+        return false;
+    };
+    let parse = |s: &str| s.trim().parse::<u32>().ok();
+    match (parse(from), parse(to), parse(code)) {
+        (Some(from), Some(to), Some(code)) => (from..=to).contains(&code),
+        _ => false,
+    }
+}
+
+fn names_place(address: &Address, name: &str) -> bool {
+    let name = name.trim().to_lowercase();
+    [&address.city, &address.district]
+        .into_iter()
+        .flatten()
+        .any(|place| place.trim().to_lowercase() == name)
+}
+
+/// Returns `true` if `position` lies within a radius like `"25;50.94;6.96"`.
+fn within_radius(position: &GeoCoordinates, value: &str) -> bool {
+    let mut parts = value.split(';').map(|s| s.trim().parse::<f64>().ok());
+    let (Some(Some(radius)), Some(Some(latitude)), Some(Some(longitude)), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    let center = GeoCoordinates {
+        latitude: Some(latitude),
+        longitude: Some(longitude),
+        ..Default::default()
+    };
+    center
+        .distance_km(position)
+        .is_some_and(|distance| distance <= radius)
+}
+
 impl Bo4eObject for RegionCriterion {
     fn type_name_german() -> &'static str {
         "Regionskriterium"
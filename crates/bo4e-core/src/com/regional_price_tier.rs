@@ -30,12 +30,12 @@ pub struct RegionalPriceTier {
     pub meta: Bo4eMeta,
 
     /// Region code (postal code, network area, etc.) (Regionscode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionscode"))]
     pub region_code: Option<String>,
 
     /// Price tiers for this region (Preisstaffeln)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preisstaffeln"))]
     pub tiers: Vec<PriceTier>,
 }
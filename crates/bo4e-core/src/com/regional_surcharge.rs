@@ -33,17 +33,17 @@ pub struct RegionalSurcharge {
     pub meta: Bo4eMeta,
 
     /// Type of regional criterion (Tarifregionskriterium)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifregionskriterium"))]
     pub region_criterion: Option<TariffRegionCriterion>,
 
     /// Region code/value (Regionscode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regionscode"))]
     pub region_code: Option<String>,
 
     /// Surcharges applicable to this region (AufAbschläge)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aufAbschlaege"))]
     pub surcharges: Vec<Surcharge>,
 }
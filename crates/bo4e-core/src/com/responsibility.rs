@@ -34,17 +34,17 @@ pub struct Responsibility {
     pub meta: Bo4eMeta,
 
     /// Subject area classification of the contact person (Themengebiet)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "themengebiet"))]
     pub subject_area: Option<SubjectArea>,
 
     /// Professional position/role of the contact person (Position)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "position"))]
     pub position: Option<String>,
 
     /// Department where the contact person works (Abteilung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "abteilung"))]
     pub department: Option<String>,
 }
@@ -1,6 +1,6 @@
 //! Seasonal tariff (Saisontarif) component.
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 use crate::traits::{Bo4eMeta, Bo4eObject};
@@ -32,27 +32,27 @@ pub struct SeasonalTariff {
     pub meta: Bo4eMeta,
 
     /// Name of the season/tariff period (Saisonbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "saisonbezeichnung"))]
     pub season_name: Option<String>,
 
     /// Start date of the season (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start_date: Option<NaiveDate>,
 
     /// End date of the season (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end_date: Option<NaiveDate>,
 
     /// Tariff identifier (Tarifkennung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifkennung"))]
     pub tariff_id: Option<String>,
 
     /// Price factor for the season (Preisfaktor)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "preisfaktor"))]
     pub price_factor: Option<f64>,
 }
@@ -75,6 +75,31 @@ impl Bo4eObject for SeasonalTariff {
     }
 }
 
+impl SeasonalTariff {
+    /// Check whether the season covers `date`.
+    ///
+    /// Seasons recur every year: only month and day of the start and end
+    /// dates are compared, both inclusive. If the end falls earlier in the
+    /// year than the start, the season wraps around the year boundary
+    /// (e.g. 1 November to 31 March). A season spanning a year or more is
+    /// always active; one without start or end date never is.
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        let (Some(start), Some(end)) = (self.start_date, self.end_date) else {
+            return false;
+        };
+        if end.signed_duration_since(start).num_days() >= 365 {
+            return true;
+        }
+        let day = (date.month(), date.day());
+        let (from, to) = ((start.month(), start.day()), (end.month(), end.day()));
+        if from <= to {
+            from <= day && day <= to
+        } else {
+            day >= from || day <= to
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +137,40 @@ mod tests {
         assert_eq!(SeasonalTariff::type_name_german(), "Saisontarif");
         assert_eq!(SeasonalTariff::type_name_english(), "SeasonalTariff");
     }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_is_active_on_summer() {
+        let summer = SeasonalTariff {
+            season_name: Some("Sommer".to_string()),
+            start_date: Some(date(2024, 4, 1)),
+            end_date: Some(date(2024, 9, 30)),
+            ..Default::default()
+        };
+        assert!(summer.is_active_on(date(2024, 7, 15)));
+        assert!(summer.is_active_on(date(2026, 9, 30)));
+        assert!(!summer.is_active_on(date(2024, 10, 1)));
+        assert!(!summer.is_active_on(date(2024, 3, 31)));
+    }
+
+    #[test]
+    fn test_is_active_on_wrapping_winter() {
+        let winter = SeasonalTariff {
+            season_name: Some("Winter".to_string()),
+            start_date: Some(date(2024, 12, 1)),
+            end_date: Some(date(2025, 2, 28)),
+            ..Default::default()
+        };
+        assert!(winter.is_active_on(date(2024, 12, 24)));
+        assert!(winter.is_active_on(date(2024, 12, 31)));
+        assert!(winter.is_active_on(date(2025, 1, 1)));
+        assert!(winter.is_active_on(date(2028, 2, 28)));
+        assert!(!winter.is_active_on(date(2024, 11, 30)));
+        assert!(!winter.is_active_on(date(2025, 3, 1)));
+
+        assert!(!SeasonalTariff::default().is_active_on(date(2025, 1, 1)));
+    }
 }
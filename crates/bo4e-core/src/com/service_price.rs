@@ -31,27 +31,27 @@ pub struct ServicePrice {
     pub meta: Bo4eMeta,
 
     /// Type of service (Dienstleistungstyp)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "dienstleistungstyp"))]
     pub service_type: Option<ServiceType>,
 
     /// Price value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Currency (Waehrung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "waehrung"))]
     pub currency: Option<Currency>,
 
     /// Reference unit (Bezugseinheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezugseinheit"))]
     pub reference_unit: Option<Unit>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 }
@@ -74,6 +74,35 @@ impl Bo4eObject for ServicePrice {
     }
 }
 
+/// Return the cheapest price for `service`.
+///
+/// Only prices for `service` with a value are considered. Values in
+/// different currencies cannot be compared, so `None` is returned if the
+/// candidates do not all share the same currency; a missing currency counts
+/// as different from any given one.
+pub fn cheapest_service_price(
+    prices: &[ServicePrice],
+    service: ServiceType,
+) -> Option<&ServicePrice> {
+    let mut candidates = prices
+        .iter()
+        .filter(|p| p.service_type == Some(service))
+        .filter_map(|p| Some((p, p.value?)))
+        .peekable();
+    let currency = candidates.peek()?.0.currency;
+
+    let mut cheapest: Option<(&ServicePrice, f64)> = None;
+    for (price, value) in candidates {
+        if price.currency != currency {
+            return None;
+        }
+        if cheapest.map_or(true, |(_, lowest)| value < lowest) {
+            cheapest = Some((price, value));
+        }
+    }
+    cheapest.map(|(price, _)| price)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +144,43 @@ mod tests {
         assert_eq!(price, parsed);
     }
 
+    fn daily_provision(value: f64, currency: Currency) -> ServicePrice {
+        ServicePrice {
+            service_type: Some(ServiceType::DataProvisionDaily),
+            value: Some(value),
+            currency: Some(currency),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cheapest_service_price() {
+        let prices = vec![
+            daily_provision(4.5, Currency::Eur),
+            ServicePrice {
+                service_type: Some(ServiceType::Disconnection),
+                value: Some(1.0),
+                currency: Some(Currency::Eur),
+                ..Default::default()
+            },
+            daily_provision(3.2, Currency::Eur),
+            daily_provision(3.9, Currency::Eur),
+        ];
+
+        let cheapest = cheapest_service_price(&prices, ServiceType::DataProvisionDaily).unwrap();
+        assert_eq!(cheapest.value, Some(3.2));
+        assert!(cheapest_service_price(&prices, ServiceType::DataProvisionWeekly).is_none());
+    }
+
+    #[test]
+    fn test_cheapest_service_price_mixed_currencies() {
+        let prices = vec![
+            daily_provision(4.5, Currency::Eur),
+            daily_provision(3.2, Currency::Chf),
+        ];
+        assert!(cheapest_service_price(&prices, ServiceType::DataProvisionDaily).is_none());
+    }
+
     #[test]
     fn test_bo4e_object_impl() {
         assert_eq!(ServicePrice::type_name_german(), "Dienstleistungspreis");
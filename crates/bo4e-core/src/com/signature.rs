@@ -1,5 +1,6 @@
 //! Signature (Unterschrift) component.
 
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::traits::{Bo4eMeta, Bo4eObject};
@@ -30,21 +31,79 @@ pub struct Signature {
     pub meta: Bo4eMeta,
 
     /// Name of the signatory (Name)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "name"))]
     pub name: Option<String>,
 
     /// Location where the signature was made (Ort)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ort"))]
     pub location: Option<String>,
 
     /// Date of the signature (Datum) - ISO 8601 datetime string
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "datum"))]
     pub date: Option<String>,
 }
 
+impl Signature {
+    /// Check that the signature is complete and not dated after `now`.
+    ///
+    /// The signer's name and the date are required. The date is an RFC 3339
+    /// timestamp or a plain `YYYY-MM-DD` date, which is taken as the start
+    /// of that day in UTC.
+    pub fn validate_at(&self, now: DateTime<Utc>) -> Result<(), SignatureError> {
+        if self
+            .name
+            .as_deref()
+            .map_or(true, |name| name.trim().is_empty())
+        {
+            return Err(SignatureError::MissingSigner);
+        }
+        let date = self.date.as_deref().ok_or(SignatureError::MissingDate)?;
+        let signed_at =
+            parse_date(date).ok_or_else(|| SignatureError::InvalidDate(date.to_string()))?;
+        if signed_at > now {
+            return Err(SignatureError::FutureDate(date.to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(date) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some(day.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+/// Error returned by [`Signature::validate_at`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature has no signer name
+    MissingSigner,
+    /// The signature has no date
+    MissingDate,
+    /// The date is neither an RFC 3339 timestamp nor a `YYYY-MM-DD` date
+    InvalidDate(String),
+    /// The date lies in the future
+    FutureDate(String),
+}
+
+impl std::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSigner => write!(f, "signature has no signer"),
+            Self::MissingDate => write!(f, "signature has no date"),
+            Self::InvalidDate(date) => write!(f, "signature date {:?} is not a valid date", date),
+            Self::FutureDate(date) => write!(f, "signature date {:?} lies in the future", date),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
 impl Bo4eObject for Signature {
     fn type_name_german() -> &'static str {
         "Unterschrift"
@@ -66,6 +125,7 @@ impl Bo4eObject for Signature {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_signature_default() {
@@ -109,4 +169,60 @@ mod tests {
         assert_eq!(Signature::type_name_german(), "Unterschrift");
         assert_eq!(Signature::type_name_english(), "Signature");
     }
+
+    #[test]
+    fn test_validate() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let sig = Signature {
+            name: Some("Erika Musterfrau".to_string()),
+            location: Some("München".to_string()),
+            date: Some("2024-03-20T09:15:00+01:00".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(sig.validate_at(now), Ok(()));
+
+        let date_only = Signature {
+            date: Some("2024-03-20".to_string()),
+            ..sig.clone()
+        };
+        assert_eq!(date_only.validate_at(now), Ok(()));
+
+        let unsigned = Signature {
+            name: Some(" ".to_string()),
+            ..sig.clone()
+        };
+        assert_eq!(
+            unsigned.validate_at(now),
+            Err(SignatureError::MissingSigner)
+        );
+
+        let undated = Signature { date: None, ..sig };
+        assert_eq!(undated.validate_at(now), Err(SignatureError::MissingDate));
+    }
+
+    #[test]
+    fn test_validate_future_date() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 20, 8, 0, 0).unwrap();
+        let sig = Signature {
+            name: Some("Hans Schmidt".to_string()),
+            date: Some("2024-03-20T09:15:00+01:00".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            sig.validate_at(now),
+            Err(SignatureError::FutureDate(
+                "2024-03-20T09:15:00+01:00".to_string()
+            ))
+        );
+        assert_eq!(sig.validate_at(now + chrono::Duration::minutes(15)), Ok(()));
+
+        let garbled = Signature {
+            date: Some("20.03.2024".to_string()),
+            ..sig
+        };
+        assert!(matches!(
+            garbled.validate_at(now),
+            Err(SignatureError::InvalidDate(_))
+        ));
+    }
 }
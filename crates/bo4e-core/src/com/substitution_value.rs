@@ -3,7 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::enums::Unit;
+use crate::enums::{MeasuredValueStatus, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
 /// A substituted/replacement value for missing or invalid measurements.
@@ -35,37 +35,37 @@ pub struct SubstitutionValue {
     pub meta: Bo4eMeta,
 
     /// Timestamp for the substituted value (Zeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitpunkt"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// The substituted value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of the value (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Method used for substitution (Ersatzwertmethode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ersatzwertmethode"))]
     pub substitution_method: Option<String>,
 
     /// Reason for substitution (Grund)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "grund"))]
     pub reason: Option<String>,
 
     /// Original value that was replaced, if available (Originalwert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "originalwert"))]
     pub original_value: Option<f64>,
 
     /// OBIS code (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 }
@@ -88,6 +88,48 @@ impl Bo4eObject for SubstitutionValue {
     }
 }
 
+impl SubstitutionValue {
+    /// Create a substitute estimated by `method` for a value with `original_status`.
+    ///
+    /// The status of the replaced value is recorded as the reason, using its
+    /// German name (e.g. `"Fehlt"`).
+    pub fn from_estimate(original_status: MeasuredValueStatus, method: &str, value: f64) -> Self {
+        Self {
+            value: Some(value),
+            substitution_method: Some(method.to_string()),
+            reason: Some(original_status.german_name().to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// One-line description of the substitution for audit logs.
+    ///
+    /// Mentions the value with the German name of its unit, timestamp,
+    /// method, reason and original value, leaving out what is not set.
+    pub fn audit_note(&self) -> String {
+        let mut note = match (self.value, self.unit) {
+            (Some(value), Some(unit)) => {
+                format!("substituted value {} {}", value, unit.german_name())
+            }
+            (Some(value), None) => format!("substituted value {}", value),
+            (None, _) => "substitution without a recorded value".to_string(),
+        };
+        if let Some(timestamp) = self.timestamp {
+            note.push_str(&format!(" at {}", timestamp.to_rfc3339()));
+        }
+        if let Some(method) = &self.substitution_method {
+            note.push_str(&format!(", method: {}", method));
+        }
+        if let Some(reason) = &self.reason {
+            note.push_str(&format!(", reason: {}", reason));
+        }
+        if let Some(original) = self.original_value {
+            note.push_str(&format!(", original value: {}", original));
+        }
+        note
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +185,26 @@ mod tests {
         assert_eq!(SubstitutionValue::type_name_german(), "Ersatzwert");
         assert_eq!(SubstitutionValue::type_name_english(), "SubstitutionValue");
     }
+
+    #[test]
+    fn test_from_estimate() {
+        let mut value =
+            SubstitutionValue::from_estimate(MeasuredValueStatus::Missing, "Interpolation", 12.5);
+        value.unit = Some(Unit::KilowattHour);
+
+        assert_eq!(value.value, Some(12.5));
+        assert_eq!(value.reason.as_deref(), Some("Fehlt"));
+        assert_eq!(
+            value.audit_note(),
+            "substituted value 12.5 Kilowattstunde, method: Interpolation, reason: Fehlt"
+        );
+
+        value.value = None;
+        value.original_value = Some(11.0);
+        assert_eq!(
+            value.audit_note(),
+            "substitution without a recorded value, method: Interpolation, reason: Fehlt, \
+             original value: 11"
+        );
+    }
 }
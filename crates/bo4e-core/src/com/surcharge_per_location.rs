@@ -31,22 +31,22 @@ pub struct SurchargePerLocation {
     pub meta: Bo4eMeta,
 
     /// Postal code (Postleitzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "postleitzahl"))]
     pub postal_code: Option<String>,
 
     /// Municipality/city (Ort)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "ort"))]
     pub municipality: Option<String>,
 
     /// Network area code (Netznummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "netznummer"))]
     pub network_area_code: Option<String>,
 
     /// Surcharges applicable to this location (AufAbschläge)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aufAbschlaege"))]
     pub surcharges: Vec<Surcharge>,
 }
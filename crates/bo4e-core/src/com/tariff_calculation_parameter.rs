@@ -33,32 +33,32 @@ pub struct TariffCalculationParameter {
     pub meta: Bo4eMeta,
 
     /// Method for tariff calculation (Berechnungsmethode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "berechnungsmethode"))]
     pub calculation_method: Option<TariffCalculationMethod>,
 
     /// Tariff time (day/night, etc.) (Tarifzeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifzeit"))]
     pub tariff_time: Option<TariffTime>,
 
     /// Whether this applies to peak demand (Ist Leistungsabhängig)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "istLeistungsabhaengig"))]
     pub is_demand_based: Option<bool>,
 
     /// Minimum annual consumption for this tariff (Mindestjahresverbrauch)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "mindestjahresverbrauch"))]
     pub min_annual_consumption: Option<f64>,
 
     /// Maximum annual consumption for this tariff (Höchstjahresverbrauch)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hoechstjahresverbrauch"))]
     pub max_annual_consumption: Option<f64>,
 
     /// Description (Beschreibung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "beschreibung"))]
     pub description: Option<String>,
 }
@@ -30,22 +30,22 @@ pub struct TariffRestriction {
     pub meta: Bo4eMeta,
 
     /// Applicable customer types (Kundentypen)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "kundentypen"))]
     pub customer_types: Vec<CustomerType>,
 
     /// Energy division (Sparte)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sparte"))]
     pub division: Option<Division>,
 
     /// Required tariff features (Tarifmerkmale)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifmerkmale"))]
     pub required_features: Vec<TariffFeature>,
 
     /// Excluded tariff features (Ausgeschlossene Tarifmerkmale)
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
     #[cfg_attr(
         feature = "json-schema",
         schemars(rename = "ausgeschlosseneTarifmerkmale")
@@ -53,17 +53,17 @@ pub struct TariffRestriction {
     pub excluded_features: Vec<TariffFeature>,
 
     /// Minimum annual consumption (Mindestjahresverbrauch)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "mindestjahresverbrauch"))]
     pub min_annual_consumption: Option<f64>,
 
     /// Maximum annual consumption (Höchstjahresverbrauch)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "hoechstjahresverbrauch"))]
     pub max_annual_consumption: Option<f64>,
 
     /// Additional notes (Bemerkung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bemerkung"))]
     pub notes: Option<String>,
 }
@@ -86,6 +86,44 @@ impl Bo4eObject for TariffRestriction {
     }
 }
 
+impl TariffRestriction {
+    /// Check whether a customer meets this restriction.
+    ///
+    /// Unset restriction fields impose no restriction: a missing minimum or
+    /// maximum leaves that side of the consumption range open, and an empty
+    /// `customer_types` list admits every customer type. Both consumption
+    /// limits are inclusive. If the restriction names customer types but
+    /// the customer's type is unknown, the restriction is not satisfied.
+    pub fn is_satisfied_by(
+        &self,
+        annual_consumption: f64,
+        customer_type: Option<CustomerType>,
+    ) -> bool {
+        let above_min = self
+            .min_annual_consumption
+            .map_or(true, |min| annual_consumption >= min);
+        let below_max = self
+            .max_annual_consumption
+            .map_or(true, |max| annual_consumption <= max);
+        let type_allowed = self.customer_types.is_empty()
+            || customer_type.is_some_and(|t| self.customer_types.contains(&t));
+        above_min && below_max && type_allowed
+    }
+
+    /// Check whether a customer meets every restriction in the list.
+    ///
+    /// An empty list is always satisfied.
+    pub fn all_satisfied(
+        restrictions: &[TariffRestriction],
+        annual_consumption: f64,
+        customer_type: Option<CustomerType>,
+    ) -> bool {
+        restrictions
+            .iter()
+            .all(|r| r.is_satisfied_by(annual_consumption, customer_type))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +178,49 @@ mod tests {
         assert_eq!(TariffRestriction::type_name_german(), "Tarifeinschraenkung");
         assert_eq!(TariffRestriction::type_name_english(), "TariffRestriction");
     }
+
+    #[test]
+    fn test_is_satisfied_by_consumption() {
+        let restriction = TariffRestriction {
+            min_annual_consumption: Some(1000.0),
+            max_annual_consumption: Some(10000.0),
+            ..Default::default()
+        };
+
+        assert!(restriction.is_satisfied_by(3500.0, None));
+        assert!(restriction.is_satisfied_by(1000.0, None));
+        assert!(!restriction.is_satisfied_by(999.0, None));
+        assert!(!restriction.is_satisfied_by(12000.0, None));
+    }
+
+    #[test]
+    fn test_all_satisfied_customer_type() {
+        let restrictions = vec![
+            TariffRestriction {
+                customer_types: vec![CustomerType::Private],
+                ..Default::default()
+            },
+            TariffRestriction {
+                max_annual_consumption: Some(10000.0),
+                ..Default::default()
+            },
+        ];
+
+        assert!(TariffRestriction::all_satisfied(
+            &restrictions,
+            3500.0,
+            Some(CustomerType::Private)
+        ));
+        assert!(!TariffRestriction::all_satisfied(
+            &restrictions,
+            3500.0,
+            Some(CustomerType::Commercial)
+        ));
+        assert!(!TariffRestriction::all_satisfied(
+            &restrictions,
+            3500.0,
+            None
+        ));
+        assert!(TariffRestriction::all_satisfied(&[], 0.0, None));
+    }
 }
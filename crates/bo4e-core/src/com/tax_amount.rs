@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::calc::VatRate;
+use crate::com::Amount;
 use crate::enums::{Currency, TaxType};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -34,27 +36,27 @@ pub struct TaxAmount {
     pub meta: Bo4eMeta,
 
     /// Type of tax (Steuerart)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerart"))]
     pub tax_type: Option<TaxType>,
 
     /// Tax rate as percentage (Steuersatz)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuersatz"))]
     pub tax_rate: Option<f64>,
 
     /// Net amount on which tax was calculated (Basiswert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "basiswert"))]
     pub basis_value: Option<f64>,
 
     /// Calculated tax amount (Steuerwert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "steuerwert"))]
     pub tax_value: Option<f64>,
 
     /// Currency (Waehrungscode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "waehrungscode"))]
     pub currency: Option<Currency>,
 }
@@ -101,6 +103,42 @@ impl TaxAmount {
             ..Default::default()
         }
     }
+
+    /// Compute the tax on `net` at the default rate of `tax_type`.
+    ///
+    /// See [`TaxType::vat_rate`]; use [`compute_vat_at`](Self::compute_vat_at)
+    /// for goods and services taxed at the reduced rate. A missing net value
+    /// counts as zero.
+    pub fn compute_vat(net: &Amount, tax_type: TaxType) -> Self {
+        Self::compute_vat_at(net, tax_type, tax_type.vat_rate())
+    }
+
+    /// Compute the tax on `net` at `rate`.
+    pub fn compute_vat_at(net: &Amount, tax_type: TaxType, rate: VatRate) -> Self {
+        let basis = net.value.unwrap_or(0.0);
+        Self {
+            tax_type: Some(tax_type),
+            tax_rate: Some(rate.percent()),
+            basis_value: Some(basis),
+            tax_value: Some(basis * (rate.percent() / 100.0)),
+            currency: net.currency,
+            ..Default::default()
+        }
+    }
+
+    /// Extract the net amount from `gross` at the default rate of `tax_type`.
+    pub fn net_from_gross(gross: &Amount, tax_type: TaxType) -> Amount {
+        Self::net_from_gross_at(gross, tax_type.vat_rate())
+    }
+
+    /// Extract the net amount from `gross` at `rate`.
+    pub fn net_from_gross_at(gross: &Amount, rate: VatRate) -> Amount {
+        Amount {
+            value: gross.value.map(|v| v / (1.0 + rate.percent() / 100.0)),
+            currency: gross.currency,
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +164,39 @@ mod tests {
         assert!((tax.tax_value.unwrap() - 7.0).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_compute_vat_standard_rate() {
+        let tax = TaxAmount::compute_vat(&Amount::eur(100.0), TaxType::ValueAddedTax);
+        assert_eq!(tax.tax_rate, Some(19.0));
+        assert_eq!(tax.basis_value, Some(100.0));
+        assert_eq!(tax.tax_value, Some(19.0));
+        assert_eq!(tax.currency, Some(Currency::Eur));
+
+        let reduced = TaxAmount::compute_vat_at(
+            &Amount::eur(100.0),
+            TaxType::ValueAddedTax,
+            VatRate::Reduced,
+        );
+        assert_eq!(reduced.tax_rate, Some(7.0));
+        assert!((reduced.tax_value.unwrap() - 7.0).abs() < 1e-9);
+
+        let reverse = TaxAmount::compute_vat(&Amount::eur(100.0), TaxType::ReverseCharge);
+        assert_eq!(reverse.tax_value, Some(0.0));
+    }
+
+    #[test]
+    fn test_net_from_gross() {
+        let net = TaxAmount::net_from_gross(&Amount::eur(119.0), TaxType::ValueAddedTax);
+        assert!((net.value.unwrap() - 100.0).abs() < 1e-9);
+        assert_eq!(net.currency, Some(Currency::Eur));
+
+        let net = TaxAmount::net_from_gross_at(&Amount::eur(107.0), VatRate::Reduced);
+        assert!((net.value.unwrap() - 100.0).abs() < 1e-9);
+
+        let net = TaxAmount::net_from_gross(&Amount::eur(50.0), TaxType::ReverseCharge);
+        assert_eq!(net.value, Some(50.0));
+    }
+
     #[test]
     fn test_default() {
         let tax = TaxAmount::default();
@@ -1,5 +1,6 @@
 //! Time-of-use register (Zaehlzeitregister) component.
 
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{TariffTime, Unit};
@@ -32,37 +33,37 @@ pub struct TimeOfUseRegister {
     pub meta: Bo4eMeta,
 
     /// Register ID (Zaehlwerkskennung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zaehlwerkskennung"))]
     pub register_id: Option<String>,
 
     /// OBIS code (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 
     /// Tariff time period (Tarifzeit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "tarifzeit"))]
     pub tariff_time: Option<TariffTime>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Description of the register (Bezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "bezeichnung"))]
     pub description: Option<String>,
 
     /// Active start time in HH:MM format (Aktivzeitbeginn)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aktivzeitbeginn"))]
     pub active_start_time: Option<String>,
 
     /// Active end time in HH:MM format (Aktivzeitende)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "aktivzeitende"))]
     pub active_end_time: Option<String>,
 }
@@ -85,9 +86,124 @@ impl Bo4eObject for TimeOfUseRegister {
     }
 }
 
+impl TimeOfUseRegister {
+    /// Active window as local start and end time, if both are set and valid.
+    fn window(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let start = parse_time(self.active_start_time.as_deref()?)?;
+        let end = parse_time(self.active_end_time.as_deref()?)?;
+        Some((start, end))
+    }
+}
+
+/// Determine which tariff time applies at `at`.
+///
+/// Windows are read as German local time (CET/CEST). A window whose end
+/// lies before its start wraps over midnight, e.g. `22:00`–`06:00`; equal
+/// start and end cover the whole day. High tariff windows only apply on
+/// weekdays: on Saturdays and Sundays a low tariff register applies all
+/// day. A register without a window is used if no window matches.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::com::{active_tariff_time, TimeOfUseRegister};
+/// use bo4e_core::enums::TariffTime;
+/// use chrono::{TimeZone, Utc};
+///
+/// let registers = [
+///     TimeOfUseRegister {
+///         tariff_time: Some(TariffTime::HighTariff),
+///         active_start_time: Some("06:00".to_string()),
+///         active_end_time: Some("22:00".to_string()),
+///         ..Default::default()
+///     },
+///     TimeOfUseRegister {
+///         tariff_time: Some(TariffTime::LowTariff),
+///         active_start_time: Some("22:00".to_string()),
+///         active_end_time: Some("06:00".to_string()),
+///         ..Default::default()
+///     },
+/// ];
+///
+/// // 11:00 CET on a Wednesday
+/// let at = Utc.with_ymd_and_hms(2024, 1, 17, 10, 0, 0).unwrap();
+/// assert_eq!(active_tariff_time(&registers, at), Some(TariffTime::HighTariff));
+/// ```
+pub fn active_tariff_time(
+    registers: &[TimeOfUseRegister],
+    at: DateTime<Utc>,
+) -> Option<TariffTime> {
+    let local = german_local_time(at);
+    let time = local.time();
+    let weekend = matches!(local.weekday(), Weekday::Sat | Weekday::Sun);
+
+    let mut fallback = None;
+    for register in registers {
+        let Some(tariff_time) = register.tariff_time else {
+            continue;
+        };
+        if weekend {
+            match tariff_time {
+                TariffTime::HighTariff => continue,
+                TariffTime::LowTariff => return Some(tariff_time),
+                TariffTime::Standard => {}
+            }
+        }
+        match register.window() {
+            Some((start, end)) => {
+                let active = match start.cmp(&end) {
+                    std::cmp::Ordering::Less => start <= time && time < end,
+                    std::cmp::Ordering::Greater => time >= start || time < end,
+                    std::cmp::Ordering::Equal => true,
+                };
+                if active {
+                    return Some(tariff_time);
+                }
+            }
+            None => {
+                fallback.get_or_insert(tariff_time);
+            }
+        }
+    }
+    fallback
+}
+
+/// Parse `HH:MM` or `HH:MM:SS`; `24:00` is read as midnight.
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    if value == "24:00" || value == "24:00:00" {
+        return Some(NaiveTime::MIN);
+    }
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M:%S"))
+        .ok()
+}
+
+/// Convert `at` to German local time.
+///
+/// Summer time runs from 01:00 UTC on the last Sunday in March to 01:00
+/// UTC on the last Sunday in October.
+fn german_local_time(at: DateTime<Utc>) -> NaiveDateTime {
+    let utc = at.naive_utc();
+    let year = utc.year();
+    let summer = match (last_sunday(year, 3), last_sunday(year, 10)) {
+        (Some(begin), Some(end)) => {
+            let switch = NaiveTime::from_hms_opt(1, 0, 0).unwrap_or(NaiveTime::MIN);
+            utc >= begin.and_time(switch) && utc < end.and_time(switch)
+        }
+        _ => false,
+    };
+    utc + Duration::hours(if summer { 2 } else { 1 })
+}
+
+fn last_sunday(year: i32, month: u32) -> Option<NaiveDate> {
+    let last = NaiveDate::from_ymd_opt(year, month, 31)?;
+    Some(last - Duration::days(i64::from(last.weekday().num_days_from_sunday())))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_high_tariff_register() {
@@ -117,6 +233,70 @@ mod tests {
         assert_eq!(register, parsed);
     }
 
+    fn ht_nt_registers() -> Vec<TimeOfUseRegister> {
+        vec![
+            TimeOfUseRegister {
+                register_id: Some("HT".to_string()),
+                tariff_time: Some(TariffTime::HighTariff),
+                active_start_time: Some("06:00".to_string()),
+                active_end_time: Some("22:00".to_string()),
+                ..Default::default()
+            },
+            TimeOfUseRegister {
+                register_id: Some("NT".to_string()),
+                tariff_time: Some(TariffTime::LowTariff),
+                active_start_time: Some("22:00".to_string()),
+                active_end_time: Some("06:00".to_string()),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_daytime_peak() {
+        // Wednesday, 11:00 CET
+        let at = Utc.with_ymd_and_hms(2024, 1, 17, 10, 0, 0).unwrap();
+        assert_eq!(
+            active_tariff_time(&ht_nt_registers(), at),
+            Some(TariffTime::HighTariff)
+        );
+        // Wednesday, 06:30 CEST, which is still before 06:00 in UTC
+        let at = Utc.with_ymd_and_hms(2024, 7, 17, 4, 30, 0).unwrap();
+        assert_eq!(
+            active_tariff_time(&ht_nt_registers(), at),
+            Some(TariffTime::HighTariff)
+        );
+    }
+
+    #[test]
+    fn test_night_off_peak_wraps_midnight() {
+        // Wednesday 23:30 and Thursday 00:30 CET
+        for (hour, minute) in [(22, 30), (23, 30)] {
+            let at = Utc.with_ymd_and_hms(2024, 1, 17, hour, minute, 0).unwrap();
+            assert_eq!(
+                active_tariff_time(&ht_nt_registers(), at),
+                Some(TariffTime::LowTariff)
+            );
+        }
+    }
+
+    #[test]
+    fn test_weekend_is_off_peak() {
+        // Saturday, 12:00 CET
+        let at = Utc.with_ymd_and_hms(2024, 1, 20, 11, 0, 0).unwrap();
+        assert_eq!(
+            active_tariff_time(&ht_nt_registers(), at),
+            Some(TariffTime::LowTariff)
+        );
+
+        let single = [TimeOfUseRegister {
+            tariff_time: Some(TariffTime::Standard),
+            ..Default::default()
+        }];
+        assert_eq!(active_tariff_time(&single, at), Some(TariffTime::Standard));
+        assert_eq!(active_tariff_time(&[], at), None);
+    }
+
     #[test]
     fn test_bo4e_object_impl() {
         assert_eq!(TimeOfUseRegister::type_name_german(), "Zaehlzeitregister");
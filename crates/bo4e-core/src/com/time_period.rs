@@ -31,12 +31,12 @@ pub struct TimePeriod {
     pub meta: Bo4eMeta,
 
     /// Start of the period (Startdatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "startdatum"))]
     pub start: Option<DateTime<Utc>>,
 
     /// End of the period (Enddatum)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "enddatum"))]
     pub end: Option<DateTime<Utc>>,
 }
@@ -1,8 +1,9 @@
 //! Time series value (Zeitreihenwert) component.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::com::Interval;
 use crate::enums::{MeasuredValueStatus, Unit};
 use crate::traits::{Bo4eMeta, Bo4eObject};
 
@@ -34,32 +35,32 @@ pub struct TimeSeriesValue {
     pub meta: Bo4eMeta,
 
     /// Timestamp of the value (Zeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "zeitpunkt"))]
     pub timestamp: Option<DateTime<Utc>>,
 
     /// Value (Wert)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "wert"))]
     pub value: Option<f64>,
 
     /// Unit of measurement (Einheit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "einheit"))]
     pub unit: Option<Unit>,
 
     /// Status/quality of the value (Status)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "status"))]
     pub status: Option<MeasuredValueStatus>,
 
     /// OBIS code (OBIS-Kennzahl)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "obisKennzahl"))]
     pub obis_code: Option<String>,
 
     /// Sequence number in the time series (Sequenznummer)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "sequenznummer"))]
     pub sequence_number: Option<i64>,
 }
@@ -82,11 +83,109 @@ impl Bo4eObject for TimeSeriesValue {
     }
 }
 
+impl TimeSeriesValue {
+    /// Resample a series onto a regular grid using linear interpolation.
+    ///
+    /// The grid starts at the earliest timestamp of the input and advances in
+    /// steps of `target_interval` up to the latest timestamp. Entries without
+    /// a timestamp are ignored; entries without a value count as missing.
+    ///
+    /// A gap is any stretch between two usable points that is wider than the
+    /// input resolution (the smallest spacing between consecutive entries),
+    /// e.g. because points are absent or carry no value. With
+    /// `interpolate_gaps` set, grid points inside a gap are interpolated
+    /// across it; otherwise they are emitted without a value.
+    ///
+    /// Returns an empty vector if the target interval has no fixed length
+    /// (months, quarters, years) or the input has no usable points.
+    pub fn resample(
+        values: &[TimeSeriesValue],
+        target_interval: &Interval,
+        interpolate_gaps: bool,
+    ) -> Vec<TimeSeriesValue> {
+        let step = match target_interval.to_duration() {
+            Some(step) if step > Duration::zero() => step,
+            _ => return Vec::new(),
+        };
+
+        let mut timestamps: Vec<DateTime<Utc>> =
+            values.iter().filter_map(|v| v.timestamp).collect();
+        timestamps.sort();
+        let resolution = timestamps
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|d| *d > Duration::zero())
+            .min();
+
+        let mut points: Vec<(DateTime<Utc>, f64)> = values
+            .iter()
+            .filter_map(|v| Some((v.timestamp?, v.value?)))
+            .collect();
+        points.sort_by_key(|(ts, _)| *ts);
+        let (Some(first), Some(last)) = (timestamps.first(), timestamps.last()) else {
+            return Vec::new();
+        };
+        let unit = values.iter().find_map(|v| v.unit);
+
+        let mut result = Vec::new();
+        let mut at = *first;
+        while at <= *last {
+            result.push(TimeSeriesValue {
+                timestamp: Some(at),
+                value: interpolate(&points, at, resolution, interpolate_gaps),
+                unit,
+                ..Default::default()
+            });
+            at += step;
+        }
+        result
+    }
+}
+
+/// Linearly interpolate the value at `at` from sorted `(timestamp, value)` points.
+fn interpolate(
+    points: &[(DateTime<Utc>, f64)],
+    at: DateTime<Utc>,
+    resolution: Option<Duration>,
+    interpolate_gaps: bool,
+) -> Option<f64> {
+    let idx = points.partition_point(|(ts, _)| *ts < at);
+    if let Some((ts, value)) = points.get(idx) {
+        if *ts == at {
+            return Some(*value);
+        }
+    }
+    let (t0, v0) = *points.get(idx.checked_sub(1)?)?;
+    let (t1, v1) = *points.get(idx)?;
+
+    let span = t1 - t0;
+    if !interpolate_gaps && resolution.is_some_and(|r| span > r) {
+        return None;
+    }
+    let fraction = (at - t0).num_milliseconds() as f64 / span.num_milliseconds() as f64;
+    Some(v0 + (v1 - v0) * fraction)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::TimeUnit;
     use chrono::TimeZone;
 
+    fn quarter_hour_series(values: &[Option<f64>]) -> Vec<TimeSeriesValue> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| TimeSeriesValue {
+                timestamp: Some(start + Duration::minutes(15 * i as i64)),
+                value: *value,
+                unit: Some(Unit::KilowattHour),
+                ..Default::default()
+            })
+            .collect()
+    }
+
     #[test]
     fn test_time_series_value() {
         let value = TimeSeriesValue {
@@ -122,4 +221,75 @@ mod tests {
         assert_eq!(TimeSeriesValue::type_name_german(), "Zeitreihenwert");
         assert_eq!(TimeSeriesValue::type_name_english(), "TimeSeriesValue");
     }
+
+    #[test]
+    fn test_resample_quarter_hour_to_hourly() {
+        // Linear ramp 0, 10, 20, ... over two hours without the 01:00 point
+        let mut series = quarter_hour_series(&[
+            Some(0.0),
+            Some(10.0),
+            Some(20.0),
+            Some(30.0),
+            Some(40.0),
+            Some(50.0),
+            Some(60.0),
+            Some(70.0),
+            Some(80.0),
+        ]);
+        series.remove(4);
+
+        let hourly = TimeSeriesValue::resample(&series, &Interval::hourly(), true);
+        assert_eq!(hourly.len(), 3);
+        let values: Vec<Option<f64>> = hourly.iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![Some(0.0), Some(40.0), Some(80.0)]);
+        assert_eq!(hourly[1].unit, Some(Unit::KilowattHour));
+        assert_eq!(
+            hourly[1].timestamp,
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 1, 0, 0).unwrap())
+        );
+
+        // Finer than the input: points between samples are interpolated
+        let ten_minutes = Interval {
+            duration: Some(10),
+            unit: Some(TimeUnit::Minute),
+            ..Default::default()
+        };
+        let resampled = TimeSeriesValue::resample(&series[..3], &ten_minutes, false);
+        let values: Vec<f64> = resampled.iter().map(|v| v.value.unwrap()).collect();
+        let expected = [0.0, 10.0 * 10.0 / 15.0, 20.0 * 10.0 / 15.0, 20.0];
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_resample_gap_handling() {
+        // 00:30 carries no value: a gap between 00:15 and 00:45
+        let series = quarter_hour_series(&[Some(0.0), Some(10.0), None, Some(30.0), Some(40.0)]);
+        let grid = Interval {
+            duration: Some(30),
+            unit: Some(TimeUnit::Minute),
+            ..Default::default()
+        };
+
+        let with_holes = TimeSeriesValue::resample(&series, &grid, false);
+        let values: Vec<Option<f64>> = with_holes.iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![Some(0.0), None, Some(40.0)]);
+
+        let filled = TimeSeriesValue::resample(&series, &grid, true);
+        let values: Vec<Option<f64>> = filled.iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![Some(0.0), Some(20.0), Some(40.0)]);
+    }
+
+    #[test]
+    fn test_resample_non_fixed_interval() {
+        let series = quarter_hour_series(&[Some(1.0), Some(2.0)]);
+        let monthly = Interval {
+            duration: Some(1),
+            unit: Some(TimeUnit::Month),
+            ..Default::default()
+        };
+        assert!(TimeSeriesValue::resample(&series, &monthly, true).is_empty());
+    }
 }
@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::traits::{Bo4eMeta, Bo4eObject};
+use crate::validation::ValidationIssue;
 
 /// Result of a validation check on measured data.
 ///
@@ -31,37 +32,37 @@ pub struct ValidationResult {
     pub meta: Bo4eMeta,
 
     /// Timestamp of validation (Validierungszeitpunkt)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "validierungszeitpunkt"))]
     pub validation_timestamp: Option<DateTime<Utc>>,
 
     /// Whether validation passed (Gültig)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "gueltig"))]
     pub is_valid: Option<bool>,
 
     /// Validation rule ID (Validierungsregel)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "validierungsregel"))]
     pub validation_rule_id: Option<String>,
 
     /// Validation rule name (Regelbezeichnung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "regelbezeichnung"))]
     pub validation_rule_name: Option<String>,
 
     /// Error code if validation failed (Fehlercode)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "fehlercode"))]
     pub error_code: Option<String>,
 
     /// Error message (Fehlermeldung)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "fehlermeldung"))]
     pub error_message: Option<String>,
 
     /// Severity level (Schweregrad)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::ser::is_none")]
     #[cfg_attr(feature = "json-schema", schemars(rename = "schweregrad"))]
     pub severity: Option<String>,
 }
@@ -84,6 +85,59 @@ impl Bo4eObject for ValidationResult {
     }
 }
 
+/// Separator used when joining several error messages.
+const MESSAGE_SEPARATOR: &str = "; ";
+
+impl ValidationResult {
+    /// Create a passing result.
+    pub fn ok() -> Self {
+        Self {
+            is_valid: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Create a failing result with an error message.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            is_valid: Some(false),
+            error_message: Some(message.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a result from the issues reported by a
+    /// [`Validate`](crate::validation::Validate) implementation.
+    ///
+    /// An empty list yields a passing result; otherwise the issues are
+    /// joined into the error message.
+    pub fn with_issues(issues: &[ValidationIssue]) -> Self {
+        if issues.is_empty() {
+            return Self::ok();
+        }
+        let messages: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        Self::error(messages.join(MESSAGE_SEPARATOR))
+    }
+
+    /// Combine several results into one.
+    ///
+    /// The merged result is valid only if no input is marked invalid, and
+    /// its error message concatenates the messages of all inputs. Inputs
+    /// without a validity flag do not affect the outcome.
+    pub fn merge(results: &[ValidationResult]) -> Self {
+        let is_valid = results.iter().all(|r| r.is_valid != Some(false));
+        let messages: Vec<&str> = results
+            .iter()
+            .filter_map(|r| r.error_message.as_deref())
+            .collect();
+        Self {
+            is_valid: Some(is_valid),
+            error_message: (!messages.is_empty()).then(|| messages.join(MESSAGE_SEPARATOR)),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +191,29 @@ mod tests {
         assert_eq!(ValidationResult::type_name_german(), "Validierungsergebnis");
         assert_eq!(ValidationResult::type_name_english(), "ValidationResult");
     }
+
+    #[test]
+    fn test_merge_all_ok() {
+        let merged = ValidationResult::merge(&[ValidationResult::ok(), ValidationResult::ok()]);
+        assert_eq!(merged, ValidationResult::ok());
+    }
+
+    #[test]
+    fn test_merge_with_failure() {
+        let merged = ValidationResult::merge(&[
+            ValidationResult::ok(),
+            ValidationResult::error("Value out of range"),
+            ValidationResult::with_issues(&[ValidationIssue::missing("division")]),
+        ]);
+        assert_eq!(merged.is_valid, Some(false));
+        assert_eq!(
+            merged.error_message.as_deref(),
+            Some("Value out of range; division: required field is missing")
+        );
+    }
+
+    #[test]
+    fn test_with_issues_empty() {
+        assert_eq!(ValidationResult::with_issues(&[]), ValidationResult::ok());
+    }
 }
@@ -0,0 +1,86 @@
+//! Aggregation method (Aggregationsmethode) enumeration.
+
+use serde::{Deserialize, Serialize};
+
+/// Method used to aggregate a series of values into one.
+///
+/// German: Aggregationsmethode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "json-schema", schemars(rename = "Aggregationsmethode"))]
+#[non_exhaustive]
+pub enum AggregationMethod {
+    /// Sum of all values (Summe)
+    #[serde(rename = "SUM")]
+    Sum,
+
+    /// Arithmetic mean (Mittelwert)
+    #[serde(rename = "AVERAGE")]
+    Average,
+
+    /// Smallest value (Minimum)
+    #[serde(rename = "MIN")]
+    Min,
+
+    /// Largest value (Maximum)
+    #[serde(rename = "MAX")]
+    Max,
+
+    /// Number of values (Anzahl)
+    #[serde(rename = "COUNT")]
+    Count,
+}
+
+impl AggregationMethod {
+    /// Returns the German name.
+    pub fn german_name(&self) -> &'static str {
+        match self {
+            Self::Sum => "Summe",
+            Self::Average => "Mittelwert",
+            Self::Min => "Minimum",
+            Self::Max => "Maximum",
+            Self::Count => "Anzahl",
+        }
+    }
+
+    /// Returns the code stored in `AggregatedValue::aggregation_method`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Sum => "SUM",
+            Self::Average => "AVERAGE",
+            Self::Min => "MIN",
+            Self::Max => "MAX",
+            Self::Count => "COUNT",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&AggregationMethod::Average).unwrap(),
+            r#""AVERAGE""#
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for method in [
+            AggregationMethod::Sum,
+            AggregationMethod::Average,
+            AggregationMethod::Min,
+            AggregationMethod::Max,
+            AggregationMethod::Count,
+        ] {
+            let json = serde_json::to_string(&method).unwrap();
+            assert_eq!(json, format!("\"{}\"", method.code()));
+            let parsed: AggregationMethod = serde_json::from_str(&json).unwrap();
+            assert_eq!(method, parsed);
+        }
+    }
+}
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Used in the `_typ` field to identify the concrete type of a BO4E object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "BoTyp"))]
 #[non_exhaustive]
 pub enum BoType {
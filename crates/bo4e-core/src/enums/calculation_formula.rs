@@ -1,5 +1,7 @@
 //! Calculation formula (Berechnungsformel) enumeration.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Calculation formula type.
@@ -9,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// German: Berechnungsformel
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Berechnungsformel"))]
 #[non_exhaustive]
 pub enum CalculationFormula {
@@ -39,6 +42,23 @@ impl CalculationFormula {
             Self::SumValue => "Summenwert",
         }
     }
+
+    /// Evaluate the formula over a set of named input values.
+    ///
+    /// Returns `None` if there are no inputs, since none of the formulas
+    /// has a meaningful result for an empty set.
+    pub fn evaluate(&self, inputs: &HashMap<String, f64>) -> Option<f64> {
+        if inputs.is_empty() {
+            return None;
+        }
+        let values = inputs.values().copied();
+        match self {
+            Self::HighestValue => values.reduce(f64::max),
+            Self::MinimumValue => values.reduce(f64::min),
+            Self::AverageValue => Some(values.sum::<f64>() / inputs.len() as f64),
+            Self::SumValue => Some(values.sum()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -66,4 +86,36 @@ mod tests {
             assert_eq!(formula, parsed);
         }
     }
+
+    #[test]
+    fn test_evaluate() {
+        let inputs: HashMap<String, f64> = [
+            ("january".to_string(), 120.0),
+            ("february".to_string(), 80.0),
+            ("march".to_string(), 100.0),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            CalculationFormula::HighestValue.evaluate(&inputs),
+            Some(120.0)
+        );
+        assert_eq!(
+            CalculationFormula::MinimumValue.evaluate(&inputs),
+            Some(80.0)
+        );
+        assert_eq!(
+            CalculationFormula::AverageValue.evaluate(&inputs),
+            Some(100.0)
+        );
+        assert_eq!(CalculationFormula::SumValue.evaluate(&inputs), Some(300.0));
+    }
+
+    #[test]
+    fn test_evaluate_empty() {
+        let inputs = HashMap::new();
+        assert_eq!(CalculationFormula::SumValue.evaluate(&inputs), None);
+        assert_eq!(CalculationFormula::AverageValue.evaluate(&inputs), None);
+    }
 }
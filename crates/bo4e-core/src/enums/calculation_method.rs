@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Kalkulationsmethode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Kalkulationsmethode"))]
 #[non_exhaustive]
 pub enum CalculationMethod {
@@ -118,6 +119,7 @@ impl CalculationMethod {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::test_util::assert_all_variants_named;
 
     #[test]
     fn test_serialize() {
@@ -164,4 +166,29 @@ mod tests {
             assert_eq!(method, parsed);
         }
     }
+
+    #[test]
+    fn test_all_variants_named() {
+        assert_all_variants_named(
+            &all_variants!(CalculationMethod {
+                Steps,
+                Zones,
+                PreZoneBasePrice,
+                Sigmoid,
+                ReactivePowerAbove50Percent,
+                ReactivePowerAbove40Percent,
+                ReactivePowerWithFreeAllowance,
+                WorkingAndBasePriceZoned,
+                CapacityChargeInstalledCapacity,
+                WorkingPriceTransportOrDistribution,
+                WorkingPriceTransportOrDistributionLocalSigmoid,
+                CapacityChargeAnnualConsumption,
+                CapacityPriceTransportOrDistribution,
+                CapacityPriceTransportOrDistributionLocalSigmoid,
+                Functions,
+                ConsumptionAboveSLPThresholdFunctionBasedLGK,
+            }),
+            CalculationMethod::german_name,
+        );
+    }
 }
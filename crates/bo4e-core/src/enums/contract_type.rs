@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Vertragsart
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Vertragsart"))]
 #[non_exhaustive]
 pub enum ContractType {
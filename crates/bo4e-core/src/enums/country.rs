@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// German: Landescode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Landescode"))]
 #[non_exhaustive]
 pub enum Country {
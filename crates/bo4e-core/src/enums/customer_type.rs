@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Kundentyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Kundentyp"))]
 #[non_exhaustive]
 pub enum CustomerType {
@@ -72,6 +73,46 @@ pub enum CustomerType {
 }
 
 impl CustomerType {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(CustomerType {
+        Commercial,
+        Private,
+        Farmer,
+        Other,
+        Household,
+        DirectHeating,
+        CommonFacilitiesMfh,
+        Church,
+        Chp,
+        ChargingStation,
+        PublicLighting,
+        StreetLighting,
+        StorageHeating,
+        InterruptibleDevice,
+        HeatPump,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::Commercial => "COMMERCIAL",
+            Self::Private => "PRIVATE",
+            Self::Farmer => "FARMER",
+            Self::Other => "OTHER",
+            Self::Household => "HOUSEHOLD",
+            Self::DirectHeating => "DIRECT_HEATING",
+            Self::CommonFacilitiesMfh => "COMMON_FACILITIES_MFH",
+            Self::Church => "CHURCH",
+            Self::Chp => "CHP",
+            Self::ChargingStation => "CHARGING_STATION",
+            Self::PublicLighting => "PUBLIC_LIGHTING",
+            Self::StreetLighting => "STREET_LIGHTING",
+            Self::StorageHeating => "STORAGE_HEATING",
+            Self::InterruptibleDevice => "INTERRUPTIBLE_DEVICE",
+            Self::HeatPump => "HEAT_PUMP",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::DeviceCategory;
+
 /// Type of billable device.
 ///
 /// Lists possible billable device types.
@@ -9,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// German: Geraetetyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Geraetetyp"))]
 #[non_exhaustive]
 pub enum DeviceType {
@@ -197,6 +200,60 @@ pub enum DeviceType {
     StateVolumeConverter,
 }
 
+impl DeviceType {
+    /// Returns the device category this type belongs to.
+    pub fn category(&self) -> DeviceCategory {
+        match self {
+            Self::CurrentTransformer
+            | Self::VoltageTransformer
+            | Self::CombinedMeasuringTransformer
+            | Self::BlockCurrentTransformer
+            | Self::MeasuringTransformerSetImsMme
+            | Self::CombinedTransformerSetImsMme => DeviceCategory::Transformer,
+            Self::MultiplexSystem
+            | Self::AmplifierSystem
+            | Self::DataLogger
+            | Self::CommunicationConnection
+            | Self::Modem
+            | Self::TelecommunicationEquipment
+            | Self::TelephoneConnection
+            | Self::ModemGsm
+            | Self::ModemGprs
+            | Self::ModemRadio
+            | Self::ModemGsmWithoutLoadProfile
+            | Self::ModemGsmWithLoadProfile
+            | Self::ModemLandline
+            | Self::ModemGprsWithLoadProfile
+            | Self::PlcCommunication
+            | Self::EthernetCommunication
+            | Self::DslCommunication
+            | Self::LteCommunication => DeviceCategory::CommunicationEquipment,
+            Self::ControlDevice
+            | Self::TariffSwitchingDevice
+            | Self::RippleControlReceiver
+            | Self::TariffSwitchingDeviceImsMme
+            | Self::RippleControlReceiverImsMme
+            | Self::OtherDevice => DeviceCategory::TechnicalControlEquipment,
+            Self::VolumeConverter
+            | Self::TemperatureCompensation
+            | Self::CompactVolumeConverter
+            | Self::SystemVolumeConverter
+            | Self::TemperatureVolumeConverter
+            | Self::StateVolumeConverter => DeviceCategory::VolumeConverter,
+            Self::IntelligentMeasuringSystem => DeviceCategory::SmartMeterGateway,
+            Self::FlatRateSystem
+            | Self::SummationDevice
+            | Self::PulseGenerator
+            | Self::ModernMeasuringDevice
+            | Self::OptionalAdditionalMeteringDevice
+            | Self::MaximumDemandIndicator
+            | Self::Edl21
+            | Self::Edl40MeterAttachment
+            | Self::Edl40 => DeviceCategory::MeteringDevice,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +280,20 @@ mod tests {
             assert_eq!(dtype, parsed);
         }
     }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(
+            DeviceType::ModernMeasuringDevice.category(),
+            DeviceCategory::MeteringDevice
+        );
+        assert_eq!(
+            DeviceType::ModemGsm.category(),
+            DeviceCategory::CommunicationEquipment
+        );
+        assert_eq!(
+            DeviceType::CurrentTransformer.category(),
+            DeviceCategory::Transformer
+        );
+    }
 }
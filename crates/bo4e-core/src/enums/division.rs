@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Sparte
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Sparte"))]
 #[non_exhaustive]
 pub enum Division {
@@ -42,6 +43,30 @@ pub enum Division {
 }
 
 impl Division {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(Division {
+        Electricity,
+        Gas,
+        DistrictHeating,
+        LocalHeating,
+        Water,
+        Wastewater,
+        ElectricityAndGas,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::Electricity => "ELECTRICITY",
+            Self::Gas => "GAS",
+            Self::DistrictHeating => "DISTRICT_HEATING",
+            Self::LocalHeating => "LOCAL_HEATING",
+            Self::Water => "WATER",
+            Self::Wastewater => "WASTEWATER",
+            Self::ElectricityAndGas => "ELECTRICITY_AND_GAS",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
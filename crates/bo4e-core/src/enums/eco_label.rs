@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Oekolabel
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Oekolabel"))]
 #[non_exhaustive]
 pub enum EcoLabel {
@@ -79,6 +80,18 @@ impl EcoLabel {
             Self::WatergreenPlus => "Watergreen Plus",
         }
     }
+
+    /// Returns the organisation awarding the label, where known.
+    pub fn issuing_body(&self) -> Option<&'static str> {
+        match self {
+            Self::GruenerStrom
+            | Self::GruenerStromGold
+            | Self::GruenerStromSilber
+            | Self::GruenesGas => Some("Grüner Strom Label e.V."),
+            Self::OkPower => Some("EnergieVision e.V."),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -118,4 +131,14 @@ mod tests {
             assert_eq!(label, parsed);
         }
     }
+
+    #[test]
+    fn test_issuing_body() {
+        assert_eq!(
+            EcoLabel::GruenerStromGold.issuing_body(),
+            Some("Grüner Strom Label e.V.")
+        );
+        assert_eq!(EcoLabel::OkPower.issuing_body(), Some("EnergieVision e.V."));
+        assert_eq!(EcoLabel::Watergreen.issuing_body(), None);
+    }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Energierichtung
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Energierichtung"))]
 #[non_exhaustive]
 pub enum EnergyDirection {
@@ -22,6 +23,17 @@ pub enum EnergyDirection {
 }
 
 impl EnergyDirection {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(EnergyDirection { FeedOut, FeedIn });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::FeedOut => "FEED_OUT",
+            Self::FeedIn => "FEED_IN",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -29,6 +41,28 @@ impl EnergyDirection {
             Self::FeedIn => "Einspeisung",
         }
     }
+
+    /// Returns the sign used when summing energy amounts.
+    ///
+    /// Consumption (feed-out from the grid) counts as `+1`, generation
+    /// (feed-in to the grid) as `-1`, so a negative net sum means the
+    /// location produced more than it consumed.
+    pub fn sign(&self) -> i8 {
+        match self {
+            Self::FeedOut => 1,
+            Self::FeedIn => -1,
+        }
+    }
+
+    /// Returns `true` if energy is fed into the grid (generation).
+    pub fn is_generation(&self) -> bool {
+        matches!(self, Self::FeedIn)
+    }
+
+    /// Returns `true` if energy is taken from the grid (consumption).
+    pub fn is_consumption(&self) -> bool {
+        matches!(self, Self::FeedOut)
+    }
 }
 
 #[cfg(test)]
@@ -67,4 +101,24 @@ mod tests {
             assert_eq!(dir, parsed);
         }
     }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(EnergyDirection::FeedOut.sign(), 1);
+        assert_eq!(EnergyDirection::FeedIn.sign(), -1);
+
+        assert!(EnergyDirection::FeedIn.is_generation());
+        assert!(!EnergyDirection::FeedIn.is_consumption());
+        assert!(EnergyDirection::FeedOut.is_consumption());
+        assert!(EnergyDirection::FeedIn.sign() < 0);
+
+        let net: f64 = [
+            (EnergyDirection::FeedOut, 300.0),
+            (EnergyDirection::FeedIn, 500.0),
+        ]
+        .iter()
+        .map(|(dir, kwh)| f64::from(dir.sign()) * kwh)
+        .sum();
+        assert_eq!(net, -200.0);
+    }
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Rechnungsstatus
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Rechnungsstatus"))]
 #[non_exhaustive]
 pub enum InvoiceStatus {
@@ -42,6 +43,39 @@ impl InvoiceStatus {
             Self::Paid => "Bezahlt",
         }
     }
+
+    /// Returns the English name.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Self::Unchecked => "Unchecked",
+            Self::CheckedOk => "Checked OK",
+            Self::CheckedWithErrors => "Checked with errors",
+            Self::Booked => "Booked",
+            Self::Paid => "Paid",
+        }
+    }
+
+    /// Returns `true` if an invoice in this status may be dunned once overdue.
+    ///
+    /// Only verified, unpaid invoices qualify: unchecked or faulty invoices
+    /// must be cleared first, and paid invoices are settled.
+    pub fn is_overdue_eligible(&self) -> bool {
+        matches!(self, Self::CheckedOk | Self::Booked)
+    }
+
+    /// Returns the next status in the processing of a received invoice.
+    ///
+    /// The workflow is Unchecked → CheckedOk → Booked; a booked invoice has
+    /// reached its end and is only awaiting payment. Faulty and paid invoices
+    /// leave the workflow and return `None`. Dunning levels are not part of
+    /// the status.
+    pub fn next_processing_status(&self) -> Option<InvoiceStatus> {
+        match self {
+            Self::Unchecked => Some(Self::CheckedOk),
+            Self::CheckedOk => Some(Self::Booked),
+            Self::CheckedWithErrors | Self::Booked | Self::Paid => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -72,6 +106,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_names() {
+        assert_eq!(InvoiceStatus::Paid.english_name(), "Paid");
+        assert_eq!(InvoiceStatus::Paid.german_name(), "Bezahlt");
+        assert_eq!(
+            InvoiceStatus::CheckedWithErrors.english_name(),
+            "Checked with errors"
+        );
+    }
+
+    #[test]
+    fn test_dunning_eligibility() {
+        assert!(InvoiceStatus::CheckedOk.is_overdue_eligible());
+        assert!(InvoiceStatus::Booked.is_overdue_eligible());
+        assert!(!InvoiceStatus::Paid.is_overdue_eligible());
+        assert!(!InvoiceStatus::Unchecked.is_overdue_eligible());
+        assert!(!InvoiceStatus::CheckedWithErrors.is_overdue_eligible());
+    }
+
+    #[test]
+    fn test_next_processing_status() {
+        assert_eq!(
+            InvoiceStatus::CheckedOk.next_processing_status(),
+            Some(InvoiceStatus::Booked)
+        );
+        assert_eq!(
+            InvoiceStatus::Unchecked.next_processing_status(),
+            Some(InvoiceStatus::CheckedOk)
+        );
+        assert_eq!(InvoiceStatus::Booked.next_processing_status(), None);
+        assert_eq!(InvoiceStatus::Paid.next_processing_status(), None);
+    }
+
     #[test]
     fn test_roundtrip() {
         for status in [
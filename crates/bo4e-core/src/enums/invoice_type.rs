@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Rechnungstyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Rechnungstyp"))]
 #[non_exhaustive]
 pub enum InvoiceType {
@@ -82,6 +83,25 @@ impl InvoiceType {
             Self::Additional13thInvoice => "Zusaetzliche 13te Rechnung",
         }
     }
+
+    /// Returns the English name.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Self::EndCustomerInvoice => "End customer invoice",
+            Self::NetworkUsageInvoice => "Network usage invoice",
+            Self::SurplusDeficitInvoice => "Surplus/deficit quantity invoice",
+            Self::MeteringPointOperationInvoice => "Metering point operation invoice",
+            Self::ProcurementInvoice => "Procurement invoice",
+            Self::BalancingEnergyInvoice => "Balancing energy invoice",
+            Self::FinalInvoice => "Final invoice",
+            Self::InstalmentInvoice => "Instalment invoice",
+            Self::PeriodicInvoice => "Periodic invoice",
+            Self::MonthlyInvoice => "Monthly invoice",
+            Self::InterimInvoice => "Interim invoice",
+            Self::Integrated13thInvoice => "Integrated 13th invoice",
+            Self::Additional13thInvoice => "Additional 13th invoice",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +132,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            InvoiceType::InstalmentInvoice.german_name(),
+            "Abschlagsrechnung"
+        );
+        assert_eq!(
+            InvoiceType::InstalmentInvoice.english_name(),
+            "Instalment invoice"
+        );
+    }
+
     #[test]
     fn test_roundtrip() {
         for invoice_type in [
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Marktrolle
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Marktrolle"))]
 #[non_exhaustive]
 pub enum MarketRole {
@@ -64,6 +65,42 @@ pub enum MarketRole {
 }
 
 impl MarketRole {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(MarketRole {
+        TechnicalResourceOperator,
+        BalanceCoordinator,
+        BalanceResponsibleParty,
+        DataProvider,
+        DeploymentResponsible,
+        EnergyServiceProvider,
+        CapacityUser,
+        Supplier,
+        MarketAreaManager,
+        MeteringPointOperator,
+        NetworkOperator,
+        RegisterOperator,
+        TransmissionSystemOperator,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::TechnicalResourceOperator => "TECHNICAL_RESOURCE_OPERATOR",
+            Self::BalanceCoordinator => "BALANCE_COORDINATOR",
+            Self::BalanceResponsibleParty => "BALANCE_RESPONSIBLE_PARTY",
+            Self::DataProvider => "DATA_PROVIDER",
+            Self::DeploymentResponsible => "DEPLOYMENT_RESPONSIBLE",
+            Self::EnergyServiceProvider => "ENERGY_SERVICE_PROVIDER",
+            Self::CapacityUser => "CAPACITY_USER",
+            Self::Supplier => "SUPPLIER",
+            Self::MarketAreaManager => "MARKET_AREA_MANAGER",
+            Self::MeteringPointOperator => "METERING_POINT_OPERATOR",
+            Self::NetworkOperator => "NETWORK_OPERATOR",
+            Self::RegisterOperator => "REGISTER_OPERATOR",
+            Self::TransmissionSystemOperator => "TRANSMISSION_SYSTEM_OPERATOR",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Messwertstatus
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Messwertstatus"))]
 #[non_exhaustive]
 pub enum MeasuredValueStatus {
@@ -64,6 +65,23 @@ impl MeasuredValueStatus {
             Self::Missing => "Fehlt",
         }
     }
+
+    /// Returns how unreliable a value with this status is, from 0 (read) upwards.
+    ///
+    /// Used to carry the worst status of the inputs into derived values.
+    pub fn quality_rank(&self) -> u8 {
+        match self {
+            Self::Read => 0,
+            Self::EnergySummed => 1,
+            Self::DeliveryNoteInfo => 2,
+            Self::Preliminary => 3,
+            Self::Proposed => 4,
+            Self::Substitute => 5,
+            Self::Forecast => 6,
+            Self::NotUsable => 7,
+            Self::Missing => 8,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,11 +4,13 @@ use serde::{Deserialize, Serialize};
 
 /// Size of a gas meter.
 ///
-/// Lists possible sizes of gas meters.
+/// Lists possible sizes of gas meters. Sizes are ordered by capacity, so
+/// `MeterSize::G6 > MeterSize::G4`.
 ///
 /// German: Zaehlergroesse
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Zaehlergroesse"))]
 #[non_exhaustive]
 pub enum MeterSize {
@@ -98,6 +100,58 @@ pub enum MeterSize {
 }
 
 impl MeterSize {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(MeterSize {
+        G2_5,
+        G4,
+        G6,
+        G10,
+        G16,
+        G25,
+        G40,
+        G65,
+        G100,
+        G160,
+        G250,
+        G400,
+        G650,
+        G1000,
+        G1600,
+        G2500,
+        G4000,
+        G6500,
+        G10000,
+        G12500,
+        G16000,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::G2_5 => "G2_5",
+            Self::G4 => "G4",
+            Self::G6 => "G6",
+            Self::G10 => "G10",
+            Self::G16 => "G16",
+            Self::G25 => "G25",
+            Self::G40 => "G40",
+            Self::G65 => "G65",
+            Self::G100 => "G100",
+            Self::G160 => "G160",
+            Self::G250 => "G250",
+            Self::G400 => "G400",
+            Self::G650 => "G650",
+            Self::G1000 => "G1000",
+            Self::G1600 => "G1600",
+            Self::G2500 => "G2500",
+            Self::G4000 => "G4000",
+            Self::G6500 => "G6500",
+            Self::G10000 => "G10000",
+            Self::G12500 => "G12500",
+            Self::G16000 => "G16000",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -124,6 +178,37 @@ impl MeterSize {
             Self::G16000 => "G16000",
         }
     }
+
+    /// Returns the nominal flow rate Qn in m³/h.
+    ///
+    /// The G number of a gas meter is its nominal flow rate; the maximum
+    /// flow rate Qmax is about 1.6 times as high.
+    pub fn nominal_flow_m3h(&self) -> Option<f64> {
+        let flow = match self {
+            Self::G2_5 => 2.5,
+            Self::G4 => 4.0,
+            Self::G6 => 6.0,
+            Self::G10 => 10.0,
+            Self::G16 => 16.0,
+            Self::G25 => 25.0,
+            Self::G40 => 40.0,
+            Self::G65 => 65.0,
+            Self::G100 => 100.0,
+            Self::G160 => 160.0,
+            Self::G250 => 250.0,
+            Self::G400 => 400.0,
+            Self::G650 => 650.0,
+            Self::G1000 => 1000.0,
+            Self::G1600 => 1600.0,
+            Self::G2500 => 2500.0,
+            Self::G4000 => 4000.0,
+            Self::G6500 => 6500.0,
+            Self::G10000 => 10000.0,
+            Self::G12500 => 12500.0,
+            Self::G16000 => 16000.0,
+        };
+        Some(flow)
+    }
 }
 
 #[cfg(test)]
@@ -157,4 +242,24 @@ mod tests {
             assert_eq!(size, parsed);
         }
     }
+
+    #[test]
+    fn test_nominal_flow() {
+        assert_eq!(MeterSize::G4.nominal_flow_m3h(), Some(4.0));
+        assert_eq!(MeterSize::G2_5.nominal_flow_m3h(), Some(2.5));
+        assert_eq!(MeterSize::G16000.nominal_flow_m3h(), Some(16000.0));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(MeterSize::G6 > MeterSize::G4);
+        assert!(MeterSize::G2_5 < MeterSize::G4);
+        assert!(MeterSize::G10000 > MeterSize::G6500);
+        assert_eq!(
+            [MeterSize::G10, MeterSize::G2_5, MeterSize::G4]
+                .iter()
+                .max(),
+            Some(&MeterSize::G10)
+        );
+    }
 }
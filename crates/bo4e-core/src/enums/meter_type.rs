@@ -6,9 +6,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// Types of meters for electricity and gas sectors.
 ///
+/// Values from older BO4E versions listed in [`MeterType::LEGACY_ALIASES`]
+/// are accepted when deserializing.
+///
 /// German: Zaehlertyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Zaehlertyp"))]
 #[non_exhaustive]
 pub enum MeterType {
@@ -49,7 +53,11 @@ pub enum MeterType {
     ModernMeasuringDevice,
 
     /// Intelligent measuring system / Smart meter (Intelligentes Messsystem)
-    #[serde(rename = "INTELLIGENTES_MESSSYSTEM")]
+    #[serde(
+        rename = "INTELLIGENTES_MESSSYSTEM",
+        alias = "SMARTMETER",
+        alias = "INTELLIGENTES_MESSYSTEM"
+    )]
     IntelligentMeasuringSystem,
 
     /// Electronic meter (Elektronischer Zähler)
@@ -66,6 +74,57 @@ pub enum MeterType {
 }
 
 impl MeterType {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(MeterType {
+        ThreePhaseRotatingMeter,
+        BellowsGasMeter,
+        RotaryPistonGasMeter,
+        PowerMeter,
+        MaximumDemandMeter,
+        TurbineWheelGasMeter,
+        UltrasonicGasMeter,
+        SinglePhaseAlternatingMeter,
+        ModernMeasuringDevice,
+        IntelligentMeasuringSystem,
+        ElectronicMeter,
+        VortexGasMeter,
+        WaterMeter,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::ThreePhaseRotatingMeter => "THREE_PHASE_ROTATING_METER",
+            Self::BellowsGasMeter => "BELLOWS_GAS_METER",
+            Self::RotaryPistonGasMeter => "ROTARY_PISTON_GAS_METER",
+            Self::PowerMeter => "POWER_METER",
+            Self::MaximumDemandMeter => "MAXIMUM_DEMAND_METER",
+            Self::TurbineWheelGasMeter => "TURBINE_WHEEL_GAS_METER",
+            Self::UltrasonicGasMeter => "ULTRASONIC_GAS_METER",
+            Self::SinglePhaseAlternatingMeter => "SINGLE_PHASE_ALTERNATING_METER",
+            Self::ModernMeasuringDevice => "MODERN_MEASURING_DEVICE",
+            Self::IntelligentMeasuringSystem => "INTELLIGENT_MEASURING_SYSTEM",
+            Self::ElectronicMeter => "ELECTRONIC_METER",
+            Self::VortexGasMeter => "VORTEX_GAS_METER",
+            Self::WaterMeter => "WATER_METER",
+        }
+    }
+
+    /// Wire values of older BO4E versions and the variants they map to.
+    pub const LEGACY_ALIASES: &'static [(&'static str, MeterType)] = &[
+        ("SMARTMETER", MeterType::IntelligentMeasuringSystem),
+        (
+            "INTELLIGENTES_MESSYSTEM",
+            MeterType::IntelligentMeasuringSystem,
+        ),
+    ];
+
+    /// Returns true if `raw` is a legacy wire value that is upgraded when
+    /// deserializing, so callers can log the upgrade.
+    pub fn was_legacy_alias(raw: &str) -> bool {
+        Self::LEGACY_ALIASES.iter().any(|(alias, _)| *alias == raw)
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -128,4 +187,18 @@ mod tests {
             assert_eq!(mtype, parsed);
         }
     }
+
+    #[test]
+    fn test_legacy_aliases() {
+        for (alias, variant) in MeterType::LEGACY_ALIASES {
+            let parsed: MeterType = serde_json::from_str(&format!("\"{}\"", alias)).unwrap();
+            assert_eq!(parsed, *variant);
+            assert!(MeterType::was_legacy_alias(alias));
+        }
+        assert_eq!(
+            serde_json::to_string(&MeterType::IntelligentMeasuringSystem).unwrap(),
+            r#""INTELLIGENTES_MESSSYSTEM""#
+        );
+        assert!(!MeterType::was_legacy_alias("INTELLIGENTES_MESSSYSTEM"));
+    }
 }
@@ -2,6 +2,28 @@
 //!
 //! This module contains all the enum types used in BO4E, organized by category.
 
+/// Build an array of every variant of a fieldless enum.
+///
+/// The variants are also matched exhaustively, so adding a variant to the
+/// enum without listing it here fails to compile.
+///
+/// ```rust,ignore
+/// let all = all_variants!(Division { Electricity, Gas });
+/// ```
+macro_rules! all_variants {
+    ($ty:ident { $($variant:ident),+ $(,)? }) => {{
+        fn _exhaustive(value: $ty) {
+            match value {
+                $($ty::$variant)|+ => {}
+            }
+        }
+        [$($ty::$variant),+]
+    }};
+}
+
+#[cfg(test)]
+pub(crate) mod test_util;
+
 // Type discriminators
 mod bo_type;
 mod com_type;
@@ -84,10 +106,12 @@ pub use unit::Unit;
 pub use unit_prefix::UnitPrefix;
 
 // Calculation and operations
+mod aggregation_method;
 mod arithmetic_operation;
 mod calculation_formula;
 mod rounding_mode;
 
+pub use aggregation_method::AggregationMethod;
 pub use arithmetic_operation::ArithmeticOperation;
 pub use calculation_formula::CalculationFormula;
 pub use rounding_mode::RoundingMode;
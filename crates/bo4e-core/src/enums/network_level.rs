@@ -1,7 +1,11 @@
 //! Network level (Netzebene) enumeration.
 
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
+use super::VoltageLevel;
+
 /// Network level within electricity and gas energy types.
 ///
 /// Lists possible network levels for electricity (voltage levels) and gas (pressure levels).
@@ -9,6 +13,7 @@ use serde::{Deserialize, Serialize};
 /// German: Netzebene
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Netzebene"))]
 #[non_exhaustive]
 pub enum NetworkLevel {
@@ -93,6 +98,49 @@ impl NetworkLevel {
             Self::HighPressure | Self::MediumPressure | Self::LowPressure
         )
     }
+
+    /// Returns the position within its grid, starting at 0 for the lowest level.
+    ///
+    /// Electricity transformation levels rank between the two voltage levels
+    /// they connect. Ranks of electricity and gas levels are not comparable.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::LowVoltage => 0,
+            Self::MediumLowVoltageTransformation => 1,
+            Self::MediumVoltage => 2,
+            Self::HighMediumVoltageTransformation => 3,
+            Self::HighVoltage => 4,
+            Self::ExtraHighHighVoltageTransformation => 5,
+            Self::ExtraHighVoltage => 6,
+            Self::LowPressure => 0,
+            Self::MediumPressure => 1,
+            Self::HighPressure => 2,
+        }
+    }
+
+    /// Returns the voltage level of an electricity network level.
+    ///
+    /// Transformation levels and gas levels have no single voltage level.
+    pub fn voltage_level(&self) -> Option<VoltageLevel> {
+        match self {
+            Self::LowVoltage => Some(VoltageLevel::LowVoltage),
+            Self::MediumVoltage => Some(VoltageLevel::MediumVoltage),
+            Self::HighVoltage => Some(VoltageLevel::HighVoltage),
+            Self::ExtraHighVoltage => Some(VoltageLevel::ExtraHighVoltage),
+            _ => None,
+        }
+    }
+}
+
+/// Levels of the same grid are ordered from low to high; electricity and
+/// gas levels are incomparable.
+impl PartialOrd for NetworkLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_electricity() != other.is_electricity() {
+            return None;
+        }
+        Some(self.rank().cmp(&other.rank()))
+    }
 }
 
 #[cfg(test)]
@@ -131,6 +179,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ordering() {
+        use NetworkLevel::*;
+        let electricity = [
+            LowVoltage,
+            MediumLowVoltageTransformation,
+            MediumVoltage,
+            HighMediumVoltageTransformation,
+            HighVoltage,
+            ExtraHighHighVoltageTransformation,
+            ExtraHighVoltage,
+        ];
+        for pair in electricity.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        }
+        assert!(LowPressure < MediumPressure);
+        assert!(MediumPressure < HighPressure);
+        assert_eq!(HighVoltage.partial_cmp(&HighPressure), None);
+        assert_eq!(LowVoltage.partial_cmp(&LowVoltage), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_voltage_level() {
+        assert_eq!(
+            NetworkLevel::MediumVoltage.voltage_level(),
+            Some(VoltageLevel::MediumVoltage)
+        );
+        assert_eq!(
+            NetworkLevel::HighMediumVoltageTransformation.voltage_level(),
+            None
+        );
+        assert_eq!(NetworkLevel::LowPressure.voltage_level(), None);
+    }
+
     #[test]
     fn test_is_electricity() {
         assert!(NetworkLevel::LowVoltage.is_electricity());
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Regionskriteriumtyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Regionskriteriumtyp"))]
 #[non_exhaustive]
 pub enum RegionCriterionType {
@@ -187,6 +188,7 @@ impl RegionCriterionType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::test_util::assert_all_variants_named;
 
     #[test]
     fn test_serialize() {
@@ -250,4 +252,46 @@ mod tests {
             assert_eq!(criterion, parsed);
         }
     }
+
+    #[test]
+    fn test_all_variants_named() {
+        assert_all_variants_named(
+            &all_variants!(RegionCriterionType {
+                FederalStateCode,
+                FederalStateName,
+                MarketAreaNumber,
+                MarketAreaName,
+                ControlAreaNumber,
+                ControlAreaName,
+                ElectricityNetwork,
+                GasNetwork,
+                ElectricityNetworkOperatorNumber,
+                GasNetworkOperatorNumber,
+                ElectricityNetworkOperatorName,
+                GasNetworkOperatorName,
+                BalancingAreaNumber,
+                MeteringServiceOperatorNumber,
+                MeteringServiceOperatorName,
+                SupplierNumber,
+                SupplierName,
+                ElectricityBasicSupplierNumber,
+                ElectricityBasicSupplierName,
+                GasBasicSupplierNumber,
+                GasBasicSupplierName,
+                DistrictName,
+                DistrictCode,
+                MunicipalityName,
+                MunicipalityCode,
+                PostalCode,
+                City,
+                PostalCity,
+                MunicipalityPopulation,
+                CityPopulation,
+                RadiusKm,
+                Nationwide,
+                PostalCodeRange,
+            }),
+            RegionCriterionType::german_name,
+        );
+    }
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Dienstleistungstyp
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Dienstleistungstyp"))]
 #[non_exhaustive]
 pub enum ServiceType {
@@ -206,6 +207,7 @@ impl ServiceType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::test_util::assert_all_variants_named_sharing;
 
     #[test]
     fn test_serialize() {
@@ -248,4 +250,50 @@ mod tests {
             assert_eq!(service_type, parsed);
         }
     }
+
+    #[test]
+    fn test_all_variants_named() {
+        // BO4E describes both monthly remote reading codes the same way.
+        assert_all_variants_named_sharing(
+            &all_variants!(ServiceType {
+                DataProvisionDaily,
+                DataProvisionWeekly,
+                DataProvisionMonthly,
+                DataProvisionYearly,
+                DataProvisionHistoricalLoadProfiles,
+                DataProvisionHourly,
+                DataProvisionQuarterly,
+                DataProvisionSemiAnnually,
+                DataProvisionMonthlyAdditional,
+                DataProvisionOneTime,
+                RemoteReading2xDaily,
+                RemoteReadingDaily,
+                ManualReadingMsb,
+                RemoteReadingMonthly,
+                RemoteReadingYearly,
+                ReadingMde,
+                RemoteReading,
+                RemoteReadingAdditionalMsb,
+                RemoteReadingMonthlyAlt,
+                RemoteReadingHourly,
+                ManualReadingMonthly,
+                ManualReadingQuarterly,
+                ManualReadingSemiAnnually,
+                ManualReadingYearly,
+                AdditionalReadingMsb,
+                AdditionalReadingCustomer,
+                TemperatureVolumeConverterReading,
+                StateVolumeConverterReading,
+                SystemVolumeConverterReading,
+                PerTransactionReading,
+                CompactVolumeConverterReading,
+                Disconnection,
+                Reconnection,
+                ReminderFees,
+                CollectionCosts,
+            }),
+            ServiceType::german_name,
+            &["Auslesung monatlich mittels Fernauslesung"],
+        );
+    }
 }
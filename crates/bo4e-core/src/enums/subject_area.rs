@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// German: Themengebiet
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Themengebiet"))]
 #[non_exhaustive]
 pub enum SubjectArea {
@@ -329,6 +330,7 @@ impl SubjectArea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::test_util::assert_all_variants_named;
 
     #[test]
     fn test_serialize() {
@@ -368,4 +370,74 @@ mod tests {
             assert_eq!(area, parsed);
         }
     }
+
+    #[test]
+    fn test_all_variants_named() {
+        assert_all_variants_named(
+            &all_variants!(SubjectArea {
+                GeneralInformationExchange,
+                RegistrationDeregistration,
+                GeneralContact,
+                BdewDvgwContact,
+                ItTechContact,
+                Balancing,
+                BalancingAreaCoordinator,
+                BalancingAreaResponsible,
+                DataFormatsCertificatesEncryption,
+                DebtorManagement,
+                DemandSideManagement,
+                EdiAgreement,
+                Edifact,
+                EnergyDataManagement,
+                ScheduleManagement,
+                Alocat,
+                Aperak,
+                Contrl,
+                Invoic,
+                Mscons,
+                Orders,
+                Ordersp,
+                Remadv,
+                Utilmd,
+                GabiGas,
+                GeliGas,
+                DeviceReturn,
+                DeviceChange,
+                Gpke,
+                Commissioning,
+                CapacityManagement,
+                ClarificationCases,
+                LoadProfilesRlm,
+                SupplierFrameworkContract,
+                SupplierSwitch,
+                Mabis,
+                Dunning,
+                MarketAreaResponsible,
+                MarketCommunication,
+                MoreLessQuantities,
+                MsbMdl,
+                NetworkBilling,
+                NetworkCharges,
+                NetworkManagement,
+                Legal,
+                RegulatoryManagement,
+                Complaints,
+                BlockingUnblockingCollection,
+                MasterData,
+                FaultCases,
+                TechnicalQuestions,
+                InvoicConversion,
+                EncryptionSignature,
+                ContractManagement,
+                Sales,
+                Wim,
+                MeterReadingsSlp,
+                PaymentTransactions,
+                AssignmentAgreement,
+                FeedIn,
+                TransactionData,
+            }),
+            SubjectArea::german_name,
+        );
+    }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: AufAbschlagsziel
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "AufAbschlagsziel"))]
 #[non_exhaustive]
 pub enum SurchargeTarget {
@@ -49,6 +50,16 @@ impl SurchargeTarget {
             Self::TotalPrice => "Auf-/Abschlag auf den Gesamtpreis",
         }
     }
+
+    /// Returns `true` if an adjustment targeting `self` applies to `price`.
+    ///
+    /// A target matches itself; [`WorkingPriceHTNT`](Self::WorkingPriceHTNT)
+    /// also covers the high and low tariff working prices.
+    pub fn covers(&self, price: SurchargeTarget) -> bool {
+        *self == price
+            || (*self == Self::WorkingPriceHTNT
+                && matches!(price, Self::WorkingPriceHT | Self::WorkingPriceNT))
+    }
 }
 
 #[cfg(test)]
@@ -67,6 +78,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_covers() {
+        use SurchargeTarget::*;
+        assert!(BasePrice.covers(BasePrice));
+        assert!(WorkingPriceHTNT.covers(WorkingPriceHT));
+        assert!(WorkingPriceHTNT.covers(WorkingPriceNT));
+        assert!(!WorkingPriceHT.covers(WorkingPriceHTNT));
+        assert!(!WorkingPriceHTNT.covers(WorkingPriceSingleTariff));
+        assert!(!TotalPrice.covers(BasePrice));
+    }
+
     #[test]
     fn test_roundtrip() {
         for target in [
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::calc::VatRate;
+
 /// Type of tax.
 ///
 /// Used to identify different types of taxes.
@@ -9,6 +11,7 @@ use serde::{Deserialize, Serialize};
 /// German: Steuerart
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Steuerart"))]
 #[non_exhaustive]
 pub enum TaxType {
@@ -34,6 +37,17 @@ impl TaxType {
             Self::InputTax => "Vorsteuer",
         }
     }
+
+    /// Returns the VAT rate applied when no other rate is given.
+    ///
+    /// Output and input tax default to the standard rate; under reverse
+    /// charge the invoice carries no tax, as the recipient owes it.
+    pub fn vat_rate(&self) -> VatRate {
+        match self {
+            Self::ValueAddedTax | Self::InputTax => VatRate::Standard,
+            Self::ReverseCharge => VatRate::Zero,
+        }
+    }
 }
 
 #[cfg(test)]
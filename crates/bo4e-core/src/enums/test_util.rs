@@ -0,0 +1,48 @@
+//! Test helpers shared by the enumeration modules.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Assert that every variant has a distinct, non-empty name and round-trips.
+///
+/// `variants` must list every variant of the enum; build it with
+/// `all_variants!`. `name_fn` is usually `german_name`; each name must be
+/// unique so the variants can be told apart in user-facing output. Each
+/// variant must also serialize to a distinct string and deserialize back
+/// to itself.
+pub(crate) fn assert_all_variants_named<T>(variants: &[T], name_fn: impl Fn(&T) -> &'static str)
+where
+    T: Debug + PartialEq + Serialize + DeserializeOwned,
+{
+    assert_all_variants_named_sharing(variants, name_fn, &[]);
+}
+
+/// Like [`assert_all_variants_named`], but `shared` names may be used by
+/// more than one variant.
+pub(crate) fn assert_all_variants_named_sharing<T>(
+    variants: &[T],
+    name_fn: impl Fn(&T) -> &'static str,
+    shared: &[&str],
+) where
+    T: Debug + PartialEq + Serialize + DeserializeOwned,
+{
+    let mut names = HashSet::new();
+    let mut codes = HashSet::new();
+    for variant in variants {
+        let name = name_fn(variant);
+        assert!(!name.trim().is_empty(), "{:?} has an empty name", variant);
+        assert!(
+            names.insert(name) || shared.contains(&name),
+            "{:?} reuses the name {:?}",
+            variant,
+            name
+        );
+
+        let json = serde_json::to_string(variant).unwrap();
+        assert!(codes.insert(json.clone()), "{:?} is listed twice", variant);
+        let parsed: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, variant, "{} does not round-trip", json);
+    }
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Mengeneinheit
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Mengeneinheit"))]
 #[non_exhaustive]
 pub enum Unit {
@@ -117,6 +118,64 @@ pub enum Unit {
 }
 
 impl Unit {
+    /// All variants, in declaration order.
+    pub const ALL: &'static [Self] = &all_variants!(Unit {
+        Watt,
+        Kilowatt,
+        Megawatt,
+        WattHour,
+        KilowattHour,
+        MegawattHour,
+        VoltAmpereReactive,
+        KilovoltAmpereReactive,
+        VoltAmpereReactiveHour,
+        KilovoltAmpereReactiveHour,
+        CubicMeter,
+        Piece,
+        Second,
+        Minute,
+        Hour,
+        QuarterHour,
+        Day,
+        Week,
+        Month,
+        Quarter,
+        HalfYear,
+        Year,
+        Percent,
+        KilowattHourPerKelvin,
+    });
+
+    /// Returns the English code: the variant name in upper snake case.
+    pub fn english_code(&self) -> &'static str {
+        match self {
+            Self::Watt => "WATT",
+            Self::Kilowatt => "KILOWATT",
+            Self::Megawatt => "MEGAWATT",
+            Self::WattHour => "WATT_HOUR",
+            Self::KilowattHour => "KILOWATT_HOUR",
+            Self::MegawattHour => "MEGAWATT_HOUR",
+            Self::VoltAmpereReactive => "VOLT_AMPERE_REACTIVE",
+            Self::KilovoltAmpereReactive => "KILOVOLT_AMPERE_REACTIVE",
+            Self::VoltAmpereReactiveHour => "VOLT_AMPERE_REACTIVE_HOUR",
+            Self::KilovoltAmpereReactiveHour => "KILOVOLT_AMPERE_REACTIVE_HOUR",
+            Self::CubicMeter => "CUBIC_METER",
+            Self::Piece => "PIECE",
+            Self::Second => "SECOND",
+            Self::Minute => "MINUTE",
+            Self::Hour => "HOUR",
+            Self::QuarterHour => "QUARTER_HOUR",
+            Self::Day => "DAY",
+            Self::Week => "WEEK",
+            Self::Month => "MONTH",
+            Self::Quarter => "QUARTER",
+            Self::HalfYear => "HALF_YEAR",
+            Self::Year => "YEAR",
+            Self::Percent => "PERCENT",
+            Self::KilowattHourPerKelvin => "KILOWATT_HOUR_PER_KELVIN",
+        }
+    }
+
     /// Returns the German name.
     pub fn german_name(&self) -> &'static str {
         match self {
@@ -146,6 +205,55 @@ impl Unit {
             Self::KilowattHourPerKelvin => "Kilowattstunde pro Kelvin",
         }
     }
+
+    /// Convert a value between scaled units of the same quantity.
+    ///
+    /// Supports active energy (Wh/kWh/MWh), active power (W/kW/MW) and
+    /// their reactive counterparts (varh/kvarh, var/kvar). Returns `None`
+    /// if the units measure different quantities, e.g. energy and power.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bo4e_core::enums::Unit;
+    ///
+    /// assert_eq!(Unit::convert(1500.0, Unit::WattHour, Unit::KilowattHour), Some(1.5));
+    /// assert_eq!(Unit::convert(1.0, Unit::KilowattHour, Unit::Kilowatt), None);
+    /// ```
+    pub fn convert(value: f64, from: Unit, to: Unit) -> Option<f64> {
+        if from == to {
+            return Some(value);
+        }
+        let (from_quantity, from_factor) = from.scale()?;
+        let (to_quantity, to_factor) = to.scale()?;
+        (from_quantity == to_quantity).then(|| value * from_factor / to_factor)
+    }
+
+    /// The physical quantity of a scalable unit and its factor to the base unit.
+    fn scale(&self) -> Option<(Quantity, f64)> {
+        match self {
+            Self::Watt => Some((Quantity::Power, 1.0)),
+            Self::Kilowatt => Some((Quantity::Power, 1e3)),
+            Self::Megawatt => Some((Quantity::Power, 1e6)),
+            Self::WattHour => Some((Quantity::Energy, 1.0)),
+            Self::KilowattHour => Some((Quantity::Energy, 1e3)),
+            Self::MegawattHour => Some((Quantity::Energy, 1e6)),
+            Self::VoltAmpereReactive => Some((Quantity::ReactivePower, 1.0)),
+            Self::KilovoltAmpereReactive => Some((Quantity::ReactivePower, 1e3)),
+            Self::VoltAmpereReactiveHour => Some((Quantity::ReactiveEnergy, 1.0)),
+            Self::KilovoltAmpereReactiveHour => Some((Quantity::ReactiveEnergy, 1e3)),
+            _ => None,
+        }
+    }
+}
+
+/// Physical quantity measured by a unit, used to reject incompatible conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantity {
+    Power,
+    Energy,
+    ReactivePower,
+    ReactiveEnergy,
 }
 
 #[cfg(test)]
@@ -205,4 +313,45 @@ mod tests {
             assert_eq!(unit, parsed);
         }
     }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(
+            Unit::convert(1500.0, Unit::WattHour, Unit::KilowattHour),
+            Some(1.5)
+        );
+        assert_eq!(
+            Unit::convert(2.5, Unit::MegawattHour, Unit::KilowattHour),
+            Some(2500.0)
+        );
+        assert_eq!(
+            Unit::convert(750.0, Unit::Kilowatt, Unit::Megawatt),
+            Some(0.75)
+        );
+        assert_eq!(
+            Unit::convert(
+                3.0,
+                Unit::KilovoltAmpereReactiveHour,
+                Unit::VoltAmpereReactiveHour
+            ),
+            Some(3000.0)
+        );
+        assert_eq!(
+            Unit::convert(7.0, Unit::CubicMeter, Unit::CubicMeter),
+            Some(7.0)
+        );
+    }
+
+    #[test]
+    fn test_convert_incompatible() {
+        assert_eq!(Unit::convert(1.0, Unit::KilowattHour, Unit::Kilowatt), None);
+        assert_eq!(
+            Unit::convert(1.0, Unit::KilowattHour, Unit::KilovoltAmpereReactiveHour),
+            None
+        );
+        assert_eq!(
+            Unit::convert(1.0, Unit::CubicMeter, Unit::KilowattHour),
+            None
+        );
+    }
 }
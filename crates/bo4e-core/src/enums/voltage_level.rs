@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// German: Spannungsebene
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "json-schema", schemars(rename = "Spannungsebene"))]
 #[non_exhaustive]
 pub enum VoltageLevel {
@@ -39,6 +40,29 @@ impl VoltageLevel {
             Self::LowVoltage => "Niederspannung",
         }
     }
+
+    /// Returns the position in the grid, from 0 (low voltage) to 3 (extra high voltage).
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::LowVoltage => 0,
+            Self::MediumVoltage => 1,
+            Self::HighVoltage => 2,
+            Self::ExtraHighVoltage => 3,
+        }
+    }
+}
+
+impl PartialOrd for VoltageLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Levels are ordered by voltage, so `HighVoltage > LowVoltage`.
+impl Ord for VoltageLevel {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +77,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ordering() {
+        use VoltageLevel::*;
+        let mut levels = vec![MediumVoltage, ExtraHighVoltage, LowVoltage, HighVoltage];
+        levels.sort();
+        assert_eq!(
+            levels,
+            vec![LowVoltage, MediumVoltage, HighVoltage, ExtraHighVoltage]
+        );
+        assert!(HighVoltage > LowVoltage);
+        assert!(ExtraHighVoltage > HighVoltage);
+        assert!(MediumVoltage < HighVoltage);
+        assert_eq!(LowVoltage.max(MediumVoltage), MediumVoltage);
+    }
+
     #[test]
     fn test_roundtrip() {
         for level in [
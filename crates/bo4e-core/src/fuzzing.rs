@@ -0,0 +1,101 @@
+//! Generators for `arbitrary::Arbitrary` field overrides.
+//!
+//! The derived implementations would fill identifiers with random strings and
+//! floats with NaN or infinity, neither of which survives a JSON round trip or
+//! resembles real data. Fields that need more care point here with
+//! `#[arbitrary(with = ...)]`.
+
+use arbitrary::{Result, Unstructured};
+use chrono::{DateTime, Utc};
+
+/// Random ASCII digits.
+fn digits(u: &mut Unstructured<'_>, len: usize) -> Result<String> {
+    (0..len)
+        .map(|_| Ok(char::from(b'0' + u.int_in_range(0..=9)?)))
+        .collect()
+}
+
+/// Maybe a value produced by `f`.
+fn maybe<T>(
+    u: &mut Unstructured<'_>,
+    f: impl FnOnce(&mut Unstructured<'_>) -> Result<T>,
+) -> Result<Option<T>> {
+    if u.arbitrary()? {
+        f(u).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Market location ID: 11 digits.
+pub(crate) fn malo_id(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| digits(u, 11))
+}
+
+/// Metering location ID: country code and 31 alphanumeric characters.
+pub(crate) fn melo_id(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| Ok(format!("DE{}", digits(u, 31)?)))
+}
+
+/// Meter number in the style of `1EMH0012345678`.
+pub(crate) fn meter_number(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| Ok(format!("1EMH{}", digits(u, 10)?)))
+}
+
+/// BDEW code number with a valid check digit.
+pub(crate) fn bdew_code(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| {
+        let mut code = format!("99{}", digits(u, 10)?);
+        let weighted: u32 = code
+            .bytes()
+            .enumerate()
+            .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 1 } else { 3 })
+            .sum();
+        code.push(char::from(b'0' + ((10 - weighted % 10) % 10) as u8));
+        Ok(code)
+    })
+}
+
+/// OBIS code such as `1-0:1.8.0`.
+pub(crate) fn obis_code(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| {
+        let medium = u.choose(&[1u8, 7])?;
+        let quantity = u.int_in_range(1u8..=16)?;
+        let tariff = u.int_in_range(0u8..=2)?;
+        Ok(format!("{medium}-0:{quantity}.8.{tariff}"))
+    })
+}
+
+/// German postal code: 5 digits.
+pub(crate) fn postal_code(u: &mut Unstructured<'_>) -> Result<Option<String>> {
+    maybe(u, |u| digits(u, 5))
+}
+
+/// Finite non-negative quantity with three decimal places.
+pub(crate) fn quantity(u: &mut Unstructured<'_>) -> Result<Option<f64>> {
+    maybe(u, |u| {
+        Ok(f64::from(u.int_in_range(0..=1_000_000_000)?) / 1000.0)
+    })
+}
+
+/// Latitude in degrees.
+pub(crate) fn latitude(u: &mut Unstructured<'_>) -> Result<Option<f64>> {
+    maybe(u, |u| {
+        Ok(f64::from(u.int_in_range(-90_000_000..=90_000_000)?) / 1e6)
+    })
+}
+
+/// Longitude in degrees.
+pub(crate) fn longitude(u: &mut Unstructured<'_>) -> Result<Option<f64>> {
+    maybe(u, |u| {
+        Ok(f64::from(u.int_in_range(-180_000_000..=180_000_000)?) / 1e6)
+    })
+}
+
+/// Timestamp between 2000 and 2050, to the second.
+pub(crate) fn timestamp(u: &mut Unstructured<'_>) -> Result<Option<DateTime<Utc>>> {
+    maybe(u, |u| {
+        let seconds = u.int_in_range(946_684_800..=2_524_608_000)?;
+        Ok(DateTime::from_timestamp(seconds, 0).unwrap_or_default())
+    })
+}
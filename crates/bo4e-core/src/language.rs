@@ -0,0 +1,19 @@
+//! Output language selection.
+
+/// Controls JSON field naming language.
+///
+/// The language only applies to serialization; deserialization accepts
+/// German and English field names alike. `bo4e-serde` provides
+/// `from_json_strict_language` to enforce a single language on input.
+///
+/// Also selects the language of human-readable text such as
+/// [`Person::formatted_name`](crate::bo::Person::formatted_name).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonLanguage {
+    /// German field names (e.g., "zaehlernummer", "marktlokationsId")
+    /// This is the BO4E standard format.
+    #[default]
+    German,
+    /// English field names (e.g., "meterNumber", "marketLocationId")
+    English,
+}
@@ -7,6 +7,9 @@
 //! - Business Objects (BOs): Top-level entities like `Meter`, `MarketLocation`
 //! - Components (COMs): Composite types like `Address`, `Price`
 //! - Enumerations: Type-safe enums for all BO4E enum values
+//! - Calculation helpers: Rounding and arithmetic for monetary values
+//! - Standard load profiles: Day classification and profile lookup
+//! - Validation: Runtime consistency checks via the `Validate` trait
 //!
 //! ## Example
 //!
@@ -18,9 +21,23 @@
 
 pub mod additional_attribute;
 pub mod bo;
+pub mod calc;
 pub mod com;
 pub mod enums;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+pub mod language;
+pub mod ser;
+pub mod slp;
 pub mod traits;
+pub mod validation;
 
 pub use additional_attribute::AdditionalAttribute;
-pub use traits::{Bo4eMeta, Bo4eObject};
+pub use language::JsonLanguage;
+pub use traits::{Bo4eMeta, Bo4eObject, CostReport, VersionError};
+pub use validation::{Validate, ValidationIssue};
+
+/// BO4E schema version implemented by this crate.
+///
+/// Stamped into `_version` by [`Bo4eMeta::for_type`].
+pub const BO4E_VERSION: &str = "202401.0.1";
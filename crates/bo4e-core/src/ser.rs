@@ -0,0 +1,105 @@
+//! Field skipping rules used by the `Serialize` derives.
+//!
+//! Unset optional fields and empty lists are normally omitted from the
+//! output. Serialization code (such as `bo4e-serde`) can switch to emitting
+//! them as `null` and `[]` for the current thread with [`with_emit_nulls`],
+//! or emit only the empty lists with [`with_emit_empty_lists`].
+
+use std::cell::Cell;
+use std::thread::LocalKey;
+
+thread_local! {
+    static EMIT_NULLS: Cell<bool> = const { Cell::new(false) };
+    static EMIT_EMPTY_LISTS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores a thread-local flag when dropped, even if `f` panics.
+struct FlagScope {
+    flag: &'static LocalKey<Cell<bool>>,
+    saved: bool,
+}
+
+impl FlagScope {
+    fn set(flag: &'static LocalKey<Cell<bool>>, value: bool) -> Self {
+        let saved = flag.with(|f| f.replace(value));
+        Self { flag, saved }
+    }
+}
+
+impl Drop for FlagScope {
+    fn drop(&mut self) {
+        self.flag.with(|f| f.set(self.saved));
+    }
+}
+
+/// Run `f` with unset fields emitted (`true`) or omitted (`false`).
+pub fn with_emit_nulls<T, F: FnOnce() -> T>(emit: bool, f: F) -> T {
+    let _scope = FlagScope::set(&EMIT_NULLS, emit);
+    f()
+}
+
+/// Returns `true` if unset fields are currently emitted.
+pub fn emit_nulls() -> bool {
+    EMIT_NULLS.with(Cell::get)
+}
+
+/// Run `f` with empty lists emitted (`true`) or omitted (`false`).
+///
+/// Unlike [`with_emit_nulls`], unset optional fields stay omitted.
+pub fn with_emit_empty_lists<T, F: FnOnce() -> T>(emit: bool, f: F) -> T {
+    let _scope = FlagScope::set(&EMIT_EMPTY_LISTS, emit);
+    f()
+}
+
+/// Returns `true` if empty lists are currently emitted.
+pub fn emit_empty_lists() -> bool {
+    EMIT_EMPTY_LISTS.with(Cell::get) || emit_nulls()
+}
+
+/// Skip predicate for `Option` fields.
+pub(crate) fn is_none<T>(value: &Option<T>) -> bool {
+    value.is_none() && !emit_nulls()
+}
+
+/// Skip predicate for `Vec` fields.
+pub(crate) fn is_empty<T>(value: &[T]) -> bool {
+    value.is_empty() && !emit_empty_lists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bo::Meter;
+
+    #[test]
+    fn test_with_emit_nulls() {
+        let meter = Meter::default();
+        assert_eq!(serde_json::to_string(&meter).unwrap(), "{}");
+
+        let json = with_emit_nulls(true, || serde_json::to_value(&meter).unwrap());
+        assert!(json["division"].is_null());
+        assert_eq!(json["registers"], serde_json::json!([]));
+        assert!(!emit_nulls());
+    }
+
+    #[test]
+    fn test_with_emit_empty_lists() {
+        let meter = Meter::default();
+        let json = with_emit_empty_lists(true, || serde_json::to_value(&meter).unwrap());
+        assert_eq!(json["registers"], serde_json::json!([]));
+        assert!(json.get("division").is_none());
+        assert!(!emit_empty_lists());
+    }
+
+    #[test]
+    fn test_flags_restored_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_emit_nulls(true, || {
+                with_emit_empty_lists(true, || panic!("inner failure"))
+            })
+        });
+        assert!(result.is_err());
+        assert!(!emit_nulls());
+        assert!(!emit_empty_lists());
+    }
+}
@@ -0,0 +1,316 @@
+//! Standard load profiles (Standardlastprofile).
+//!
+//! BDEW standard load profiles give the consumption of a customer group in
+//! 96 quarter-hour values per day, with one set of values for each
+//! combination of [`Season`] and [`DayType`]. This module classifies dates
+//! the way the BDEW method does and looks up the values for a date in the
+//! bundled BDEW tables.
+//!
+//! The tables are bundled in `data/bdew_slp.csv`, one row per profile,
+//! season, day type and quarter hour, with the values as published (W per
+//! 1,000 kWh annual consumption). `scripts/convert_bdew_slp.py` writes the
+//! file from the BDEW workbook for the electricity profiles H0, G0 to G6
+//! and L0 to L2. The converted values are not committed yet: the file only
+//! has its header row, so [`standard_profile`] returns `None` for every
+//! customer group until the script has been run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bo4e_core::slp::{DayType, Season};
+//! use chrono::NaiveDate;
+//!
+//! let sunday = NaiveDate::from_ymd_opt(2024, 7, 7).unwrap();
+//! assert_eq!(Season::of(sunday), Season::Summer);
+//! assert_eq!(DayType::of(sunday), DayType::Sunday);
+//! ```
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::enums::CustomerGroup;
+
+/// BDEW standard load profile tables, see the module documentation.
+const BDEW_TABLES: &str = include_str!("../data/bdew_slp.csv");
+
+/// Number of quarter hours in a standard load profile day.
+pub const QUARTER_HOURS: usize = 96;
+
+/// Season of the BDEW standard load profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// 1 November to 20 March
+    Winter,
+    /// 15 May to 14 September
+    Summer,
+    /// 21 March to 14 May and 15 September to 31 October
+    Transition,
+}
+
+impl Season {
+    /// Name of the season in the bundled tables.
+    fn code(self) -> &'static str {
+        match self {
+            Self::Winter => "winter",
+            Self::Summer => "summer",
+            Self::Transition => "transition",
+        }
+    }
+
+    /// Returns the season `date` falls into.
+    pub fn of(date: NaiveDate) -> Self {
+        match (date.month(), date.day()) {
+            (11..=12, _) | (1..=2, _) | (3, 1..=20) => Self::Winter,
+            (5, 15..=31) | (6..=8, _) | (9, 1..=14) => Self::Summer,
+            _ => Self::Transition,
+        }
+    }
+}
+
+/// Day type of the BDEW standard load profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayType {
+    /// Monday to Friday
+    Weekday,
+    /// Saturday, and 24 and 31 December
+    Saturday,
+    /// Sunday and nationwide public holidays
+    Sunday,
+}
+
+impl DayType {
+    /// Name of the day type in the bundled tables.
+    fn code(self) -> &'static str {
+        match self {
+            Self::Weekday => "weekday",
+            Self::Saturday => "saturday",
+            Self::Sunday => "sunday",
+        }
+    }
+
+    /// Returns the day type of `date`.
+    ///
+    /// Nationwide German public holidays count as Sundays. Christmas Eve
+    /// and New Year's Eve count as Saturdays unless they fall on a Sunday.
+    pub fn of(date: NaiveDate) -> Self {
+        if date.weekday() == Weekday::Sun || is_public_holiday(date) {
+            Self::Sunday
+        } else if date.weekday() == Weekday::Sat
+            || (date.month() == 12 && matches!(date.day(), 24 | 31))
+        {
+            Self::Saturday
+        } else {
+            Self::Weekday
+        }
+    }
+}
+
+/// BDEW profile name of a customer group, if it has a bundled profile.
+fn profile_name(group: CustomerGroup) -> Option<&'static str> {
+    match group {
+        CustomerGroup::SlpElectricityH0 => Some("H0"),
+        CustomerGroup::SlpElectricityG0 => Some("G0"),
+        CustomerGroup::SlpElectricityG1 => Some("G1"),
+        CustomerGroup::SlpElectricityG2 => Some("G2"),
+        CustomerGroup::SlpElectricityG3 => Some("G3"),
+        CustomerGroup::SlpElectricityG4 => Some("G4"),
+        CustomerGroup::SlpElectricityG5 => Some("G5"),
+        CustomerGroup::SlpElectricityG6 => Some("G6"),
+        CustomerGroup::SlpElectricityL0 => Some("L0"),
+        CustomerGroup::SlpElectricityL1 => Some("L1"),
+        CustomerGroup::SlpElectricityL2 => Some("L2"),
+        _ => None,
+    }
+}
+
+/// Returns the normalized profile of `group` for `date`.
+///
+/// The 96 quarter-hour values sum to 1.0, so multiplying them by the daily
+/// consumption gives the consumption per quarter hour. Returns `None` for
+/// customer groups without a BDEW profile and if the bundled tables lack
+/// the season and day type of `date`.
+pub fn standard_profile(group: CustomerGroup, date: NaiveDate) -> Option<Vec<f64>> {
+    let values = lookup(
+        BDEW_TABLES,
+        profile_name(group)?,
+        Season::of(date),
+        DayType::of(date),
+    )?;
+    normalize(&values)
+}
+
+/// Collect the quarter-hour values of one profile, season and day type
+/// from `table`.
+///
+/// Returns `None` unless every quarter hour has exactly one valid value.
+fn lookup(table: &str, profile: &str, season: Season, day_type: DayType) -> Option<Vec<f64>> {
+    let mut values = [None; QUARTER_HOURS];
+    for line in table.lines().skip(1) {
+        let mut cells = line.split(',').map(str::trim);
+        let (Some(name), Some(s), Some(d), Some(quarter), Some(value)) = (
+            cells.next(),
+            cells.next(),
+            cells.next(),
+            cells.next(),
+            cells.next(),
+        ) else {
+            continue;
+        };
+        if name != profile || s != season.code() || d != day_type.code() {
+            continue;
+        }
+        let slot = values.get_mut(quarter.parse::<usize>().ok()?)?;
+        if slot.replace(value.parse::<f64>().ok()?).is_some() {
+            return None;
+        }
+    }
+    values.into_iter().collect()
+}
+
+/// Scale `values` to sum to 1.0.
+fn normalize(values: &[f64]) -> Option<Vec<f64>> {
+    let total: f64 = values.iter().sum();
+    if total == 0.0 || !total.is_finite() {
+        return None;
+    }
+    Some(values.iter().map(|v| v / total).collect())
+}
+
+/// Returns `true` for public holidays observed in all German states.
+fn is_public_holiday(date: NaiveDate) -> bool {
+    let fixed = matches!(
+        (date.month(), date.day()),
+        (1, 1) | (5, 1) | (10, 3) | (12, 25) | (12, 26)
+    );
+    if fixed {
+        return true;
+    }
+    let Some(easter) = easter_sunday(date.year()) else {
+        return false;
+    };
+    let offset = date.signed_duration_since(easter).num_days();
+    // Good Friday, Easter Monday, Ascension Day, Whit Monday
+    matches!(offset, -2 | 1 | 39 | 50)
+}
+
+/// Date of Easter Sunday in the Gregorian calendar.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_season_boundaries() {
+        assert_eq!(Season::of(date(2024, 3, 20)), Season::Winter);
+        assert_eq!(Season::of(date(2024, 3, 21)), Season::Transition);
+        assert_eq!(Season::of(date(2024, 5, 15)), Season::Summer);
+        assert_eq!(Season::of(date(2024, 9, 14)), Season::Summer);
+        assert_eq!(Season::of(date(2024, 9, 15)), Season::Transition);
+        assert_eq!(Season::of(date(2024, 11, 1)), Season::Winter);
+    }
+
+    #[test]
+    fn test_day_types_and_holidays() {
+        assert_eq!(easter_sunday(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Some(date(2025, 4, 20)));
+
+        assert_eq!(DayType::of(date(2024, 1, 17)), DayType::Weekday);
+        assert_eq!(DayType::of(date(2024, 1, 20)), DayType::Saturday);
+        assert_eq!(DayType::of(date(2024, 1, 21)), DayType::Sunday);
+        // Good Friday and Whit Monday 2024
+        assert_eq!(DayType::of(date(2024, 3, 29)), DayType::Sunday);
+        assert_eq!(DayType::of(date(2024, 5, 20)), DayType::Sunday);
+        // Christmas Eve on a Tuesday
+        assert_eq!(DayType::of(date(2024, 12, 24)), DayType::Saturday);
+        assert_eq!(DayType::of(date(2024, 10, 3)), DayType::Sunday);
+    }
+
+    #[test]
+    fn test_lookup_and_normalize() {
+        let mut table = String::from("profile,season,day_type,quarter_hour,value\n");
+        for quarter in 0..QUARTER_HOURS {
+            let value = if quarter < 24 { 40.0 } else { 120.0 };
+            table.push_str(&format!("H0,winter,weekday,{quarter},{value}\n"));
+            table.push_str(&format!("H0,summer,weekday,{quarter},1\n"));
+        }
+
+        let values = lookup(&table, "H0", Season::Winter, DayType::Weekday).unwrap();
+        assert_eq!(values.len(), QUARTER_HOURS);
+        assert_eq!(values[0], 40.0);
+        assert_eq!(values[95], 120.0);
+
+        let profile = normalize(&values).unwrap();
+        assert!((profile.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(profile[72] > profile[8]);
+
+        // Missing combinations, incomplete and duplicated quarter hours
+        assert!(lookup(&table, "H0", Season::Winter, DayType::Sunday).is_none());
+        assert!(lookup(&table, "G0", Season::Winter, DayType::Weekday).is_none());
+        let incomplete = table.replace("H0,winter,weekday,95,120\n", "");
+        assert!(lookup(&incomplete, "H0", Season::Winter, DayType::Weekday).is_none());
+        let duplicated = format!("{table}H0,summer,weekday,0,1\n");
+        assert!(lookup(&duplicated, "H0", Season::Summer, DayType::Weekday).is_none());
+
+        assert!(normalize(&[0.0; QUARTER_HOURS]).is_none());
+    }
+
+    #[test]
+    fn test_groups_without_profile() {
+        assert!(standard_profile(CustomerGroup::Rlm, date(2024, 1, 17)).is_none());
+        assert!(standard_profile(CustomerGroup::SlpGasStandard, date(2024, 1, 17)).is_none());
+    }
+
+    #[test]
+    #[ignore = "needs the BDEW tables in data/bdew_slp.csv, see scripts/convert_bdew_slp.py"]
+    fn test_bundled_tables_complete() {
+        let groups = [
+            CustomerGroup::SlpElectricityH0,
+            CustomerGroup::SlpElectricityG0,
+            CustomerGroup::SlpElectricityG1,
+            CustomerGroup::SlpElectricityG2,
+            CustomerGroup::SlpElectricityG3,
+            CustomerGroup::SlpElectricityG4,
+            CustomerGroup::SlpElectricityG5,
+            CustomerGroup::SlpElectricityG6,
+            CustomerGroup::SlpElectricityL0,
+            CustomerGroup::SlpElectricityL1,
+            CustomerGroup::SlpElectricityL2,
+        ];
+        for group in groups {
+            let name = profile_name(group).unwrap();
+            for season in [Season::Winter, Season::Summer, Season::Transition] {
+                for day_type in [DayType::Weekday, DayType::Saturday, DayType::Sunday] {
+                    assert!(
+                        lookup(BDEW_TABLES, name, season, day_type).is_some(),
+                        "{name} {season:?} {day_type:?} missing"
+                    );
+                }
+            }
+        }
+
+        // H0 on a winter weekday
+        let profile = standard_profile(CustomerGroup::SlpElectricityH0, date(2024, 1, 17)).unwrap();
+        assert_eq!(profile.len(), QUARTER_HOURS);
+        assert!((profile.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+}
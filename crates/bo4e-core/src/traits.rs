@@ -1,6 +1,8 @@
 //! Core traits and types for BO4E objects.
 
-use crate::AdditionalAttribute;
+use crate::additional_attribute::AttributeValue;
+use crate::com::{Amount, CurrencyMismatch};
+use crate::{AdditionalAttribute, BO4E_VERSION};
 use serde::{Deserialize, Serialize};
 
 /// Metadata common to all BO4E objects.
@@ -11,6 +13,9 @@ use serde::{Deserialize, Serialize};
 /// - `_id`: External system ID
 /// - `zusatzAttribute`: Additional attributes for extensibility
 ///
+/// When deserializing, the underscore-free keys `boTyp`, `boVersion` and
+/// `boId` are accepted as well.
+///
 /// # Example
 ///
 /// ```rust
@@ -24,22 +29,36 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "camelCase")]
 pub struct Bo4eMeta {
     /// Type discriminator (maps to `_typ` in JSON)
-    #[serde(rename = "_typ", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "_typ",
+        alias = "boTyp",
+        skip_serializing_if = "crate::ser::is_none"
+    )]
     pub typ: Option<String>,
 
     /// BO4E schema version (maps to `_version` in JSON)
-    #[serde(rename = "_version", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "_version",
+        alias = "boVersion",
+        skip_serializing_if = "crate::ser::is_none"
+    )]
     pub version: Option<String>,
 
     /// External system ID (maps to `_id` in JSON)
-    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "_id",
+        alias = "boId",
+        skip_serializing_if = "crate::ser::is_none"
+    )]
     pub id: Option<String>,
 
     /// Additional attributes for extensibility
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "crate::ser::is_empty")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub zusatz_attribute: Vec<AdditionalAttribute>,
 }
 
@@ -52,12 +71,50 @@ impl Bo4eMeta {
         }
     }
 
+    /// Create metadata for `T`, with its German type name and [`BO4E_VERSION`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bo4e_core::bo::Meter;
+    /// use bo4e_core::Bo4eMeta;
+    ///
+    /// let meta = Bo4eMeta::for_type::<Meter>();
+    /// assert_eq!(meta.typ.as_deref(), Some("Zaehler"));
+    /// ```
+    pub fn for_type<T: Bo4eObject>() -> Self {
+        Self::with_type(T::type_name_german()).version(BO4E_VERSION)
+    }
+
     /// Set the version.
     pub fn version(mut self, version: impl Into<String>) -> Self {
         self.version = Some(version.into());
         self
     }
 
+    /// Get the BO4E schema version.
+    ///
+    /// Named `version_str` because [`Bo4eMeta::version`] is the builder setter.
+    pub fn version_str(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Set the version after checking it against the BO4E version scheme.
+    ///
+    /// Accepted versions look like `202401.0.1` or `v202401.0.1`: a
+    /// `YYYYMM` release followed by functional and technical version
+    /// numbers, optionally with a pre-release or build suffix such as
+    /// `-rc1` or `+dev`. On error the current version is left unchanged.
+    pub fn set_version(&mut self, version: &str) -> Result<(), VersionError> {
+        if !is_valid_version(version) {
+            return Err(VersionError {
+                version: version.to_string(),
+            });
+        }
+        self.version = Some(version.to_string());
+        Ok(())
+    }
+
     /// Set the external ID.
     pub fn id(mut self, id: impl Into<String>) -> Self {
         self.id = Some(id.into());
@@ -69,8 +126,76 @@ impl Bo4eMeta {
         self.zusatz_attribute.push(attr);
         self
     }
+
+    /// Add a reference to another object under the relation `rel`.
+    ///
+    /// BO4E has no dedicated field for cross-references, so links are
+    /// stored as string-valued additional attributes named after the
+    /// relation. Adding the same link twice has no effect.
+    pub fn add_link(&mut self, rel: &str, target_id: &str) {
+        if !self.links_for(rel).contains(&target_id) {
+            self.zusatz_attribute
+                .push(AdditionalAttribute::string(rel, target_id));
+        }
+    }
+
+    /// Get the IDs referenced under the relation `rel`, in insertion order.
+    pub fn links_for(&self, rel: &str) -> Vec<&str> {
+        self.zusatz_attribute
+            .iter()
+            .filter(|attr| attr.name == rel)
+            .filter_map(|attr| match &attr.value {
+                Some(AttributeValue::String(id)) => Some(id.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn is_valid_version(version: &str) -> bool {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let (core, suffix) = match version.find(['-', '+']) {
+        Some(i) => (&version[..i], Some(&version[i + 1..])),
+        None => (version, None),
+    };
+    if let Some(suffix) = suffix {
+        let valid_suffix = !suffix.is_empty()
+            && suffix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'));
+        if !valid_suffix {
+            return false;
+        }
+    }
+
+    let parts: Vec<&str> = core.split('.').collect();
+    let [release, functional, technical] = parts[..] else {
+        return false;
+    };
+    let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let month = release.get(4..).and_then(|m| m.parse::<u32>().ok());
+    release.len() == 6
+        && is_number(release)
+        && matches!(month, Some(1..=12))
+        && is_number(functional)
+        && is_number(technical)
 }
 
+/// Error returned by [`Bo4eMeta::set_version`] for a malformed version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError {
+    /// The rejected version string
+    pub version: String,
+}
+
+impl std::fmt::Display for VersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid BO4E version: {:?}", self.version)
+    }
+}
+
+impl std::error::Error for VersionError {}
+
 /// Trait implemented by all BO4E types.
 ///
 /// This trait provides a common interface for accessing type metadata
@@ -93,6 +218,25 @@ pub trait Bo4eObject {
     fn meta_mut(&mut self) -> &mut Bo4eMeta;
 }
 
+/// Uniform total and breakdown view over cost objects.
+///
+/// Implemented by `Costs`, `TariffCosts` and `ExternalCosts` so that they can
+/// be rendered the same way regardless of their concrete structure.
+pub trait CostReport {
+    /// Labelled amounts that make up the total, one entry per cost component.
+    ///
+    /// Fails if a component has to be summed from amounts in different
+    /// currencies.
+    fn breakdown(&self) -> Result<Vec<(String, Amount)>, CurrencyMismatch>;
+
+    /// Sum of all amounts in [`breakdown`](Self::breakdown).
+    ///
+    /// Fails if the components use different currencies.
+    fn grand_total(&self) -> Result<Amount, CurrencyMismatch> {
+        Amount::sum(self.breakdown()?.iter().map(|(_, amount)| amount))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +261,14 @@ mod tests {
         assert_eq!(meta.id, Some("ext-123".to_string()));
     }
 
+    #[test]
+    fn test_meta_for_type() {
+        let meta = Bo4eMeta::for_type::<crate::bo::Meter>();
+        assert_eq!(meta.typ.as_deref(), Some("Zaehler"));
+        assert!(!meta.version_str().unwrap_or_default().is_empty());
+        assert!(is_valid_version(BO4E_VERSION));
+    }
+
     #[test]
     fn test_meta_serialize() {
         let meta = Bo4eMeta::with_type("Zaehler").version("202401.0.1");
@@ -145,4 +297,56 @@ mod tests {
         assert_eq!(meta.zusatz_attribute.len(), 1);
         assert_eq!(meta.zusatz_attribute[0].name, "sap_id");
     }
+
+    #[test]
+    fn test_links() {
+        let mut meta = Bo4eMeta::with_type("Zaehler");
+        meta.add_link("marktlokation", "12345678901");
+        meta.add_link("messlokation", "DE0001234567890123456789012345678");
+        meta.add_link("marktlokation", "12345678901");
+
+        assert_eq!(meta.links_for("marktlokation"), vec!["12345678901"]);
+        assert_eq!(
+            meta.links_for("messlokation"),
+            vec!["DE0001234567890123456789012345678"]
+        );
+        assert!(meta.links_for("vertrag").is_empty());
+
+        let json = serde_json::to_string(&meta).unwrap();
+        let parsed: Bo4eMeta = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.links_for("marktlokation"), vec!["12345678901"]);
+    }
+
+    #[test]
+    fn test_set_version() {
+        let mut meta = Bo4eMeta::default();
+        assert_eq!(meta.version_str(), None);
+
+        for valid in [
+            "202401.0.1",
+            "v202401.0.1",
+            "v202401.2.10-rc1",
+            "202312.1.0+dev",
+        ] {
+            assert_eq!(meta.set_version(valid), Ok(()), "{}", valid);
+            assert_eq!(meta.version_str(), Some(valid));
+        }
+    }
+
+    #[test]
+    fn test_set_version_malformed() {
+        let mut meta = Bo4eMeta::with_type("Zaehler").version("202401.0.1");
+        for invalid in [
+            "",
+            "latest",
+            "2024.0.1",
+            "202413.0.1",
+            "202401.0",
+            "202401.0.1-",
+        ] {
+            let err = meta.set_version(invalid).unwrap_err();
+            assert_eq!(err.version, invalid);
+        }
+        assert_eq!(meta.version_str(), Some("202401.0.1"));
+    }
 }
@@ -0,0 +1,388 @@
+//! Runtime validation of BO4E objects.
+//!
+//! Types implementing [`Validate`] check their fields against the rules of
+//! the BO4E standard and report every problem found, not just the first.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bo4e_core::bo::MarketLocation;
+//! use bo4e_core::validation::Validate;
+//!
+//! let malo = MarketLocation {
+//!     market_location_id: Some("123".to_string()),
+//!     ..Default::default()
+//! };
+//!
+//! let issues = malo.validate().unwrap_err();
+//! assert_eq!(issues.len(), 2);
+//! assert_eq!(issues[0].path, "market_location_id");
+//! ```
+
+use std::fmt;
+
+use crate::com::MeterRegister;
+use crate::enums::Unit;
+
+/// A single problem found while validating an object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// Path of the offending field, e.g. `energy_mix.sources[0].percentage_share`
+    pub path: String,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl ValidationIssue {
+    /// Create an issue for the field at `path`.
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Create an issue for a required field that is not set.
+    pub fn missing(path: impl Into<String>) -> Self {
+        Self::new(path, "required field is missing")
+    }
+
+    /// Prefix the path with the field holding the validated value.
+    pub fn nested(mut self, prefix: &str) -> Self {
+        self.path = if self.path.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{}.{}", prefix, self.path)
+        };
+        self
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationIssue {}
+
+/// Trait for types that can check their own consistency.
+pub trait Validate {
+    /// Validate the object, returning all issues found.
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>>;
+}
+
+/// Turn a list of collected issues into a validation result.
+pub(crate) fn finish(issues: Vec<ValidationIssue>) -> Result<(), Vec<ValidationIssue>> {
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// Validate an optional nested value, prefixing its issues with `prefix`.
+pub(crate) fn validate_nested<T: Validate>(
+    value: Option<&T>,
+    prefix: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if let Some(Err(nested)) = value.map(Validate::validate) {
+        issues.extend(nested.into_iter().map(|issue| issue.nested(prefix)));
+    }
+}
+
+/// Check a 13-digit BDEW code number (Marktpartner-ID).
+///
+/// The last digit is a check digit computed like an EAN-13 check digit:
+/// the first twelve digits are weighted alternately with 1 and 3 from the
+/// left, and the check digit brings the weighted sum to a multiple of 10.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::validation::{validate_bdew_code, BdewCodeError};
+///
+/// assert!(validate_bdew_code("9907248000001").is_ok());
+/// assert_eq!(
+///     validate_bdew_code("9907248000002"),
+///     Err(BdewCodeError::CheckDigit { expected: 1, found: 2 })
+/// );
+/// ```
+pub fn validate_bdew_code(code: &str) -> Result<(), BdewCodeError> {
+    if code.len() != 13 {
+        return Err(BdewCodeError::WrongLength(code.len()));
+    }
+    if !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BdewCodeError::NotNumeric);
+    }
+    let digits: Vec<u32> = code.bytes().map(|b| u32::from(b - b'0')).collect();
+    let weighted: u32 = digits[..12]
+        .iter()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+        .sum();
+    let expected = ((10 - weighted % 10) % 10) as u8;
+    let found = digits[12] as u8;
+    if expected != found {
+        return Err(BdewCodeError::CheckDigit { expected, found });
+    }
+    Ok(())
+}
+
+/// Error returned by [`validate_bdew_code`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BdewCodeError {
+    /// The code does not have 13 characters; holds the actual length
+    WrongLength(usize),
+    /// The code contains characters other than digits
+    NotNumeric,
+    /// The check digit does not match the other digits
+    CheckDigit {
+        /// Check digit computed from the first twelve digits
+        expected: u8,
+        /// Last digit of the code
+        found: u8,
+    },
+}
+
+impl fmt::Display for BdewCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength(len) => write!(f, "expected 13 digits, got {} characters", len),
+            Self::NotNumeric => write!(f, "BDEW code must only contain digits"),
+            Self::CheckDigit { expected, found } => {
+                write!(f, "check digit is {}, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BdewCodeError {}
+
+/// Check the format of a market location ID (MaLo-ID): 11 digits.
+///
+/// The check digit is not verified.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::validation::is_market_location_id;
+///
+/// assert!(is_market_location_id("51238696781"));
+/// assert!(!is_market_location_id("5123869678"));
+/// ```
+pub fn is_market_location_id(id: &str) -> bool {
+    id.len() == 11 && id.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Check the format of a metering location ID (MeLo-ID): 33 alphanumeric
+/// characters.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::validation::is_metering_location_id;
+///
+/// assert!(is_metering_location_id("DE0001234567890123456789012345678"));
+/// assert!(!is_metering_location_id("DE-123"));
+/// ```
+pub fn is_metering_location_id(id: &str) -> bool {
+    id.len() == 33 && id.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// Characters of an EIC code, in the order of their numeric value.
+const EIC_ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-";
+
+/// Check a 16-character ENTSO-E Energy Identification Code (EIC).
+///
+/// An EIC consists of uppercase letters, digits and `-`, and ends with a
+/// check character. Following the ENTSO-E algorithm, the first fifteen
+/// characters are numbered by their position in `0-9`, `A-Z`, `-`,
+/// weighted with 16 down to 2, and the check character is
+/// `36 - ((sum - 1) mod 37)`.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::validation::{validate_eic, EicError};
+///
+/// assert!(validate_eic("10YDE-RWENET---I").is_ok());
+/// assert_eq!(
+///     validate_eic("10YDE-RWENET---J"),
+///     Err(EicError::CheckCharacter { expected: 'I', found: 'J' })
+/// );
+/// ```
+pub fn validate_eic(code: &str) -> Result<(), EicError> {
+    if let Some(c) = code
+        .chars()
+        .find(|c| !c.is_ascii() || !EIC_ALPHABET.contains(&(*c as u8)))
+    {
+        return Err(EicError::InvalidCharacter(c));
+    }
+    if code.len() != 16 {
+        return Err(EicError::WrongLength(code.len()));
+    }
+    let bytes = code.as_bytes();
+    let weighted: usize = bytes[..15]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| eic_value(*b) * (16 - i))
+        .sum();
+    let expected = char::from(EIC_ALPHABET[36 - (weighted + 36) % 37]);
+    let found = char::from(bytes[15]);
+    if expected != found {
+        return Err(EicError::CheckCharacter { expected, found });
+    }
+    Ok(())
+}
+
+fn eic_value(b: u8) -> usize {
+    EIC_ALPHABET.iter().position(|a| *a == b).unwrap_or(0)
+}
+
+/// Error returned by [`validate_eic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EicError {
+    /// The code does not have 16 characters; holds the actual length
+    WrongLength(usize),
+    /// The code contains a character other than `A-Z`, `0-9` and `-`
+    InvalidCharacter(char),
+    /// The check character does not match the other characters
+    CheckCharacter {
+        /// Check character computed from the first fifteen characters
+        expected: char,
+        /// Last character of the code
+        found: char,
+    },
+}
+
+impl fmt::Display for EicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength(len) => write!(f, "expected 16 characters, got {}", len),
+            Self::InvalidCharacter(c) => write!(f, "invalid character {:?} in EIC", c),
+            Self::CheckCharacter { expected, found } => {
+                write!(f, "check character is {:?}, expected {:?}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EicError {}
+
+/// Check whether `unit` suits the measurement identified by an OBIS code.
+///
+/// The expected unit is derived with
+/// [`MeterRegister::measurement_from_obis`]; scaled variants of it, such as
+/// Wh for a kWh register, are accepted as well. Codes that cannot be
+/// classified are accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::enums::Unit;
+/// use bo4e_core::validation::is_unit_compatible;
+///
+/// assert!(is_unit_compatible("1-0:1.8.0", Unit::KilowattHour));
+/// assert!(!is_unit_compatible("1-0:1.8.0", Unit::CubicMeter));
+/// ```
+pub fn is_unit_compatible(obis_code: &str, unit: Unit) -> bool {
+    match MeterRegister::measurement_from_obis(obis_code) {
+        Some((_, expected)) => Unit::convert(1.0, expected, unit).is_some(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_path() {
+        let issue = ValidationIssue::missing("value").nested("net_amount");
+        assert_eq!(issue.path, "net_amount.value");
+        assert_eq!(
+            issue.to_string(),
+            "net_amount.value: required field is missing"
+        );
+
+        let root = ValidationIssue::new("", "inconsistent").nested("energy_mix");
+        assert_eq!(root.path, "energy_mix");
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(finish(Vec::new()), Ok(()));
+        let issues = vec![ValidationIssue::missing("division")];
+        assert_eq!(finish(issues.clone()), Err(issues));
+    }
+
+    #[test]
+    fn test_bdew_code() {
+        assert_eq!(validate_bdew_code("9907248000001"), Ok(()));
+        assert_eq!(validate_bdew_code("9900000000004"), Ok(()));
+        assert_eq!(
+            validate_bdew_code("990724800000"),
+            Err(BdewCodeError::WrongLength(12))
+        );
+        assert_eq!(
+            validate_bdew_code("99072480000A1"),
+            Err(BdewCodeError::NotNumeric)
+        );
+        assert_eq!(
+            validate_bdew_code("9907248000007"),
+            Err(BdewCodeError::CheckDigit {
+                expected: 1,
+                found: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_location_ids() {
+        assert!(is_market_location_id("51238696781"));
+        assert!(!is_market_location_id("5123869678A"));
+        assert!(!is_market_location_id("512386967812"));
+
+        let melo = "DE00012345678901234567890123456AB";
+        assert!(is_metering_location_id(melo));
+        assert!(!is_metering_location_id(&melo[..32]));
+        assert!(!is_metering_location_id(
+            "DE00012345678901234567890123456A-"
+        ));
+    }
+
+    #[test]
+    fn test_eic() {
+        // Control area of Amprion and the ENTSO-E secretariat
+        assert_eq!(validate_eic("10YDE-RWENET---I"), Ok(()));
+        assert_eq!(validate_eic("10X1001A1001A450"), Ok(()));
+        assert!(validate_eic("000000000000000A").is_err());
+        assert_eq!(
+            validate_eic("10YDE-RWENET---K"),
+            Err(EicError::CheckCharacter {
+                expected: 'I',
+                found: 'K'
+            })
+        );
+        assert_eq!(
+            validate_eic("10YDE-RWENET-I"),
+            Err(EicError::WrongLength(14))
+        );
+        assert_eq!(
+            validate_eic("10yde-rwenet---I"),
+            Err(EicError::InvalidCharacter('y'))
+        );
+    }
+
+    #[test]
+    fn test_unit_compatibility() {
+        assert!(is_unit_compatible("1-0:1.8.0", Unit::KilowattHour));
+        assert!(is_unit_compatible("1-0:1.8.0", Unit::WattHour));
+        assert!(is_unit_compatible("7-0:3.0.0", Unit::CubicMeter));
+        assert!(!is_unit_compatible("1-0:1.8.0", Unit::CubicMeter));
+        assert!(!is_unit_compatible("1-0:1.8.0", Unit::Kilowatt));
+        assert!(is_unit_compatible("1-0:99.99.0", Unit::Percent));
+    }
+}
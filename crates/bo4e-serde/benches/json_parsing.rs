@@ -116,6 +116,57 @@ fn bench_batch_parsing(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_interned_parsing(c: &mut Criterion) {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct OwnedRow {
+        meter_number: String,
+        network_operator_code: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct InternedRow {
+        meter_number: String,
+        #[serde(deserialize_with = "bo4e_serde::intern::deserialize")]
+        network_operator_code: std::sync::Arc<str>,
+    }
+
+    let rows: Vec<String> = (0..10_000)
+        .map(|i| {
+            format!(
+                r#"{{"meterNumber":"1EMH{:010}","networkOperatorCode":"9900000000001"}}"#,
+                i
+            )
+        })
+        .collect();
+    let bytes = format!("[{}]", rows.join(",")).into_bytes();
+
+    let mut group = c.benchmark_group("interned_parsing");
+    group.throughput(Throughput::Elements(10_000));
+
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            let mut bytes = bytes.clone();
+            let _: Vec<OwnedRow> = bo4e_serde::from_json(black_box(&mut bytes)).unwrap();
+        })
+    });
+
+    group.bench_function("interned", |b| {
+        let mut interner = bo4e_serde::intern::Interner::new();
+        b.iter(|| {
+            let mut bytes = bytes.clone();
+            let _: Vec<InternedRow> =
+                bo4e_serde::intern::from_json_interned(black_box(&mut bytes), &mut interner)
+                    .unwrap();
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_serialization(c: &mut Criterion) {
     let meter = create_meter();
     let malo = create_market_location();
@@ -183,6 +234,7 @@ criterion_group!(
     benches,
     bench_deserialization,
     bench_batch_parsing,
+    bench_interned_parsing,
     bench_serialization,
     bench_batch_serialization
 );
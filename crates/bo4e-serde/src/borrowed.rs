@@ -0,0 +1,111 @@
+//! Zero-copy views of BO4E objects.
+//!
+//! The types in this module borrow their string fields from the input
+//! buffer instead of allocating a `String` for each. They are meant for
+//! read-only processing of large inputs with [`from_json_borrowed`]; use the
+//! owned types from `bo4e_core` when objects need to outlive the buffer or
+//! be modified.
+//!
+//! Nested components are not part of the views. Fields that are not
+//! modelled are skipped while parsing.
+//!
+//! [`from_json_borrowed`]: crate::from_json_borrowed
+
+use bo4e_core::enums::{Division, MeterSize, MeterType};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Borrowed view of a [`Meter`](bo4e_core::bo::Meter).
+///
+/// String fields are slices of the input buffer. simd-json unescapes
+/// strings in place, so [`from_json_borrowed`](crate::from_json_borrowed)
+/// can borrow every string; deserializers that cannot, like `serde_json`
+/// for strings with escape sequences, fail instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_serde::borrowed::MeterRef;
+/// use bo4e_serde::from_json_borrowed;
+///
+/// let mut bytes = br#"{"_typ":"Zaehler","zaehlernummer":"1EMH0012345678"}"#.to_vec();
+/// let meter: MeterRef = from_json_borrowed(&mut bytes).unwrap();
+/// assert_eq!(meter.meter_number, Some("1EMH0012345678"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeterRef<'a> {
+    /// BO4E type name (`_typ`)
+    #[serde(default, rename = "_typ", alias = "boTyp")]
+    pub typ: Option<&'a str>,
+
+    /// Meter number (Zaehlernummer)
+    #[serde(default, alias = "zaehlernummer")]
+    pub meter_number: Option<&'a str>,
+
+    /// Energy division (Sparte)
+    #[serde(default, alias = "sparte")]
+    pub division: Option<Division>,
+
+    /// Type of meter (Zaehlertyp)
+    #[serde(default, alias = "zaehlertyp")]
+    pub meter_type: Option<MeterType>,
+
+    /// Size of meter (Zaehlergroesse)
+    #[serde(default, alias = "zaehlergroesse")]
+    pub meter_size: Option<MeterSize>,
+
+    /// Reference to associated market location ID (Marktlokation)
+    #[serde(default, alias = "marktlokationsId")]
+    pub market_location_id: Option<&'a str>,
+
+    /// Reference to associated metering location ID (Messlokation)
+    #[serde(default, alias = "messlokationsId")]
+    pub metering_location_id: Option<&'a str>,
+
+    /// Ownership status (Eigentumsverhaeltnis)
+    #[serde(default, alias = "eigentumsverhaeltnis")]
+    pub ownership: Option<&'a str>,
+
+    /// Manufacturer (Hersteller)
+    #[serde(default, alias = "hersteller")]
+    pub manufacturer: Option<&'a str>,
+
+    /// Manufacturing year (Herstellungsjahr)
+    #[serde(default, alias = "herstellungsjahr")]
+    pub manufacturing_year: Option<i32>,
+
+    /// Installation date (Einbaudatum)
+    #[serde(default, alias = "einbaudatum")]
+    pub installation_date: Option<DateTime<Utc>>,
+
+    /// Removal date (Ausbaudatum)
+    #[serde(default, alias = "ausbaudatum")]
+    pub removal_date: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_json_borrowed;
+
+    #[test]
+    fn test_meter_number_points_into_buffer() {
+        let mut bytes = br#"{"_typ":"Zaehler","meterNumber":"1EMH0012345678","sparte":"STROM","zaehlwerke":[{"obisKennzahl":"1-0:1.8.0"}]}"#.to_vec();
+        let range = bytes.as_ptr_range();
+
+        let meter: MeterRef = from_json_borrowed(&mut bytes).unwrap();
+        let number = meter.meter_number.unwrap();
+        assert_eq!(number, "1EMH0012345678");
+        assert!(range.contains(&number.as_ptr()));
+        assert_eq!(meter.division, Some(Division::Electricity));
+        assert_eq!(meter.typ, Some("Zaehler"));
+    }
+
+    #[test]
+    fn test_escaped_string() {
+        let mut bytes = r#"{"hersteller":"Müller \"Zähler\" GmbH"}"#.as_bytes().to_vec();
+        let meter: MeterRef = from_json_borrowed(&mut bytes).unwrap();
+        assert_eq!(meter.manufacturer, Some("Müller \"Zähler\" GmbH"));
+    }
+}
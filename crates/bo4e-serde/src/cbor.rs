@@ -0,0 +1,66 @@
+//! CBOR encoding of BO4E objects.
+//!
+//! Structs are encoded as maps keyed by the same field names as the German
+//! JSON output. Timestamps are written as RFC 3339 text strings rather than
+//! tagged CBOR dates so that any CBOR decoder can read them.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{with_config, Error, SerializeConfig};
+
+/// Serialize a BO4E object to CBOR.
+pub fn to_cbor<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    with_config(SerializeConfig::german(), || {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).map_err(|e| Error::Serialize(e.to_string()))?;
+        Ok(bytes)
+    })
+}
+
+/// Deserialize a BO4E object from CBOR.
+pub fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    ciborium::from_reader(bytes).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::MarketLocation;
+    use bo4e_core::enums::{Division, EnergyDirection};
+    use bo4e_core::Bo4eMeta;
+    use chrono::{TimeZone, Utc};
+    use ciborium::Value;
+
+    fn sample_location() -> MarketLocation {
+        MarketLocation {
+            meta: Bo4eMeta::with_type("Marktlokation"),
+            market_location_id: Some("12345678901".to_string()),
+            division: Some(Division::Electricity),
+            energy_direction: Some(EnergyDirection::FeedOut),
+            supply_start: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            annual_consumption: Some(3500.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_market_location_roundtrip() {
+        let malo = sample_location();
+        let bytes = to_cbor(&malo).unwrap();
+        let parsed: MarketLocation = from_cbor(&bytes).unwrap();
+        assert_eq!(malo, parsed);
+    }
+
+    #[test]
+    fn test_datetime_is_text() {
+        let bytes = to_cbor(&sample_location()).unwrap();
+        let value: Value = from_cbor(&bytes).unwrap();
+        let start = value
+            .as_map()
+            .unwrap()
+            .iter()
+            .find(|(k, _)| k.as_text() == Some("supplyStart"))
+            .map(|(_, v)| v.clone());
+        assert_eq!(start, Some(Value::Text("2024-01-01T00:00:00Z".to_string())));
+    }
+}
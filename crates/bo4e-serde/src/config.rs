@@ -2,15 +2,27 @@
 
 use std::cell::RefCell;
 
-/// Controls JSON field naming language.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
-pub enum JsonLanguage {
-    /// German field names (e.g., "zaehlernummer", "marktlokationsId")
-    /// This is the BO4E standard format.
+pub use bo4e_core::JsonLanguage;
+
+/// Naming of the flattened `Bo4eMeta` keys in JSON output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MetaKeyStyle {
+    /// `_typ`, `_version` and `_id` as in the BO4E standard
     #[default]
-    German,
-    /// English field names (e.g., "meterNumber", "marketLocationId")
-    English,
+    Underscore,
+    /// `boTyp`, `boVersion` and `boId`, for consumers that cannot handle
+    /// keys starting with an underscore
+    BoPrefix,
+}
+
+impl MetaKeyStyle {
+    /// Keys for the type, version and ID, in this order.
+    pub fn keys(self) -> [&'static str; 3] {
+        match self {
+            Self::Underscore => ["_typ", "_version", "_id"],
+            Self::BoPrefix => ["boTyp", "boVersion", "boId"],
+        }
+    }
 }
 
 /// Configuration for JSON serialization.
@@ -20,8 +32,32 @@ pub struct SerializeConfig {
     pub language: JsonLanguage,
     /// Pretty-print JSON output
     pub pretty: bool,
-    /// Include null fields in output
+    /// Emit unset fields as `null` and empty lists as `[]` instead of
+    /// omitting them
     pub include_nulls: bool,
+    /// Omit the flattened `Bo4eMeta` fields (`_typ`, `_version`, `_id`,
+    /// `zusatzAttribute`) from the output
+    pub skip_meta: bool,
+    /// Emit empty lists as `[]` instead of omitting them
+    pub emit_empty_arrays: bool,
+    /// Rename JSON keys to the field names of `language` using
+    /// [`mapping`](crate::mapping); keys without a mapping are kept
+    pub translate_keys: bool,
+    /// Write UTC timestamps with a `+00:00` offset instead of `Z`
+    pub utc_offset: bool,
+    /// Write whole-valued floats without the trailing `.0`
+    pub trim_float_zero: bool,
+    /// Write monetary values with this many decimal places
+    pub money_decimals: Option<u32>,
+    /// Retry input that simd-json rejects with `serde_json` when parsing
+    /// with [`from_slice`](crate::from_slice); copies the input before each
+    /// parse
+    pub simd_fallback: bool,
+    /// Naming of the metadata keys
+    pub meta_key_style: MetaKeyStyle,
+    /// Reject input whose arrays and objects are nested deeper than this
+    /// when parsing with [`from_slice`](crate::from_slice)
+    pub max_depth: Option<usize>,
 }
 
 impl Default for SerializeConfig {
@@ -30,6 +66,15 @@ impl Default for SerializeConfig {
             language: JsonLanguage::German,
             pretty: false,
             include_nulls: false,
+            skip_meta: false,
+            emit_empty_arrays: false,
+            translate_keys: false,
+            utc_offset: false,
+            trim_float_zero: false,
+            money_decimals: None,
+            simd_fallback: false,
+            meta_key_style: MetaKeyStyle::Underscore,
+            max_depth: None,
         }
     }
 }
@@ -48,6 +93,22 @@ impl SerializeConfig {
         }
     }
 
+    /// Create config matching the output of BO4E-Python.
+    ///
+    /// Keys are translated to German, empty lists are emitted as `[]`, UTC
+    /// timestamps use a `+00:00` offset, and the metadata fields (`_typ`,
+    /// `_version`) are kept. Rust objects only carry `_typ` if it is set
+    /// in their `Bo4eMeta`.
+    pub fn python_compatible() -> Self {
+        Self {
+            language: JsonLanguage::German,
+            emit_empty_arrays: true,
+            translate_keys: true,
+            utc_offset: true,
+            ..Default::default()
+        }
+    }
+
     /// Enable pretty-printing.
     pub fn pretty(mut self) -> Self {
         self.pretty = true;
@@ -59,6 +120,84 @@ impl SerializeConfig {
         self.include_nulls = true;
         self
     }
+
+    /// Emit unset fields as `null` and empty lists as `[]`.
+    ///
+    /// Lets consumers distinguish a field that is explicitly unset from
+    /// one their schema does not know about.
+    pub fn with_emit_nulls(mut self, emit: bool) -> Self {
+        self.include_nulls = emit;
+        self
+    }
+
+    /// Emit empty lists as `[]` while still omitting unset fields.
+    pub fn with_emit_empty_arrays(mut self, emit: bool) -> Self {
+        self.emit_empty_arrays = emit;
+        self
+    }
+
+    /// Rename known keys to the field names of the configured language.
+    pub fn with_translate_keys(mut self, translate: bool) -> Self {
+        self.translate_keys = translate;
+        self
+    }
+
+    /// Write UTC timestamps as `2024-01-01T00:00:00+00:00`.
+    pub fn with_utc_offset(mut self, offset: bool) -> Self {
+        self.utc_offset = offset;
+        self
+    }
+
+    /// Write whole-valued floats like `1500.0` as `1500`.
+    pub fn with_trim_float_trailing_zero(mut self, trim: bool) -> Self {
+        self.trim_float_zero = trim;
+        self
+    }
+
+    /// Write monetary values with a fixed number of decimal places.
+    ///
+    /// Applies to the `value` of objects that also carry a `currency`,
+    /// such as `Amount` and `Price`, e.g. `119.00` for two places.
+    pub fn with_money_decimals(mut self, decimals: u32) -> Self {
+        self.money_decimals = Some(decimals);
+        self
+    }
+
+    /// Retry input rejected by simd-json with `serde_json`.
+    ///
+    /// Disabled by default, since the input has to be copied before each
+    /// parse: simd-json rewrites it in place, even when it fails.
+    pub fn with_simd_fallback(mut self, fallback: bool) -> Self {
+        self.simd_fallback = fallback;
+        self
+    }
+
+    /// Name the metadata keys according to `style`.
+    ///
+    /// Deserialization accepts both styles regardless of this setting.
+    pub fn with_meta_key_style(mut self, style: MetaKeyStyle) -> Self {
+        self.meta_key_style = style;
+        self
+    }
+
+    /// Limit how deeply arrays and objects may be nested in parsed input.
+    ///
+    /// The top-level object counts as depth 1. Input beyond the limit is
+    /// rejected before it reaches the deserializer, so crafted documents
+    /// cannot exhaust the stack. Unlimited by default.
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Omit the BO4E metadata fields from the output.
+    ///
+    /// Useful for internal message passing where the type is known from
+    /// context. Deserialization accepts input with or without metadata.
+    pub fn with_skip_meta(mut self, skip: bool) -> Self {
+        self.skip_meta = skip;
+        self
+    }
 }
 
 // Thread-local storage for current serialization context
@@ -82,11 +221,23 @@ pub fn current_config() -> SerializeConfig {
 }
 
 /// Execute a closure with a specific config.
+///
+/// Scopes nest: each call saves the config it replaces and restores it when
+/// `f` returns or panics, so [`current_config`] always reflects the
+/// innermost scope and the outer config is intact afterwards.
 pub fn with_config<T, F: FnOnce() -> T>(config: SerializeConfig, f: F) -> T {
-    let old = CURRENT_CONFIG.with(|c| c.replace(config));
-    let result = f();
-    CURRENT_CONFIG.with(|c| c.replace(old));
-    result
+    let _scope = ConfigScope(CURRENT_CONFIG.with(|c| c.replace(config)));
+    f()
+}
+
+/// Restores the saved config when dropped.
+struct ConfigScope(SerializeConfig);
+
+impl Drop for ConfigScope {
+    fn drop(&mut self) {
+        let saved = std::mem::take(&mut self.0);
+        CURRENT_CONFIG.with(|c| *c.borrow_mut() = saved);
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +263,20 @@ mod tests {
         assert!(config.include_nulls);
     }
 
+    #[test]
+    fn test_skip_meta_config() {
+        assert!(!SerializeConfig::default().skip_meta);
+        assert!(SerializeConfig::german().with_skip_meta(true).skip_meta);
+    }
+
+    #[test]
+    fn test_python_compatible_config() {
+        let config = SerializeConfig::python_compatible();
+        assert_eq!(config.language, JsonLanguage::German);
+        assert!(config.emit_empty_arrays && config.translate_keys && config.utc_offset);
+        assert!(!config.skip_meta && !config.include_nulls);
+    }
+
     #[test]
     fn test_thread_local_config() {
         // Default should be German
@@ -138,4 +303,27 @@ mod tests {
         assert_eq!(result, 42);
         assert_eq!(current_language(), JsonLanguage::German);
     }
+
+    #[test]
+    fn test_nested_with_config() {
+        let outer = SerializeConfig::german().with_skip_meta(true);
+        with_config(outer, || {
+            with_config(SerializeConfig::english(), || {
+                assert_eq!(current_language(), JsonLanguage::English);
+                assert!(!current_config().skip_meta);
+            });
+            assert_eq!(current_language(), JsonLanguage::German);
+            assert!(current_config().skip_meta);
+        });
+        assert!(!current_config().skip_meta);
+    }
+
+    #[test]
+    fn test_with_config_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_config(SerializeConfig::english(), || panic!("inner failure"))
+        });
+        assert!(result.is_err());
+        assert_eq!(current_language(), JsonLanguage::German);
+    }
 }
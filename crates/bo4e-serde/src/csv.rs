@@ -0,0 +1,352 @@
+//! CSV export and import of BO4E collections.
+//!
+//! Each object becomes one row. Headers are the German field names, nested
+//! objects are flattened into dotted columns (`lokationsadresse.postleitzahl`)
+//! and arrays are written as JSON into a single cell. The header row is the
+//! union of the columns of all objects in order of first appearance; cells
+//! of fields an object does not set are left empty.
+//!
+//! [`from_csv`] reverses this. Since CSV cells carry no types, each cell is
+//! converted to whatever the target field asks for: numbers and booleans
+//! are parsed from the text, and cells starting with `[` or `{` are read as
+//! JSON when the field is a list or an untyped value.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Write};
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Serialize};
+use serde_json::Value;
+
+use crate::{serialize, with_config, Error, SerializeConfig};
+
+/// Write `values` as CSV with a header row to `writer`.
+pub fn to_csv<T: Serialize, W: Write>(values: &[T], writer: W) -> Result<(), Error> {
+    let config = SerializeConfig::german().with_translate_keys(true);
+    let mut headers: Vec<String> = Vec::new();
+    let mut rows: Vec<HashMap<String, String>> = Vec::with_capacity(values.len());
+
+    for value in values {
+        let mut tree = with_config(config.clone(), || serde_json::to_value(value))?;
+        serialize::apply_config(&mut tree, &config, std::any::type_name::<T>());
+
+        let mut row = HashMap::new();
+        flatten("", tree, &mut row, &mut headers)?;
+        rows.push(row);
+    }
+
+    let mut out = ::csv::Writer::from_writer(writer);
+    out.write_record(&headers).map_err(csv_error)?;
+    for row in &rows {
+        let record = headers
+            .iter()
+            .map(|header| row.get(header).map_or("", String::as_str));
+        out.write_record(record).map_err(csv_error)?;
+    }
+    out.flush().map_err(|e| Error::Serialize(e.to_string()))
+}
+
+/// Collect the cells of `value` into `row`, registering new columns in `headers`.
+fn flatten(
+    prefix: &str,
+    value: Value,
+    row: &mut HashMap<String, String>,
+    headers: &mut Vec<String>,
+) -> Result<(), Error> {
+    let cell = match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let column = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&column, child, row, headers)?;
+            }
+            return Ok(());
+        }
+        Value::Array(_) => serde_json::to_string(&value)?,
+        Value::String(s) => s,
+        Value::Null => String::new(),
+        other => other.to_string(),
+    };
+    if !headers.iter().any(|h| h == prefix) {
+        headers.push(prefix.to_string());
+    }
+    row.insert(prefix.to_string(), cell);
+    Ok(())
+}
+
+fn csv_error(e: ::csv::Error) -> Error {
+    Error::Serialize(e.to_string())
+}
+
+/// Read all rows from `reader` into objects of type `T`.
+///
+/// The first row must hold the headers, with German or English field names
+/// and dotted names for nested fields. Empty cells are treated as absent
+/// fields, so optional fields become `None` rather than empty strings.
+pub fn from_csv<T: DeserializeOwned, R: Read>(reader: R) -> Result<Vec<T>, Error> {
+    let mut input = ::csv::Reader::from_reader(reader);
+    let headers = input
+        .headers()
+        .map_err(|e| Error::Deserialize(e.to_string()))?
+        .clone();
+
+    let mut values = Vec::new();
+    for (line, record) in input.records().enumerate() {
+        let record = record.map_err(|e| Error::Deserialize(e.to_string()))?;
+        let mut row = BTreeMap::new();
+        for (header, cell) in headers.iter().zip(record.iter()) {
+            if !cell.is_empty() {
+                insert(&mut row, header, cell)
+                    .map_err(|msg| Error::Deserialize(format!("row {}: {}", line + 1, msg)))?;
+            }
+        }
+        let value = T::deserialize(Node::Map(row))
+            .map_err(|e| Error::Deserialize(format!("row {}: {}", line + 1, e)))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Insert `cell` under the dotted column name `header`.
+fn insert(row: &mut BTreeMap<String, Node>, header: &str, cell: &str) -> Result<(), String> {
+    let mut current = row;
+    let mut parts = header.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            if current.contains_key(part) {
+                return Err(format!("column {:?} conflicts with another column", header));
+            }
+            current.insert(part.to_string(), Node::Cell(cell.to_string()));
+            return Ok(());
+        }
+        let child = current
+            .entry(part.to_string())
+            .or_insert_with(|| Node::Map(BTreeMap::new()));
+        current = match child {
+            Node::Map(map) => map,
+            Node::Cell(_) => {
+                return Err(format!("column {:?} conflicts with another column", header))
+            }
+        };
+    }
+    Ok(())
+}
+
+/// A row rebuilt into nested objects, deserialized on demand.
+enum Node {
+    Cell(String),
+    Map(BTreeMap<String, Node>),
+}
+
+impl Node {
+    fn parse<N: std::str::FromStr>(cell: &str, expected: &str) -> Result<N, serde_json::Error> {
+        cell.trim()
+            .parse()
+            .map_err(|_| de::Error::custom(format!("expected {}, found {:?}", expected, cell)))
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Node {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Parse the cell as `$ty` for the typed `deserialize_*` calls.
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ty, $expected:literal;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self {
+                    Node::Cell(cell) => visitor.$visit(Node::parse::<$ty>(&cell, $expected)?),
+                    map => map.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Node {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Node::Map(map) => visitor.visit_map(de::value::MapDeserializer::new(map.into_iter())),
+            Node::Cell(cell) if cell.starts_with('[') || cell.starts_with('{') => {
+                serde_json::from_str::<Value>(&cell)?.deserialize_any(visitor)
+            }
+            Node::Cell(cell) => visitor.visit_string(cell),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool, "a boolean";
+        deserialize_i8 => visit_i8: i8, "an integer";
+        deserialize_i16 => visit_i16: i16, "an integer";
+        deserialize_i32 => visit_i32: i32, "an integer";
+        deserialize_i64 => visit_i64: i64, "an integer";
+        deserialize_u8 => visit_u8: u8, "an unsigned integer";
+        deserialize_u16 => visit_u16: u16, "an unsigned integer";
+        deserialize_u32 => visit_u32: u32, "an unsigned integer";
+        deserialize_u64 => visit_u64: u64, "an unsigned integer";
+        deserialize_f32 => visit_f32: f32, "a number";
+        deserialize_f64 => visit_f64: f64, "a number";
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Node::Cell(cell) => visitor.visit_string(cell),
+            map => map.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Node::Cell(cell) => serde_json::from_str::<Value>(&cell)?.deserialize_seq(visitor),
+            map => map.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            Node::Cell(cell) if cell.starts_with('{') => {
+                serde_json::from_str::<Value>(&cell)?.deserialize_enum(name, variants, visitor)
+            }
+            Node::Cell(cell) => visitor.visit_enum(cell.into_deserializer()),
+            map => map.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        char bytes byte_buf unit unit_struct tuple tuple_struct map struct
+        identifier ignored_any i128 u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+    use bo4e_core::com::{Address, MeterRegister};
+    use bo4e_core::enums::Division;
+
+    #[test]
+    fn test_meters_from_csv() {
+        let csv = "\
+zaehlernummer,sparte,zaehlergroesse,standort.postleitzahl,standort.ort,herstellungsjahr,zaehlwerke
+1EMH0001,STROM,,50667,Köln,2019,\"[{\"\"obisCode\"\":\"\"1-0:1.8.0\"\"}]\"
+1EMH0002,,,,,,
+";
+        let meters: Vec<Meter> = from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(meters.len(), 2);
+
+        assert_eq!(meters[0].meter_number, Some("1EMH0001".to_string()));
+        assert_eq!(meters[0].division, Some(Division::Electricity));
+        assert_eq!(meters[0].manufacturing_year, Some(2019));
+        let location = meters[0].location.as_ref().unwrap();
+        assert_eq!(location.postal_code, Some("50667".to_string()));
+        assert_eq!(location.city, Some("Köln".to_string()));
+        assert_eq!(meters[0].registers.len(), 1);
+        assert_eq!(
+            meters[0].registers[0].obis_code,
+            Some("1-0:1.8.0".to_string())
+        );
+
+        assert_eq!(meters[1].meter_number, Some("1EMH0002".to_string()));
+        assert_eq!(meters[1].division, None);
+        assert_eq!(meters[1].meter_size, None);
+        assert!(meters[1].location.is_none());
+        assert!(meters[1].registers.is_empty());
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let meters = vec![Meter {
+            meter_number: Some("1EMH0003".to_string()),
+            division: Some(Division::Gas),
+            location: Some(Address {
+                postal_code: Some("10115".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let mut out = Vec::new();
+        to_csv(&meters, &mut out).unwrap();
+        let parsed: Vec<Meter> = from_csv(&out[..]).unwrap();
+        assert_eq!(parsed, meters);
+
+        let err = from_csv::<Meter, _>(&b"herstellungsjahr\nneunzehn\n"[..]).unwrap_err();
+        assert!(err.to_string().contains("row 1"));
+    }
+
+    #[test]
+    fn test_meters_to_csv() {
+        let meters = vec![
+            Meter {
+                meter_number: Some("1EMH0001".to_string()),
+                division: Some(Division::Electricity),
+                location: Some(Address {
+                    postal_code: Some("50667".to_string()),
+                    city: Some("Köln".to_string()),
+                    ..Default::default()
+                }),
+                registers: vec![MeterRegister {
+                    obis_code: Some("1-0:1.8.0".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            Meter {
+                meter_number: Some("1EMH0002".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let mut out = Vec::new();
+        to_csv(&meters, &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        let headers: Vec<&str> = lines[0].split(',').collect();
+        assert!(headers.contains(&"zaehlernummer"));
+        assert!(headers.contains(&"sparte"));
+        assert!(headers.iter().any(|h| h.ends_with(".postleitzahl")));
+        assert_eq!(lines.len(), 3);
+
+        let column = |name: &str| headers.iter().position(|h| *h == name).unwrap();
+        let first: Vec<&str> = lines[1].split(',').collect();
+        assert_eq!(first[column("zaehlernummer")], "1EMH0001");
+        assert_eq!(first[column("sparte")], "STROM");
+        assert!(lines[1].contains(r#""[{""obisKennzahl"":""1-0:1.8.0""}]""#));
+
+        let second: Vec<&str> = lines[2].split(',').collect();
+        assert_eq!(second[column("zaehlernummer")], "1EMH0002");
+        assert_eq!(second[column("sparte")], "");
+    }
+}
@@ -0,0 +1,67 @@
+//! Registry of deprecated BO4E fields.
+
+use std::fmt;
+
+use serde_json::Value;
+
+/// Deprecated JSON keys, in both languages, with an optional hint such as
+/// the name of the replacing field.
+const DEPRECATED_FIELDS: &[(&str, Option<&str>)] = &[
+    ("grundversorgerCodenummer", None),
+    ("basicSupplierCode", None),
+];
+
+/// A non-fatal finding reported by [`from_json_with_warnings`](crate::from_json_with_warnings).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// JSON pointer to the field, e.g. `/grundversorgerCodenummer`
+    pub path: String,
+    /// The deprecated key as it appeared in the input
+    pub field: String,
+    /// Suggested replacement, if there is one
+    pub hint: Option<String>,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}` is deprecated", self.field)?;
+        if let Some(hint) = &self.hint {
+            write!(f, ", {}", hint)?;
+        }
+        if self.path != format!("/{}", self.field) {
+            write!(f, " (at {})", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect warnings for all deprecated keys in `value`, at any depth.
+pub(crate) fn collect(value: &Value) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    walk(value, "", &mut warnings);
+    warnings
+}
+
+fn walk(value: &Value, path: &str, out: &mut Vec<Warning>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                if let Some((_, hint)) = DEPRECATED_FIELDS.iter().find(|(field, _)| field == key) {
+                    out.push(Warning {
+                        path: child_path.clone(),
+                        field: key.clone(),
+                        hint: hint.map(str::to_string),
+                    });
+                }
+                walk(child, &child_path, out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                walk(item, &format!("{}/{}", path, i), out);
+            }
+        }
+        _ => {}
+    }
+}
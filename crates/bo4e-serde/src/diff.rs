@@ -0,0 +1,157 @@
+//! Structural comparison of BO4E objects.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{with_config, SerializeConfig};
+
+/// A single difference found by [`diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// JSON pointer to the changed value, e.g. `/basePrice/value`
+    pub path: String,
+    /// Previous value, `None` if the field was added
+    pub old_value: Option<Value>,
+    /// New value, `None` if the field was removed
+    pub new_value: Option<Value>,
+}
+
+/// List the fields that differ between two versions of an object.
+///
+/// Both objects are serialized with the German configuration and compared
+/// recursively. Objects are compared key by key and arrays index by index;
+/// any other difference is reported at the innermost path where it occurs.
+/// Paths can be resolved with [`get_pointer`](crate::patch::get_pointer).
+///
+/// # Panics
+///
+/// Panics if either object cannot be represented as JSON (e.g. a map with
+/// non-string keys), which does not happen for BO4E types.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::diff::diff;
+///
+/// let old = Meter { meter_number: Some("1".to_string()), ..Default::default() };
+/// let new = Meter { meter_number: Some("2".to_string()), ..Default::default() };
+/// let changes = diff(&old, &new);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].path, "/meterNumber");
+/// ```
+pub fn diff<T: Serialize>(old: &T, new: &T) -> Vec<FieldChange> {
+    let (old, new) = with_config(SerializeConfig::german(), || {
+        (
+            serde_json::to_value(old).expect("BO4E object serializes to JSON"),
+            serde_json::to_value(new).expect("BO4E object serializes to JSON"),
+        )
+    });
+    let mut changes = Vec::new();
+    diff_values(String::new(), Some(&old), Some(&new), &mut changes);
+    changes
+}
+
+fn diff_values(
+    path: String,
+    old: Option<&Value>,
+    new: Option<&Value>,
+    changes: &mut Vec<FieldChange>,
+) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let added = new.keys().filter(|key| !old.contains_key(*key));
+            for key in old.keys().chain(added) {
+                diff_values(child(&path, key), old.get(key), new.get(key), changes);
+            }
+        }
+        (Some(Value::Array(old)), Some(Value::Array(new))) => {
+            for i in 0..old.len().max(new.len()) {
+                diff_values(
+                    child(&path, &i.to_string()),
+                    old.get(i),
+                    new.get(i),
+                    changes,
+                );
+            }
+        }
+        _ if old == new => {}
+        _ => changes.push(FieldChange {
+            path,
+            old_value: old.cloned(),
+            new_value: new.cloned(),
+        }),
+    }
+}
+
+fn child(path: &str, segment: &str) -> String {
+    format!("{}/{}", path, segment.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Tariff;
+    use bo4e_core::com::{Price, PriceTier};
+    use serde_json::json;
+
+    fn tier(number: i32, unit_price: f64) -> PriceTier {
+        PriceTier {
+            tier_number: Some(number),
+            unit_price: Some(unit_price),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_tariffs() {
+        let old = Tariff {
+            tariff_name: Some("Basis".to_string()),
+            base_price: Some(Price {
+                value: Some(9.90),
+                ..Default::default()
+            }),
+            price_tiers: vec![tier(1, 0.32)],
+            ..Default::default()
+        };
+        let new = Tariff {
+            base_price: Some(Price {
+                value: Some(11.90),
+                ..Default::default()
+            }),
+            price_tiers: vec![tier(1, 0.32), tier(2, 0.29)],
+            ..old.clone()
+        };
+
+        let changes = diff(&old, &new);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["/basePrice/value", "/priceTiers/1"]);
+
+        assert_eq!(changes[0].old_value, Some(json!(9.90)));
+        assert_eq!(changes[0].new_value, Some(json!(11.90)));
+        assert_eq!(changes[1].old_value, None);
+        assert_eq!(
+            changes[1].new_value,
+            Some(serde_json::to_value(tier(2, 0.29)).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_diff_removed_and_identical() {
+        let old = Tariff {
+            tariff_name: Some("Basis".to_string()),
+            ..Default::default()
+        };
+        assert!(diff(&old, &old.clone()).is_empty());
+
+        let changes = diff(&old, &Tariff::default());
+        assert_eq!(
+            changes,
+            vec![FieldChange {
+                path: "/tariffName".to_string(),
+                old_value: Some(json!("Basis")),
+                new_value: None,
+            }]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Length-prefixed framing for sending BO4E objects over a byte stream.
+//!
+//! Each frame is a 4-byte big-endian length followed by that many bytes of
+//! JSON. The reader rejects frames longer than a limit before allocating,
+//! so a corrupt or malicious prefix cannot exhaust memory.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bo4e_core::bo::Meter;
+//! use bo4e_serde::framing::{read_framed, write_framed};
+//!
+//! let meter = Meter {
+//!     meter_number: Some("1EMH0012345678".to_string()),
+//!     ..Default::default()
+//! };
+//!
+//! let mut buffer = Vec::new();
+//! write_framed(&mut buffer, &meter).unwrap();
+//! let received: Meter = read_framed(&mut buffer.as_slice()).unwrap();
+//! assert_eq!(received, meter);
+//! ```
+
+use std::io::{Read, Write};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{from_slice, serialize, Error};
+
+/// Default maximum frame length accepted by [`read_framed`] (16 MiB).
+pub const DEFAULT_MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Write `value` as one frame to `w`.
+pub fn write_framed<T: Serialize, W: Write>(w: &mut W, value: &T) -> Result<(), Error> {
+    let json = serialize::to_vec(value)?;
+    let len = u32::try_from(json.len())
+        .map_err(|_| Error::Serialize(format!("frame of {} bytes exceeds 4 GiB", json.len())))?;
+    w.write_all(&len.to_be_bytes())
+        .and_then(|()| w.write_all(&json))
+        .map_err(|e| Error::Serialize(e.to_string()))
+}
+
+/// Read one frame from `r`, accepting at most [`DEFAULT_MAX_FRAME_LEN`] bytes.
+pub fn read_framed<T: DeserializeOwned, R: Read>(r: &mut R) -> Result<T, Error> {
+    read_framed_with_limit(r, DEFAULT_MAX_FRAME_LEN)
+}
+
+/// Read one frame from `r`, rejecting frames longer than `max_len` bytes.
+///
+/// The length prefix is checked before the payload is read, so oversized
+/// frames fail without allocating.
+pub fn read_framed_with_limit<T: DeserializeOwned, R: Read>(
+    r: &mut R,
+    max_len: usize,
+) -> Result<T, Error> {
+    let mut prefix = [0u8; 4];
+    r.read_exact(&mut prefix).map_err(io_error)?;
+    let len = u32::from_be_bytes(prefix) as usize;
+    if len > max_len {
+        return Err(Error::Deserialize(format!(
+            "frame of {} bytes exceeds the limit of {} bytes",
+            len, max_len
+        )));
+    }
+    let mut payload = vec![0u8; len];
+    r.read_exact(&mut payload).map_err(io_error)?;
+    from_slice(&mut payload).map_err(Error::from)
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::Deserialize(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+    use bo4e_core::enums::Division;
+    use std::io::{Cursor, Seek, SeekFrom};
+
+    #[test]
+    fn test_meter_roundtrip_over_stream() {
+        let meters: Vec<Meter> = (1..=3)
+            .map(|i| Meter {
+                meter_number: Some(format!("1EMH000000000{}", i)),
+                division: Some(Division::Electricity),
+                ..Default::default()
+            })
+            .collect();
+
+        let mut stream = Cursor::new(Vec::new());
+        for meter in &meters {
+            write_framed(&mut stream, meter).unwrap();
+        }
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let received: Vec<Meter> = (0..3).map(|_| read_framed(&mut stream).unwrap()).collect();
+        assert_eq!(received, meters);
+
+        // The stream is exhausted
+        assert!(read_framed::<Meter, _>(&mut stream).is_err());
+    }
+
+    #[test]
+    fn test_rejects_oversized_prefix() {
+        let mut input = u32::MAX.to_be_bytes().to_vec();
+        input.extend_from_slice(b"{}");
+        let err = read_framed::<Meter, _>(&mut input.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+
+        let mut buffer = Vec::new();
+        write_framed(&mut buffer, &Meter::default()).unwrap();
+        assert!(read_framed_with_limit::<Meter, _>(&mut buffer.as_slice(), 1).is_err());
+        assert!(read_framed_with_limit::<Meter, _>(&mut buffer.as_slice(), 64).is_ok());
+    }
+}
@@ -0,0 +1,203 @@
+//! String interning for ingesting many objects with repeated values.
+//!
+//! Fields like network operator codes or OBIS codes repeat across millions
+//! of objects. Declaring them as `Arc<str>` with
+//! `#[serde(deserialize_with = "bo4e_serde::intern::deserialize")]` and
+//! parsing with [`from_json_interned`] stores each distinct value once and
+//! hands out shared references to it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use bo4e_serde::intern::{from_json_interned, Interner};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Row {
+//!     #[serde(deserialize_with = "bo4e_serde::intern::deserialize")]
+//!     operator: Arc<str>,
+//! }
+//!
+//! let mut interner = Interner::new();
+//! let mut json = br#"[{"operator":"9900000000001"},{"operator":"9900000000001"}]"#.to_vec();
+//! let rows: Vec<Row> = from_json_interned(&mut json, &mut interner).unwrap();
+//! assert!(Arc::ptr_eq(&rows[0].operator, &rows[1].operator));
+//! assert_eq!(interner.len(), 1);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::{self, DeserializeOwned, Deserializer, Visitor};
+
+use crate::{from_slice, Error};
+
+/// A pool of shared strings.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl Interner {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the pooled copy of `value`, adding it if not yet present.
+    pub fn intern(&mut self, value: &str) -> Arc<str> {
+        if let Some(existing) = self.strings.get(value) {
+            return Arc::clone(existing);
+        }
+        let interned: Arc<str> = Arc::from(value);
+        self.strings.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Number of distinct strings in the pool.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+// Pool used by `deserialize` while `from_json_interned` runs on this thread
+thread_local! {
+    static ACTIVE: RefCell<Option<Interner>> = const { RefCell::new(None) };
+}
+
+/// Deserialize from JSON, interning all fields that use [`deserialize`].
+///
+/// The pool is kept across calls, so passing the same interner to several
+/// calls deduplicates values across all of them.
+pub fn from_json_interned<T: DeserializeOwned>(
+    json: &mut [u8],
+    interner: &mut Interner,
+) -> Result<T, Error> {
+    let previous = ACTIVE.with(|a| a.replace(Some(std::mem::take(interner))));
+    let result = from_slice(json).map_err(Error::from);
+    if let Some(pool) = ACTIVE.with(|a| a.replace(previous)) {
+        *interner = pool;
+    }
+    result
+}
+
+/// Deserialize a string into an `Arc<str>`, interning it if a pool is active.
+///
+/// Outside of [`from_json_interned`] each value gets its own allocation.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<str>, D::Error> {
+    deserializer.deserialize_str(ArcStrVisitor)
+}
+
+/// Like [`deserialize`], for optional fields.
+///
+/// Combine with `#[serde(default)]` so that missing fields become `None`.
+pub fn deserialize_option<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Arc<str>>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor)
+}
+
+fn intern(value: &str) -> Arc<str> {
+    ACTIVE.with(|a| match a.borrow_mut().as_mut() {
+        Some(pool) => pool.intern(value),
+        None => Arc::from(value),
+    })
+}
+
+struct ArcStrVisitor;
+
+impl<'de> Visitor<'de> for ArcStrVisitor {
+    type Value = Arc<str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(intern(value))
+    }
+}
+
+struct OptionVisitor;
+
+impl<'de> Visitor<'de> for OptionVisitor {
+    type Value = Option<Arc<str>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserialize(deserializer).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct MeterRow {
+        meter_number: String,
+        #[serde(deserialize_with = "deserialize")]
+        network_operator_code: Arc<str>,
+        #[serde(default, deserialize_with = "deserialize_option")]
+        obis_code: Option<Arc<str>>,
+    }
+
+    #[test]
+    fn test_shared_operator_code_allocated_once() {
+        let rows: Vec<String> = (0..10_000)
+            .map(|i| {
+                format!(
+                    r#"{{"meterNumber":"1EMH{:010}","networkOperatorCode":"9900000000001","obisCode":"1-0:1.8.0"}}"#,
+                    i
+                )
+            })
+            .collect();
+        let mut json = format!("[{}]", rows.join(",")).into_bytes();
+
+        let mut interner = Interner::new();
+        let meters: Vec<MeterRow> = from_json_interned(&mut json, &mut interner).unwrap();
+
+        assert_eq!(meters.len(), 10_000);
+        assert_eq!(meters[9_999].meter_number, "1EMH0000009999");
+        // Two distinct values instead of 20,000 separate allocations
+        assert_eq!(interner.len(), 2);
+        let code = &meters[0].network_operator_code;
+        assert!(meters
+            .iter()
+            .all(|m| Arc::ptr_eq(&m.network_operator_code, code)));
+        assert_eq!(Arc::strong_count(code), 10_001);
+    }
+
+    #[test]
+    fn test_without_pool() {
+        let mut json = br#"[{"meterNumber":"1","networkOperatorCode":"A"},{"meterNumber":"2","networkOperatorCode":"A","obisCode":null}]"#.to_vec();
+        let meters: Vec<MeterRow> = from_slice(&mut json).unwrap();
+        assert!(!Arc::ptr_eq(
+            &meters[0].network_operator_code,
+            &meters[1].network_operator_code
+        ));
+        assert_eq!(meters[1].obis_code, None);
+    }
+}
@@ -26,18 +26,48 @@
 //! let parsed: Meter = from_json(&mut bytes)?;
 //! ```
 
+pub mod borrowed;
+#[cfg(feature = "cbor")]
+mod cbor;
 mod config;
+#[cfg(feature = "csv")]
+mod csv;
+mod deprecation;
+pub mod diff;
+pub mod framing;
+pub mod intern;
 pub mod mapping;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod number;
+pub mod patch;
 pub mod serialize;
 pub mod simd;
+mod stream;
+mod strict;
+mod transcode;
 
 pub use config::{
-    current_config, current_language, set_config, with_config, JsonLanguage, SerializeConfig,
+    current_config, current_language, set_config, with_config, JsonLanguage, MetaKeyStyle,
+    SerializeConfig,
 };
+pub use deprecation::Warning;
 pub use serialize::{to_string, to_string_pretty, to_vec};
 pub use simd::{from_slice, from_str, from_vec};
+pub use stream::JsonArrayStream;
+pub use transcode::transcode_enums;
 
-use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "csv")]
+pub use self::csv::{from_csv, to_csv};
+#[cfg(feature = "cbor")]
+pub use cbor::{from_cbor, to_cbor};
+#[cfg(feature = "msgpack")]
+pub use msgpack::{from_msgpack, to_msgpack};
+
+use bo4e_core::bo::Bo4eAny;
+use bo4e_core::ser::{with_emit_empty_lists, with_emit_nulls};
+use bo4e_core::Bo4eObject;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// Error type for serialization operations.
 #[derive(Debug)]
@@ -80,6 +110,20 @@ pub fn to_json_german<T: Serialize>(value: &T) -> Result<String, Error> {
     })
 }
 
+/// Serialize each object of a slice to JSON with German field names.
+///
+/// Equivalent to calling [`to_json_german`] per element, but the config is
+/// installed once for the whole batch. Stops at the first element that
+/// fails; the previous config is restored either way.
+pub fn to_json_german_each<T: Serialize>(values: &[T]) -> Result<Vec<String>, Error> {
+    with_config(SerializeConfig::german(), || {
+        values
+            .iter()
+            .map(|value| serde_json::to_string(value).map_err(Error::from))
+            .collect()
+    })
+}
+
 /// Serialize a BO4E object to JSON with English field names.
 pub fn to_json_english<T: Serialize>(value: &T) -> Result<String, Error> {
     with_config(SerializeConfig::english(), || {
@@ -93,14 +137,143 @@ pub fn to_json_with_config<T: Serialize>(
     config: &SerializeConfig,
 ) -> Result<String, Error> {
     with_config(config.clone(), || {
-        if config.pretty {
-            serde_json::to_string_pretty(value).map_err(Error::from)
-        } else {
-            serde_json::to_string(value).map_err(Error::from)
-        }
+        with_emit_nulls(config.include_nulls, || {
+            with_emit_empty_lists(config.emit_empty_arrays, || {
+                if serialize::needs_value_pass(config) {
+                    let mut tree = serde_json::to_value(value)?;
+                    serialize::apply_config(&mut tree, config, std::any::type_name::<T>());
+                    if number::needs_number_pass(config) {
+                        number::to_string(&tree, config).map_err(Error::from)
+                    } else if config.pretty {
+                        serde_json::to_string_pretty(&tree).map_err(Error::from)
+                    } else {
+                        serde_json::to_string(&tree).map_err(Error::from)
+                    }
+                } else if config.pretty {
+                    serde_json::to_string_pretty(value).map_err(Error::from)
+                } else {
+                    serde_json::to_string(value).map_err(Error::from)
+                }
+            })
+        })
     })
 }
 
+/// Serialize to the smallest JSON representation.
+///
+/// The output has no insignificant whitespace, and after serialization all
+/// `null` values and empty objects and arrays are removed recursively, array
+/// elements included, so a nested component whose fields are all unset is
+/// dropped entirely.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::MarketLocation;
+/// use bo4e_core::com::Address;
+/// use bo4e_serde::to_json_compact;
+///
+/// let malo = MarketLocation {
+///     market_location_id: Some("51238696781".to_string()),
+///     address: Some(Address::default()),
+///     ..Default::default()
+/// };
+/// assert_eq!(to_json_compact(&malo).unwrap(), r#"{"marketLocationId":"51238696781"}"#);
+/// ```
+pub fn to_json_compact<T: Serialize>(value: &T) -> Result<String, Error> {
+    let mut tree = serde_json::to_value(value)?;
+    serialize::prune_empty(&mut tree);
+    serde_json::to_string(&tree).map_err(Error::from)
+}
+
+/// Stable SHA-256 content hash of an object, as a lowercase hex digest.
+///
+/// The object is serialized to JSON with object keys sorted and every
+/// `_version` stamp removed, so the id depends only on the content, not on
+/// field order or the BO4E version an object was written with.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::content_id;
+///
+/// let id = content_id(&Meter::default()).unwrap();
+/// assert_eq!(id.len(), 64);
+/// ```
+pub fn content_id<T: Serialize>(value: &T) -> Result<String, Error> {
+    use sha2::{Digest, Sha256};
+
+    let canonical = canonicalize(serde_json::to_value(value)?);
+    let digest = Sha256::digest(serde_json::to_vec(&canonical)?);
+    Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Sort object keys recursively and drop `_version`.
+///
+/// Rebuilding the maps keeps the keys sorted even if `serde_json` is built
+/// with `preserve_order`.
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map
+                .into_iter()
+                .filter(|(key, _)| key != "_version")
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonicalize).collect()),
+        other => other,
+    }
+}
+
+/// Wrap a BO4E object in a JSON:API document.
+///
+/// Produces `{"data": {"type": ..., "id": ..., "attributes": {...}}}`. The
+/// resource type is the German or English type name of `T`, following the
+/// language of the current config. The `_typ` discriminator is removed from
+/// the attributes, since the resource type already carries it.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::to_jsonapi;
+///
+/// let meter = Meter::new("1EMH0012345678", bo4e_core::enums::Division::Electricity);
+/// let doc = to_jsonapi(&meter, "42").unwrap();
+/// assert_eq!(doc["data"]["type"], "Zaehler");
+/// assert_eq!(doc["data"]["attributes"]["meterNumber"], "1EMH0012345678");
+/// ```
+pub fn to_jsonapi<T: Bo4eObject + Serialize>(
+    value: &T,
+    id: &str,
+) -> Result<serde_json::Value, Error> {
+    let mut attributes = serde_json::to_value(value)?;
+    if let serde_json::Value::Object(map) = &mut attributes {
+        map.remove("_typ");
+    }
+    let resource_type = match current_language() {
+        JsonLanguage::German => T::type_name_german(),
+        JsonLanguage::English => T::type_name_english(),
+    };
+    Ok(serde_json::json!({
+        "data": {
+            "type": resource_type,
+            "id": id,
+            "attributes": attributes,
+        }
+    }))
+}
+
 /// Deserialize a BO4E object from JSON.
 ///
 /// Accepts both German and English field names.
@@ -109,6 +282,264 @@ pub fn from_json<T: DeserializeOwned>(json: &mut [u8]) -> Result<T, Error> {
     from_slice(json).map_err(Error::from)
 }
 
+/// Deserialize the first JSON value from `json`, ignoring what follows.
+///
+/// Returns the value and the number of bytes it took up, including a leading
+/// byte order mark and whitespace, so concatenated documents can be parsed
+/// one after another.
+/// Only the consumed bytes are modified by parsing.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::from_json_prefix;
+///
+/// let mut bytes = br#"{"zaehlernummer":"1"}{"zaehlernummer":"2"}"#.to_vec();
+/// let (first, used): (Meter, usize) = from_json_prefix(&mut bytes).unwrap();
+/// let (second, _): (Meter, usize) = from_json_prefix(&mut bytes[used..]).unwrap();
+/// assert_eq!(first.meter_number.as_deref(), Some("1"));
+/// assert_eq!(second.meter_number.as_deref(), Some("2"));
+/// ```
+pub fn from_json_prefix<T: DeserializeOwned>(json: &mut [u8]) -> Result<(T, usize), Error> {
+    let skipped = json.len() - simd::trim_start(json).len();
+    let end = stream::first_value_end(&json[skipped..])
+        .map(|len| skipped + len)
+        .ok_or_else(|| Error::Deserialize("no complete JSON value in input".to_string()))?;
+    let value = from_slice(&mut json[skipped..end])?;
+    Ok((value, end))
+}
+
+/// Deserialize from JSON, borrowing string fields from `json`.
+///
+/// simd-json unescapes strings in place, so `&str` and borrowed `Cow<str>`
+/// fields of `T` point into the buffer instead of being copied. See
+/// [`borrowed`] for views of the BO4E types.
+pub fn from_json_borrowed<'a, T: Deserialize<'a>>(json: &'a mut [u8]) -> Result<T, Error> {
+    simd_json::serde::from_slice(simd::prepare(json)?).map_err(Error::from)
+}
+
+/// Deserialize any Business Object from JSON, dispatching on its `_typ` field.
+///
+/// The discriminator may use either the German (`"Zaehler"`) or English
+/// (`"Meter"`) type name. A missing or unknown `_typ` is an error.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Bo4eAny;
+/// use bo4e_serde::from_json_any;
+///
+/// let mut bytes = br#"{"_typ":"Zaehler","zaehlernummer":"123"}"#.to_vec();
+/// let any = from_json_any(&mut bytes).unwrap();
+/// assert!(matches!(any, Bo4eAny::Meter(_)));
+/// ```
+pub fn from_json_any(json: &mut [u8]) -> Result<Bo4eAny, Error> {
+    let value: serde_json::Value = from_slice(json)?;
+    let typ = match value.get("_typ").or_else(|| value.get("boTyp")) {
+        Some(serde_json::Value::String(typ)) => typ,
+        Some(_) => {
+            return Err(Error::Deserialize(
+                "`_typ` discriminator must be a string".to_string(),
+            ))
+        }
+        None => {
+            return Err(Error::Deserialize(
+                "missing `_typ` discriminator".to_string(),
+            ))
+        }
+    };
+    let bo_type = Bo4eAny::resolve_type(typ)
+        .ok_or_else(|| Error::Deserialize(format!("unknown `_typ` discriminator: {:?}", typ)))?;
+    Bo4eAny::deserialize_as(bo_type, value).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+/// Check that `json` is a well-formed BO4E object without deserializing it.
+///
+/// Parses the input into a simd-json tape, which is much cheaper than
+/// building a value or a concrete type, and checks that it is a JSON object
+/// whose `_typ` names a known Business Object in German or English. Use
+/// this as a pre-filter before [`from_json`] or [`from_json_any`]. Like
+/// those, it unescapes strings in place, so the buffer is modified.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_serde::quick_validate;
+///
+/// let mut bytes = br#"{"_typ":"Zaehler","zaehlernummer":"123"}"#.to_vec();
+/// assert!(quick_validate(&mut bytes).is_ok());
+///
+/// let mut truncated = br#"{"_typ":"Zaehler","zaehlernummer":"#.to_vec();
+/// assert!(quick_validate(&mut truncated).is_err());
+/// ```
+pub fn quick_validate(json: &mut [u8]) -> Result<(), Error> {
+    use simd_json::prelude::ValueAsScalar;
+
+    let tape = simd_json::to_tape(simd::prepare(json)?)?;
+    let value = tape.as_value();
+    let object = value
+        .as_object()
+        .ok_or_else(|| Error::Deserialize("expected a JSON object".to_string()))?;
+    let typ = object
+        .get("_typ")
+        .or_else(|| object.get("boTyp"))
+        .ok_or_else(|| Error::Deserialize("missing `_typ` discriminator".to_string()))?;
+    let typ = typ
+        .as_str()
+        .ok_or_else(|| Error::Deserialize("`_typ` discriminator must be a string".to_string()))?;
+    match Bo4eAny::resolve_type(typ) {
+        Some(_) => Ok(()),
+        None => Err(Error::Deserialize(format!(
+            "unknown `_typ` discriminator: {:?}",
+            typ
+        ))),
+    }
+}
+
+/// Deserialize a BO4E object from JSON, checking its `_typ` discriminator.
+///
+/// Like [`from_json`], but if the input carries a `_typ` it must name `T`,
+/// in either its German or English form. Input without `_typ` is accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::from_json_checked;
+///
+/// let mut bytes = br#"{"_typ":"Marktlokation","marktlokationsId":"51238696781"}"#.to_vec();
+/// let err = from_json_checked::<Meter>(&mut bytes).unwrap_err();
+/// assert!(err.to_string().contains("type mismatch"));
+/// ```
+pub fn from_json_checked<T: Bo4eObject + DeserializeOwned>(json: &mut [u8]) -> Result<T, Error> {
+    let value: serde_json::Value = from_slice(json)?;
+    match value.get("_typ").or_else(|| value.get("boTyp")) {
+        None => {}
+        Some(serde_json::Value::String(typ))
+            if typ == T::type_name_german() || typ == T::type_name_english() => {}
+        Some(serde_json::Value::String(typ)) => {
+            return Err(Error::Deserialize(format!(
+                "type mismatch: expected `_typ` {:?}, found {:?}",
+                T::type_name_german(),
+                typ
+            )))
+        }
+        Some(_) => {
+            return Err(Error::Deserialize(
+                "`_typ` discriminator must be a string".to_string(),
+            ))
+        }
+    }
+    serde_json::from_value(value).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+/// Deserialize a BO4E object from JSON, rejecting unknown fields.
+///
+/// Accepts German and English field names like [`from_json`], but fails if
+/// the input contains fields (at any nesting level) that the target type
+/// does not declare. The error lists the paths of all unexpected fields.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::from_json_strict;
+///
+/// let mut bytes = br#"{"zaehlernummer":"123","foo":1}"#.to_vec();
+/// let err = from_json_strict::<Meter>(&mut bytes).unwrap_err();
+/// assert!(err.to_string().contains("foo"));
+/// ```
+pub fn from_json_strict<T: DeserializeOwned>(json: &mut [u8]) -> Result<T, Error> {
+    let value: serde_json::Value = from_slice(json)?;
+    let (parsed, unknown) =
+        strict::deserialize_tracked(value).map_err(|e| Error::Deserialize(e.to_string()))?;
+    if unknown.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(Error::Deserialize(format!(
+            "unknown fields: {}",
+            unknown.join(", ")
+        )))
+    }
+}
+
+/// Deserialize a BO4E object from JSON, rejecting field names of the other language.
+///
+/// Before deserializing, every top-level key is looked up in the field name
+/// vocabulary of [`mapping`]. Keys that are only known in the other
+/// language make the input fail; keys unknown to both vocabularies and the
+/// metadata keys (`_typ`, `_version`, `_id`) are accepted. The input must be
+/// a JSON object.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::{from_json_strict_language, JsonLanguage};
+///
+/// let mut german = br#"{"zaehlernummer":"123"}"#.to_vec();
+/// assert!(from_json_strict_language::<Meter>(&mut german, JsonLanguage::German).is_ok());
+///
+/// let mut english = br#"{"meterNumber":"123"}"#.to_vec();
+/// assert!(from_json_strict_language::<Meter>(&mut english, JsonLanguage::German).is_err());
+/// ```
+pub fn from_json_strict_language<T: DeserializeOwned>(
+    json: &mut [u8],
+    language: JsonLanguage,
+) -> Result<T, Error> {
+    let value: serde_json::Value = from_slice(json)?;
+    let object = value
+        .as_object()
+        .ok_or_else(|| Error::Deserialize("expected a JSON object".to_string()))?;
+    let foreign: Vec<&str> = object
+        .keys()
+        .map(String::as_str)
+        .filter(|key| match language {
+            JsonLanguage::German => {
+                mapping::is_english_json_key(key) && !mapping::is_german_field(key)
+            }
+            JsonLanguage::English => {
+                mapping::is_german_field(key) && !mapping::is_english_json_key(key)
+            }
+        })
+        .collect();
+    if !foreign.is_empty() {
+        return Err(Error::Deserialize(format!(
+            "field names not in {:?} vocabulary: {}",
+            language,
+            foreign.join(", ")
+        )));
+    }
+    serde_json::from_value(value).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+/// Deserialize a BO4E object from JSON, reporting deprecated fields.
+///
+/// Parses like [`from_json`], and additionally returns a [`Warning`] for
+/// every deprecated field found at any nesting level. Warnings never make
+/// the call fail.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::MarketLocation;
+/// use bo4e_serde::from_json_with_warnings;
+///
+/// let mut bytes = br#"{"marktlokationsId":"51238696781","grundversorgerCodenummer":"9900000000001"}"#.to_vec();
+/// let (malo, warnings) = from_json_with_warnings::<MarketLocation>(&mut bytes).unwrap();
+/// assert_eq!(malo.basic_supplier_code.as_deref(), Some("9900000000001"));
+/// assert_eq!(warnings[0].field, "grundversorgerCodenummer");
+/// ```
+pub fn from_json_with_warnings<T: DeserializeOwned>(
+    json: &mut [u8],
+) -> Result<(T, Vec<Warning>), Error> {
+    let value: serde_json::Value = from_slice(json)?;
+    let warnings = deprecation::collect(&value);
+    let parsed = serde_json::from_value(value).map_err(|e| Error::Deserialize(e.to_string()))?;
+    Ok((parsed, warnings))
+}
+
 /// Deserialize from a string.
 pub fn from_json_str<T: DeserializeOwned>(json: &str) -> Result<T, Error> {
     from_str(json).map_err(Error::from)
@@ -131,6 +562,74 @@ mod tests {
         assert!(json.contains("meterNumber"));
     }
 
+    #[test]
+    fn test_german_each() {
+        let meters: Vec<Meter> = (0..100)
+            .map(|i| Meter {
+                meter_number: Some(format!("1EMH{:010}", i)),
+                ..Default::default()
+            })
+            .collect();
+
+        let batch = to_json_german_each(&meters).unwrap();
+        let single: Vec<String> = meters.iter().map(|m| to_json_german(m).unwrap()).collect();
+        assert_eq!(batch, single);
+    }
+
+    #[test]
+    fn test_german_each_restores_config_on_error() {
+        struct Failing;
+        impl Serialize for Failing {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("boom"))
+            }
+        }
+
+        let result = with_config(SerializeConfig::english(), || {
+            let result = to_json_german_each(&[Failing]);
+            assert_eq!(current_language(), JsonLanguage::English);
+            result
+        });
+        assert!(matches!(result, Err(Error::Serialize(_))));
+    }
+
+    #[test]
+    fn test_content_id_ignores_version() {
+        let meter = Meter {
+            meta: bo4e_core::Bo4eMeta::with_type("Zaehler").version("202401.0.1"),
+            meter_number: Some("1EMH0012345678".to_string()),
+            ..Default::default()
+        };
+        let mut newer = meter.clone();
+        newer.meta.version = Some("202501.0.0".to_string());
+
+        let id = content_id(&meter).unwrap();
+        assert_eq!(id.len(), 64);
+        assert_eq!(id, content_id(&newer).unwrap());
+
+        newer.meter_number = Some("1EMH0012345679".to_string());
+        assert_ne!(id, content_id(&newer).unwrap());
+    }
+
+    #[test]
+    fn test_jsonapi() {
+        let meter = Meter {
+            meta: bo4e_core::Bo4eMeta::with_type("Zaehler"),
+            meter_number: Some("1EMH0012345678".to_string()),
+            ..Default::default()
+        };
+
+        let doc = to_jsonapi(&meter, "meter-1").unwrap();
+        let data = &doc["data"];
+        assert_eq!(data["type"], "Zaehler");
+        assert_eq!(data["id"], "meter-1");
+        assert_eq!(data["attributes"]["meterNumber"], "1EMH0012345678");
+        assert!(data["attributes"].get("_typ").is_none());
+
+        let english = with_config(SerializeConfig::english(), || to_jsonapi(&meter, "meter-1"));
+        assert_eq!(english.unwrap()["data"]["type"], "Meter");
+    }
+
     #[test]
     fn test_english_serialization() {
         let meter = Meter {
@@ -176,4 +675,276 @@ mod tests {
         // Pretty-printed JSON should contain newlines
         assert!(json.contains('\n'));
     }
+
+    #[test]
+    fn test_to_json_compact_drops_empty_address() {
+        let malo = bo4e_core::bo::MarketLocation {
+            meta: bo4e_core::Bo4eMeta::with_type("Marktlokation"),
+            market_location_id: Some("51238696781".to_string()),
+            address: Some(bo4e_core::com::Address::default()),
+            ..Default::default()
+        };
+        assert!(to_json_german(&malo).unwrap().contains("address"));
+
+        let json = to_json_compact(&malo).unwrap();
+        assert_eq!(
+            json,
+            r#"{"_typ":"Marktlokation","marketLocationId":"51238696781"}"#
+        );
+    }
+
+    #[test]
+    fn test_from_json_any_meter() {
+        let mut bytes = br#"{"_typ":"Zaehler","zaehlernummer":"ANY1"}"#.to_vec();
+        match from_json_any(&mut bytes).unwrap() {
+            Bo4eAny::Meter(meter) => {
+                assert_eq!(meter.meter_number, Some("ANY1".to_string()));
+            }
+            other => panic!("Expected Meter, got {:?}", other.bo_type()),
+        }
+    }
+
+    #[test]
+    fn test_from_json_any_invoice() {
+        let mut bytes = br#"{"_typ":"Rechnung","invoiceNumber":"R-2024-001"}"#.to_vec();
+        match from_json_any(&mut bytes).unwrap() {
+            Bo4eAny::Invoice(invoice) => {
+                assert_eq!(invoice.invoice_number, Some("R-2024-001".to_string()));
+            }
+            other => panic!("Expected Invoice, got {:?}", other.bo_type()),
+        }
+    }
+
+    #[test]
+    fn test_from_json_any_unknown_or_missing_typ() {
+        let mut unknown = br#"{"_typ":"Raumschiff"}"#.to_vec();
+        let err = from_json_any(&mut unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown `_typ`"));
+
+        let mut missing = br#"{"zaehlernummer":"123"}"#.to_vec();
+        let err = from_json_any(&mut missing).unwrap_err();
+        assert!(err.to_string().contains("missing `_typ`"));
+    }
+
+    #[test]
+    fn test_quick_validate() {
+        let mut meter =
+            br#"{"_typ":"Meter","meterNumber":"1","zaehlwerke":[{"obisKennzahl":"1-0:1.8.0"}]}"#
+                .to_vec();
+        assert!(quick_validate(&mut meter).is_ok());
+
+        let mut malformed = br#"{"_typ":"Zaehler","zaehlernummer":"1",}"#.to_vec();
+        assert!(quick_validate(&mut malformed).is_err());
+
+        let mut missing = br#"{"zaehlernummer":"1","nested":{"_typ":"Zaehler"}}"#.to_vec();
+        let err = quick_validate(&mut missing).unwrap_err();
+        assert!(err.to_string().contains("missing `_typ`"));
+
+        let mut unknown = br#"{"_typ":"Raumschiff"}"#.to_vec();
+        let err = quick_validate(&mut unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown `_typ`"));
+
+        let mut array = br#"[{"_typ":"Zaehler"}]"#.to_vec();
+        assert!(quick_validate(&mut array).is_err());
+    }
+
+    #[test]
+    fn test_from_json_checked_rejects_other_type() {
+        let mut bytes =
+            br#"{"_typ":"Marktlokation","marktlokationsId":"51238696781","sparte":"STROM"}"#
+                .to_vec();
+        let err = from_json_checked::<Meter>(&mut bytes).unwrap_err();
+        assert!(matches!(err, Error::Deserialize(_)));
+        assert!(err.to_string().contains("type mismatch"));
+        assert!(err.to_string().contains("Marktlokation"));
+
+        for typ in ["Zaehler", "Meter"] {
+            let mut bytes = format!(r#"{{"_typ":"{}","zaehlernummer":"1"}}"#, typ).into_bytes();
+            let meter = from_json_checked::<Meter>(&mut bytes).unwrap();
+            assert_eq!(meter.meter_number, Some("1".to_string()));
+        }
+
+        let mut untyped = br#"{"zaehlernummer":"2"}"#.to_vec();
+        assert!(from_json_checked::<Meter>(&mut untyped).is_ok());
+    }
+
+    #[test]
+    fn test_from_json_with_warnings_deprecated_field() {
+        let mut bytes = br#"{
+            "_typ": "Marktlokation",
+            "marktlokationsId": "51238696781",
+            "grundversorgerCodenummer": "9900000000001"
+        }"#
+        .to_vec();
+        let (malo, warnings) =
+            from_json_with_warnings::<bo4e_core::bo::MarketLocation>(&mut bytes).unwrap();
+        assert_eq!(malo.basic_supplier_code, Some("9900000000001".to_string()));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "/grundversorgerCodenummer");
+        assert_eq!(
+            warnings[0].to_string(),
+            "field `grundversorgerCodenummer` is deprecated"
+        );
+
+        let mut clean = br#"{"marktlokationsId":"51238696781"}"#.to_vec();
+        let (_, warnings) =
+            from_json_with_warnings::<bo4e_core::bo::MarketLocation>(&mut clean).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_from_json_strict_accepts_known_fields() {
+        let mut bytes =
+            br#"{"_typ":"Zaehler","zaehlernummer":"STRICT1","sparte":"STROM"}"#.to_vec();
+        let meter: Meter = from_json_strict(&mut bytes).unwrap();
+        assert_eq!(meter.meter_number, Some("STRICT1".to_string()));
+    }
+
+    #[test]
+    fn test_from_json_strict_rejects_unknown_field() {
+        let mut bytes = br#"{"_typ":"Zaehler","meterNumber":"STRICT2","foo":"bar"}"#.to_vec();
+        let err = from_json_strict::<Meter>(&mut bytes).unwrap_err();
+        assert!(matches!(err, Error::Deserialize(_)));
+        assert!(err.to_string().contains("unknown fields: foo"));
+    }
+
+    #[test]
+    fn test_with_config_skip_meta() {
+        let meter = Meter {
+            meta: bo4e_core::Bo4eMeta::with_type("Zaehler").version("202401.0.1"),
+            meter_number: Some("NOMETA".to_string()),
+            ..Default::default()
+        };
+
+        let config = SerializeConfig::german().with_skip_meta(true);
+        let json = to_json_with_config(&meter, &config).unwrap();
+        assert!(!json.contains("_typ"));
+        assert!(!json.contains("_version"));
+        assert!(json.contains(r#""meterNumber":"NOMETA""#));
+
+        let mut bytes = json.into_bytes();
+        let parsed: Meter = from_json(&mut bytes).unwrap();
+        assert_eq!(parsed.meter_number, meter.meter_number);
+        assert_eq!(parsed.meta, bo4e_core::Bo4eMeta::default());
+    }
+
+    #[test]
+    fn test_with_config_meta_key_style() {
+        let meter = Meter {
+            meta: bo4e_core::Bo4eMeta::with_type("Zaehler")
+                .version("202401.0.1")
+                .id("42"),
+            meter_number: Some("1EMH0012345678".to_string()),
+            ..Default::default()
+        };
+
+        let config = SerializeConfig::german().with_meta_key_style(MetaKeyStyle::BoPrefix);
+        let json = to_json_with_config(&meter, &config).unwrap();
+        assert!(!json.contains("\"_"));
+        assert!(json.contains(r#""boTyp":"Zaehler""#));
+        assert!(json.contains(r#""boVersion":"202401.0.1""#));
+        assert!(json.contains(r#""boId":"42""#));
+
+        let parsed: Meter = from_json(&mut json.clone().into_bytes()).unwrap();
+        assert_eq!(parsed, meter);
+        let strict: Meter = from_json_strict(&mut json.clone().into_bytes()).unwrap();
+        assert_eq!(strict, meter);
+        assert!(matches!(
+            from_json_any(&mut json.into_bytes()).unwrap(),
+            Bo4eAny::Meter(_)
+        ));
+    }
+
+    #[test]
+    fn test_from_json_strict_language() {
+        let mut german = br#"{"_typ":"Zaehler","zaehlernummer":"DE1","sparte":"STROM"}"#.to_vec();
+        let meter: Meter = from_json_strict_language(&mut german, JsonLanguage::German).unwrap();
+        assert_eq!(meter.meter_number, Some("DE1".to_string()));
+
+        let mut english = br#"{"meterNumber":"EN1","division":"STROM"}"#.to_vec();
+        let err = from_json_strict_language::<Meter>(&mut english.clone(), JsonLanguage::German)
+            .unwrap_err();
+        assert!(err.to_string().contains("meterNumber"));
+        assert!(from_json_strict_language::<Meter>(&mut english, JsonLanguage::English).is_ok());
+    }
+
+    #[test]
+    fn test_with_config_emit_nulls() {
+        let config = SerializeConfig::german().with_emit_nulls(true);
+        let json = to_json_with_config(&Meter::default(), &config).unwrap();
+        assert!(json.contains(r#""division":null"#));
+        assert!(json.contains(r#""registers":[]"#));
+
+        let json = to_json_with_config(&Meter::default(), &SerializeConfig::german()).unwrap();
+        assert!(!json.contains("division"));
+    }
+
+    #[test]
+    fn test_from_json_prefix_concatenated_meters() {
+        let mut bytes =
+            br#"{"_typ":"Zaehler","zaehlernummer":"1EMH0001","sparte":"STROM"} {"meterNumber":"1EMH0002"}"#
+                .to_vec();
+
+        let (first, used): (Meter, usize) = from_json_prefix(&mut bytes).unwrap();
+        assert_eq!(first.meter_number.as_deref(), Some("1EMH0001"));
+        assert_eq!(bytes[used], b' ');
+
+        let (second, rest): (Meter, usize) = from_json_prefix(&mut bytes[used..]).unwrap();
+        assert_eq!(second.meter_number.as_deref(), Some("1EMH0002"));
+        assert_eq!(used + rest, bytes.len());
+
+        assert!(from_json_prefix::<Meter>(&mut bytes[used + rest..]).is_err());
+    }
+
+    #[test]
+    fn test_entry_points_skip_bom_and_check_depth() {
+        let mut bytes = b"\xEF\xBB\xBF {\"zaehlernummer\":\"1\"}\n".to_vec();
+        let (meter, used): (Meter, usize) = from_json_prefix(&mut bytes).unwrap();
+        assert_eq!(meter.meter_number.as_deref(), Some("1"));
+        assert_eq!(used, bytes.len() - 1);
+
+        let mut bytes = b"\xEF\xBB\xBF{\"_typ\":\"Zaehler\"}".to_vec();
+        assert!(quick_validate(&mut bytes).is_ok());
+        let mut bytes = b"\xEF\xBB\xBF{\"zaehlernummer\":\"1\"}".to_vec();
+        let value: serde_json::Value = from_json_borrowed(&mut bytes).unwrap();
+        assert_eq!(value["zaehlernummer"], "1");
+
+        let deep = format!(
+            r#"{{"_typ":"Zaehler","nested":{}{}}}"#,
+            "[".repeat(8),
+            "]".repeat(8)
+        );
+        let limited = SerializeConfig::default().with_max_depth(4);
+        with_config(limited, || {
+            assert!(quick_validate(&mut deep.clone().into_bytes()).is_err());
+            assert!(
+                from_json_borrowed::<serde_json::Value>(&mut deep.clone().into_bytes()).is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn test_with_config_number_formatting() {
+        use bo4e_core::com::Amount;
+
+        let amount = Amount::eur(119.0);
+        assert_eq!(
+            to_json_with_config(&amount, &SerializeConfig::english()).unwrap(),
+            r#"{"value":119.0,"currency":"EUR"}"#
+        );
+
+        let trimmed = SerializeConfig::english().with_trim_float_trailing_zero(true);
+        let json = to_json_with_config(&amount, &trimmed).unwrap();
+        assert!(
+            json.contains(r#""value":119"#) && !json.contains("119.0"),
+            "{}",
+            json
+        );
+
+        let money = SerializeConfig::german()
+            .with_translate_keys(true)
+            .with_money_decimals(2);
+        let json = to_json_with_config(&amount, &money).unwrap();
+        assert!(json.contains(r#""wert":119.00"#), "{}", json);
+    }
 }
@@ -12,6 +12,9 @@ static ENGLISH_TO_GERMAN: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(
     map.insert("meter_type", "zaehlertyp");
     map.insert("meter_size", "zaehlergroesse");
     map.insert("registers", "zaehlwerke");
+    map.insert("hardware", "geraeteeigenschaften");
+    map.insert("installation_date", "einbaudatum");
+    map.insert("removal_date", "ausbaudatum");
 
     // MarketLocation fields
     map.insert("market_location_id", "marktlokationsId");
@@ -93,6 +96,284 @@ static GERMAN_TO_ENGLISH: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(
         .collect()
 });
 
+/// A field of a BO4E type: its English name, its German JSON key and, for
+/// fields holding another BO4E type, that type's German name.
+pub(crate) type Field = (&'static str, &'static str, Option<&'static str>);
+
+/// Fields of the BO4E types that are translated per type, by German and
+/// English type name.
+///
+/// Unlike the flat mapping above, a key is only renamed within the types
+/// that declare it, so e.g. `description` becomes `bezeichnung` in a
+/// `Zaehlwerk` but `beschreibung` in a `Vertrag`.
+static TYPE_FIELDS: &[(&str, &str, &[Field])] = &[
+    (
+        "Zaehler",
+        "Meter",
+        &[
+            ("meter_number", "zaehlernummer", None),
+            ("division", "sparte", None),
+            ("meter_type", "zaehlertyp", None),
+            ("meter_size", "zaehlergroesse", None),
+            ("location", "standort", Some("Adresse")),
+            ("registers", "zaehlwerke", Some("Zaehlwerk")),
+            ("hardware", "geraeteeigenschaften", Some("Hardware")),
+            ("market_location_id", "marktlokationsId", None),
+            ("metering_location_id", "messlokationsId", None),
+            ("ownership", "eigentumsverhaeltnis", None),
+            ("manufacturer", "hersteller", None),
+            ("manufacturing_year", "herstellungsjahr", None),
+            ("installation_date", "einbaudatum", None),
+            ("removal_date", "ausbaudatum", None),
+            ("calibration_date", "eichdatum", None),
+            ("calibration_expiry_date", "eichablaufdatum", None),
+        ],
+    ),
+    (
+        "Marktlokation",
+        "MarketLocation",
+        &[
+            ("market_location_id", "marktlokationsId", None),
+            ("division", "sparte", None),
+            ("energy_direction", "energierichtung", None),
+            ("customer_type", "kundentyp", None),
+            ("address", "adresse", Some("Adresse")),
+            ("supply_start", "lieferbeginn", None),
+            ("supply_end", "lieferende", None),
+            ("annual_consumption", "jahresverbrauchsprognose", None),
+            ("network_operator_code", "netzbetreiberCodenummer", None),
+            ("basic_supplier_code", "grundversorgerCodenummer", None),
+            (
+                "metering_operator_code",
+                "messstellenbetreiberCodenummer",
+                None,
+            ),
+            (
+                "transmission_operator_code",
+                "uebertragungsnetzbetreiberCodenummer",
+                None,
+            ),
+            ("grid_level", "netzebene", None),
+            ("network_area", "netzgebiet", None),
+            ("balancing_area", "bilanzierungsgebiet", None),
+            ("metering_location_ids", "messlokationsIds", None),
+            ("is_controllable_resource", "istSteuerbareRessource", None),
+        ],
+    ),
+    (
+        "Messlokation",
+        "MeteringLocation",
+        &[
+            ("metering_location_id", "messlokationsId", None),
+            ("division", "sparte", None),
+            ("address", "adresse", Some("Adresse")),
+            ("coordinates", "geokoordinaten", Some("Geokoordinaten")),
+            (
+                "metering_operator_code",
+                "messstellenbetreiberCodenummer",
+                None,
+            ),
+            ("network_operator_code", "netzbetreiberCodenummer", None),
+            ("grid_area", "regelzone", None),
+            ("description", "beschreibung", None),
+            ("hardware", "geraete", Some("Hardware")),
+            ("meter_ids", "zaehler", None),
+            ("market_location_ids", "marktlokationen", None),
+        ],
+    ),
+    (
+        "Geschaeftspartner",
+        "BusinessPartner",
+        &[
+            ("partner_id", "geschaeftspartnerId", None),
+            ("name1", "name1", None),
+            ("name2", "name2", None),
+            ("name3", "name3", None),
+            ("roles", "geschaeftspartnerrollen", None),
+            ("address", "adresse", Some("Adresse")),
+            ("contact_methods", "kontaktwege", Some("Kontaktweg")),
+            ("commercial_register_number", "handelsregisternummer", None),
+            ("tax_id", "steuernummer", None),
+            ("vat_id", "umsatzsteuerId", None),
+        ],
+    ),
+    (
+        "Vertrag",
+        "Contract",
+        &[
+            ("contract_number", "vertragsnummer", None),
+            ("description", "beschreibung", None),
+            ("contract_type", "vertragsart", None),
+            ("status", "vertragsstatus", None),
+            ("division", "sparte", None),
+            ("contract_start", "vertragsbeginn", None),
+            ("contract_end", "vertragsende", None),
+            ("signing_date", "unterzeichnungsdatum", None),
+            ("validity_period", "gueltigkeitszeitraum", Some("Zeitraum")),
+            (
+                "conditions",
+                "vertragskonditionen",
+                Some("Vertragskonditionen"),
+            ),
+            ("parts", "vertragsteile", Some("Vertragsteil")),
+            ("contract_partner", "vertragspartner", None),
+        ],
+    ),
+    (
+        "Rechnung",
+        "Invoice",
+        &[
+            ("invoice_number", "rechnungsnummer", None),
+            ("invoice_type", "rechnungstyp", None),
+            ("status", "rechnungsstatus", None),
+            ("division", "sparte", None),
+            ("invoice_date", "rechnungsdatum", None),
+            ("due_date", "faelligkeitsdatum", None),
+            ("billing_period", "abrechnungszeitraum", Some("Zeitraum")),
+            ("net_amount", "nettobetrag", Some("Betrag")),
+            ("tax_amount", "steuerbetrag", Some("Betrag")),
+            ("gross_amount", "bruttobetrag", Some("Betrag")),
+            (
+                "positions",
+                "rechnungspositionen",
+                Some("Rechnungsposition"),
+            ),
+            ("recipient", "rechnungsempfaenger", None),
+        ],
+    ),
+    (
+        "Adresse",
+        "Address",
+        &[
+            ("street", "strasse", None),
+            ("house_number", "hausnummer", None),
+            ("postal_code", "postleitzahl", None),
+            ("city", "ort", None),
+            ("district", "ortsteil", None),
+            ("po_box", "postfach", None),
+            ("address_addition", "adresszusatz", None),
+            ("co_ergaenzung", "coErgaenzung", None),
+            ("country_code", "landescode", None),
+        ],
+    ),
+    (
+        "Zaehlwerk",
+        "MeterRegister",
+        &[
+            ("register_id", "zaehlwerkskennung", None),
+            ("obis_code", "obisKennzahl", None),
+            ("register_type", "registerart", None),
+            ("energy_direction", "energierichtung", None),
+            ("unit", "einheit", None),
+            ("decimal_places", "nachkommastellen", None),
+            ("transformer_ratio", "wandlerfaktor", None),
+            ("description", "bezeichnung", None),
+        ],
+    ),
+    (
+        "Hardware",
+        "Hardware",
+        &[
+            ("device_number", "geraetenummer", None),
+            ("description", "bezeichnung", None),
+            ("device_category", "geraeteklasse", None),
+            ("device_type", "geraetetyp", None),
+        ],
+    ),
+    (
+        "Geokoordinaten",
+        "GeoCoordinates",
+        &[
+            ("latitude", "breitengrad", None),
+            ("longitude", "laengengrad", None),
+        ],
+    ),
+    (
+        "Kontaktweg",
+        "ContactMethod",
+        &[
+            ("contact_type", "kontaktart", None),
+            ("contact_value", "kontaktwert", None),
+            ("description", "beschreibung", None),
+            ("is_preferred", "istBevorzugterKontaktweg", None),
+        ],
+    ),
+    (
+        "Zeitraum",
+        "TimePeriod",
+        &[("start", "startdatum", None), ("end", "enddatum", None)],
+    ),
+    (
+        "Vertragskonditionen",
+        "ContractConditions",
+        &[
+            ("description", "beschreibung", None),
+            ("installment_count", "anzahlAbschlaege", None),
+            ("contract_duration", "vertragslaufzeit", None),
+            ("notice_period", "kuendigungsfrist", None),
+            ("extension_period", "vertragsverlaengerung", None),
+            ("installment_cycle", "abschlagszyklus", None),
+        ],
+    ),
+    (
+        "Vertragsteil",
+        "ContractPart",
+        &[
+            ("contract_part_start", "vertragsteilbeginn", None),
+            ("contract_part_end", "vertragsteilende", None),
+            ("location_id", "lokation", None),
+            ("fixed_quantity_value", "vertraglichFixierteMenge", None),
+            ("minimum_quantity_value", "minimaleAbnahmemenge", None),
+            ("maximum_quantity_value", "maximaleAbnahmemenge", None),
+        ],
+    ),
+    (
+        "Betrag",
+        "Amount",
+        &[("value", "wert", None), ("currency", "waehrung", None)],
+    ),
+    (
+        "Rechnungsposition",
+        "InvoicePosition",
+        &[
+            ("position_number", "positionsnummer", None),
+            ("position_text", "positionstext", None),
+            ("delivery_period_start", "lieferungszeitraumVon", None),
+            ("delivery_period_end", "lieferungszeitraumBis", None),
+            ("quantity_value", "positionsmenge", None),
+            ("unit_price_value", "einzelpreis", None),
+            ("total_price_value", "gesamtpreis", None),
+            ("article_number", "artikelnummer", None),
+            ("article_id", "artikelId", None),
+            ("tax_amount_value", "steuerbetrag", None),
+            ("time_unit", "zeiteinheit", None),
+            ("time_based_quantity_value", "zeitbezogeneMenge", None),
+        ],
+    ),
+];
+
+/// Get the fields of a BO4E type by its German or English name.
+pub(crate) fn type_fields(type_name: &str) -> Option<&'static [Field]> {
+    TYPE_FIELDS
+        .iter()
+        .find(|(german, english, _)| *german == type_name || *english == type_name)
+        .map(|(_, _, fields)| *fields)
+}
+
+/// Extract the type name from [`std::any::type_name`] output.
+///
+/// Containers are unwrapped, so `alloc::vec::Vec<bo4e_core::bo::meter::Meter>`
+/// and `&[bo4e_core::bo::meter::Meter]` both give `Meter`.
+pub(crate) fn short_type_name(full: &str) -> &str {
+    let inner = match (full.find('<'), full.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &full[start + 1..end],
+        _ => full,
+    };
+    let inner = inner.trim_start_matches(['&', '[']);
+    let inner = inner.split([';', ']']).next().unwrap_or(inner);
+    inner.rsplit("::").next().unwrap_or(inner).trim()
+}
+
 /// Get the German field name for an English field name.
 pub fn to_german(english: &str) -> Option<&'static str> {
     ENGLISH_TO_GERMAN.get(english).copied()
@@ -113,6 +394,42 @@ pub fn is_german_field(name: &str) -> bool {
     GERMAN_TO_ENGLISH.contains_key(name)
 }
 
+/// Check if a camelCase JSON key is a known English field (e.g. `meterNumber`).
+pub fn is_english_json_key(key: &str) -> bool {
+    is_english_field(&camel_to_snake(key))
+}
+
+/// Convert a camelCase JSON key to the snake_case form used in the mapping.
+pub(crate) fn camel_to_snake(key: &str) -> String {
+    let mut snake = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Convert a snake_case mapping name to its camelCase JSON key.
+pub(crate) fn snake_to_camel(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,5 +469,47 @@ mod tests {
 
         assert!(is_german_field("zaehlernummer"));
         assert!(!is_german_field("meter_number"));
+
+        assert!(is_english_json_key("meterNumber"));
+        assert!(is_english_json_key("marketLocationId"));
+        assert!(!is_english_json_key("zaehlernummer"));
+        assert!(!is_english_json_key("marktlokationsId"));
+    }
+
+    #[test]
+    fn test_type_fields() {
+        let register = type_fields("Zaehlwerk").unwrap();
+        assert!(register.contains(&("description", "bezeichnung", None)));
+        assert_eq!(type_fields("Zaehlwerk"), type_fields("MeterRegister"));
+        assert!(type_fields("Unbekannt").is_none());
+
+        // Every nested type has its own table
+        for (german, _, fields) in TYPE_FIELDS {
+            for (field, _, nested) in fields.iter() {
+                if let Some(nested) = nested {
+                    assert!(
+                        type_fields(nested).is_some(),
+                        "{}.{} refers to unknown type {}",
+                        german,
+                        field,
+                        nested
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("bo4e_core::bo::meter::Meter"), "Meter");
+        assert_eq!(
+            short_type_name("alloc::vec::Vec<bo4e_core::bo::meter::Meter>"),
+            "Meter"
+        );
+        assert_eq!(
+            short_type_name("&[bo4e_core::com::amount::Amount]"),
+            "Amount"
+        );
+        assert_eq!(short_type_name("serde_json::value::Value"), "Value");
     }
 }
@@ -0,0 +1,64 @@
+//! MessagePack encoding of BO4E objects.
+//!
+//! Structs are encoded as maps keyed by field name, using the same names as
+//! the German JSON output, so both formats share one canonical naming.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{with_config, Error, SerializeConfig};
+
+/// Serialize a BO4E object to MessagePack.
+pub fn to_msgpack<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    with_config(SerializeConfig::german(), || {
+        rmp_serde::to_vec_named(value).map_err(|e| Error::Serialize(e.to_string()))
+    })
+}
+
+/// Deserialize a BO4E object from MessagePack.
+pub fn from_msgpack<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    rmp_serde::from_slice(bytes).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+    use bo4e_core::com::MeterRegister;
+    use bo4e_core::enums::{Division, MeterType};
+    use bo4e_core::Bo4eMeta;
+
+    #[test]
+    fn test_meter_roundtrip() {
+        let meter = Meter {
+            meta: Bo4eMeta::with_type("Zaehler"),
+            meter_number: Some("1EMH0012345678".to_string()),
+            division: Some(Division::Electricity),
+            meter_type: Some(MeterType::ModernMeasuringDevice),
+            registers: vec![MeterRegister {
+                obis_code: Some("1-0:1.8.0".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let bytes = to_msgpack(&meter).unwrap();
+        let parsed: Meter = from_msgpack(&bytes).unwrap();
+        assert_eq!(meter, parsed);
+    }
+
+    #[test]
+    fn test_field_names_match_json() {
+        let meter = Meter {
+            meter_number: Some("123".to_string()),
+            ..Default::default()
+        };
+
+        let bytes = to_msgpack(&meter).unwrap();
+        let value: serde_json::Value = from_msgpack(&bytes).unwrap();
+        let json = crate::to_json_german(&meter).unwrap();
+        assert_eq!(
+            value,
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Number formatting for configured JSON output.
+//!
+//! serde_json writes every `f64` with a fractional part, so `1500.0` is
+//! emitted as `1500.0` while an integer field would give `1500`. With
+//! [`SerializeConfig::trim_float_zero`] whole-valued floats are written as
+//! integers. [`SerializeConfig::money_decimals`] writes monetary values,
+//! the `value` next to a `currency` as in `Amount` and `Price`, with a
+//! fixed number of decimal places.
+
+use std::cell::Cell;
+use std::io;
+use std::rc::Rc;
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
+use serde_json::{Map, Value};
+
+use crate::SerializeConfig;
+
+/// Returns `true` if `config` changes how numbers are written.
+pub(crate) fn needs_number_pass(config: &SerializeConfig) -> bool {
+    config.trim_float_zero || config.money_decimals.is_some()
+}
+
+/// Write `value` as JSON, formatting numbers according to `config`.
+pub(crate) fn to_string(value: &Value, config: &SerializeConfig) -> serde_json::Result<String> {
+    let decimals = Rc::new(Cell::new(None));
+    let tree = Numbers {
+        value,
+        config,
+        money: false,
+        decimals: Rc::clone(&decimals),
+    };
+    let mut out = Vec::new();
+    if config.pretty {
+        let formatter = DecimalFormatter {
+            inner: PrettyFormatter::new(),
+            decimals,
+        };
+        tree.serialize(&mut serde_json::Serializer::with_formatter(
+            &mut out, formatter,
+        ))?;
+    } else {
+        let formatter = DecimalFormatter {
+            inner: CompactFormatter,
+            decimals,
+        };
+        tree.serialize(&mut serde_json::Serializer::with_formatter(
+            &mut out, formatter,
+        ))?;
+    }
+    // serde_json only writes valid UTF-8
+    Ok(String::from_utf8(out).unwrap_or_default())
+}
+
+/// Keys holding the numeric value of a monetary object.
+const MONEY_VALUE_KEYS: &[&str] = &["value", "wert"];
+/// Keys marking an object as monetary.
+const CURRENCY_KEYS: &[&str] = &["currency", "waehrung"];
+
+fn is_monetary(map: &Map<String, Value>) -> bool {
+    CURRENCY_KEYS.iter().any(|key| map.contains_key(*key))
+}
+
+/// A `Value` that serializes its numbers according to the config.
+struct Numbers<'a> {
+    value: &'a Value,
+    config: &'a SerializeConfig,
+    /// Whether `value` is the amount of a monetary object
+    money: bool,
+    decimals: Rc<Cell<Option<usize>>>,
+}
+
+impl Numbers<'_> {
+    fn child<'b>(&'b self, value: &'b Value, money: bool) -> Numbers<'b> {
+        Numbers {
+            value,
+            config: self.config,
+            money,
+            decimals: Rc::clone(&self.decimals),
+        }
+    }
+}
+
+impl Serialize for Numbers<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            Value::Number(n) => match (n.as_f64(), n.is_f64()) {
+                (Some(f), true) => {
+                    if let (true, Some(places)) = (self.money, self.config.money_decimals) {
+                        self.decimals.set(Some(places as usize));
+                        serializer.serialize_f64(f)
+                    } else if self.config.trim_float_zero && f.fract() == 0.0 && f.abs() < 1e15 {
+                        serializer.serialize_i64(f as i64)
+                    } else {
+                        serializer.serialize_f64(f)
+                    }
+                }
+                _ => n.serialize(serializer),
+            },
+            Value::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&self.child(item, false))?;
+                }
+                seq.end()
+            }
+            Value::Object(map) => {
+                let monetary = is_monetary(map);
+                let mut out = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map {
+                    let money = monetary && MONEY_VALUE_KEYS.contains(&key.as_str());
+                    out.serialize_entry(key, &self.child(value, money))?;
+                }
+                out.end()
+            }
+            other => other.serialize(serializer),
+        }
+    }
+}
+
+/// Formatter writing the next float with the decimal places set in `decimals`.
+struct DecimalFormatter<F> {
+    inner: F,
+    decimals: Rc<Cell<Option<usize>>>,
+}
+
+impl<F: Formatter> Formatter for DecimalFormatter<F> {
+    fn write_f64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        match self.decimals.take() {
+            Some(places) if value.is_finite() => write!(writer, "{:.*}", places, value),
+            _ => self.inner.write_f64(writer, value),
+        }
+    }
+
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.inner.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.inner.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_object_value(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_trim_and_money_decimals() {
+        // Keys in sorted order, so the output does not depend on whether
+        // serde_json preserves insertion order
+        let value = json!({
+            "count": 3,
+            "quantityValue": 1500.0,
+            "share": 12.5,
+            "total": {"waehrung": "EUR", "wert": 450.0},
+            "unitPrice": {"currency": "EUR", "value": 0.3}
+        });
+
+        let trimmed = SerializeConfig::default().with_trim_float_trailing_zero(true);
+        assert_eq!(
+            to_string(&value, &trimmed).unwrap(),
+            r#"{"count":3,"quantityValue":1500,"share":12.5,"total":{"waehrung":"EUR","wert":450},"unitPrice":{"currency":"EUR","value":0.3}}"#
+        );
+
+        let money = trimmed.with_money_decimals(2);
+        assert_eq!(
+            to_string(&value, &money).unwrap(),
+            r#"{"count":3,"quantityValue":1500,"share":12.5,"total":{"waehrung":"EUR","wert":450.00},"unitPrice":{"currency":"EUR","value":0.30}}"#
+        );
+    }
+
+    #[test]
+    fn test_pretty_output() {
+        let config = SerializeConfig::default().pretty().with_money_decimals(2);
+        let out = to_string(&json!({"currency": "EUR", "value": 119.0}), &config).unwrap();
+        assert_eq!(out, "{\n  \"currency\": \"EUR\",\n  \"value\": 119.00\n}");
+    }
+}
@@ -0,0 +1,306 @@
+//! Path-based access to serialized BO4E objects.
+//!
+//! Pointers follow RFC 6901 (`/address/postalCode`, `/registers/0`). Object
+//! keys may be given in either language: if a key is not present verbatim,
+//! its counterpart from [`mapping`](crate::mapping) is tried, so
+//! `/address/postleitzahl` finds `postalCode` and vice versa.
+//!
+//! [`merge_patch`] and [`apply_patch`] implement JSON Merge Patch
+//! (RFC 7386) for partial updates, with the same key matching.
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{mapping, Error};
+
+/// Error returned by [`set_pointer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointerError {
+    /// The pointer is neither empty nor starts with `/`
+    Syntax(String),
+    /// A parent of the target does not exist or is not an object or array
+    NotFound(String),
+    /// An array index is malformed or out of bounds
+    InvalidIndex(String),
+}
+
+impl fmt::Display for PointerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(ptr) => write!(f, "invalid JSON pointer: {:?}", ptr),
+            Self::NotFound(ptr) => write!(f, "no such path: {:?}", ptr),
+            Self::InvalidIndex(ptr) => write!(f, "invalid array index in {:?}", ptr),
+        }
+    }
+}
+
+impl std::error::Error for PointerError {}
+
+/// Get the value at `ptr`, or `None` if the path does not exist.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_serde::patch::get_pointer;
+/// use serde_json::json;
+///
+/// let malo = json!({"address": {"postalCode": "50667"}});
+/// assert_eq!(get_pointer(&malo, "/address/postleitzahl"), Some(&json!("50667")));
+/// ```
+pub fn get_pointer<'a>(value: &'a Value, ptr: &str) -> Option<&'a Value> {
+    let mut current = value;
+    for segment in segments(ptr).ok()? {
+        current = match current {
+            Value::Object(map) => map.get(&resolve_key(map, &segment)?)?,
+            Value::Array(items) => items.get(parse_index(&segment)?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+/// Set the value at `ptr`, replacing any existing value.
+///
+/// All parents must exist. A missing object key is inserted as given; for
+/// arrays the index must be in bounds, or equal to the length (or `-`) to
+/// append. The empty pointer replaces the whole document.
+pub fn set_pointer(value: &mut Value, ptr: &str, new: Value) -> Result<(), PointerError> {
+    let mut segments = segments(ptr)?;
+    let Some(last) = segments.pop() else {
+        *value = new;
+        return Ok(());
+    };
+
+    let mut current = value;
+    for segment in &segments {
+        current = match current {
+            Value::Object(map) => resolve_key(map, segment).and_then(|key| map.get_mut(&key)),
+            Value::Array(items) => parse_index(segment).and_then(|i| items.get_mut(i)),
+            _ => None,
+        }
+        .ok_or_else(|| PointerError::NotFound(ptr.to_string()))?;
+    }
+
+    match current {
+        Value::Object(map) => {
+            let key = resolve_key(map, &last).unwrap_or(last);
+            map.insert(key, new);
+            Ok(())
+        }
+        Value::Array(items) => {
+            let index = if last == "-" {
+                items.len()
+            } else {
+                parse_index(&last).ok_or_else(|| PointerError::InvalidIndex(ptr.to_string()))?
+            };
+            match index.cmp(&items.len()) {
+                std::cmp::Ordering::Less => items[index] = new,
+                std::cmp::Ordering::Equal => items.push(new),
+                std::cmp::Ordering::Greater => {
+                    return Err(PointerError::InvalidIndex(ptr.to_string()))
+                }
+            }
+            Ok(())
+        }
+        _ => Err(PointerError::NotFound(ptr.to_string())),
+    }
+}
+
+/// Apply a JSON Merge Patch (RFC 7386) to `target` in place.
+///
+/// Objects in the patch are merged recursively, a `null` member deletes the
+/// key, and any other value (including arrays) replaces the target value.
+/// A patch that is not an object replaces `target` entirely.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_serde::patch::merge_patch;
+/// use serde_json::json;
+///
+/// let mut meter = json!({"meterNumber": "1EMH0001", "division": "STROM"});
+/// merge_patch(&mut meter, &json!({"zaehlernummer": "1EMH0002", "division": null}));
+/// assert_eq!(meter, json!({"meterNumber": "1EMH0002"}));
+/// ```
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(map) = target else {
+        unreachable!("target was just made an object");
+    };
+    for (name, value) in members {
+        let key = resolve_key(map, name).unwrap_or_else(|| name.clone());
+        if value.is_null() {
+            map.remove(&key);
+        } else {
+            merge_patch(map.entry(key).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// Apply a JSON Merge Patch to a typed BO4E object.
+///
+/// `base` is serialized to a [`Value`], patched with [`merge_patch`] and
+/// deserialized back into a new `T`.
+pub fn apply_patch<T: Serialize + DeserializeOwned>(base: &T, patch: &Value) -> Result<T, Error> {
+    let mut value = serde_json::to_value(base)?;
+    merge_patch(&mut value, patch);
+    serde_json::from_value(value).map_err(|e| Error::Deserialize(e.to_string()))
+}
+
+/// Split a pointer into unescaped reference tokens.
+fn segments(ptr: &str) -> Result<Vec<String>, PointerError> {
+    if ptr.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rest = ptr
+        .strip_prefix('/')
+        .ok_or_else(|| PointerError::Syntax(ptr.to_string()))?;
+    Ok(rest
+        .split('/')
+        .map(|s| s.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn parse_index(segment: &str) -> Option<usize> {
+    let leading_zero = segment.len() > 1 && segment.starts_with('0');
+    if leading_zero || !segment.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    segment.parse().ok()
+}
+
+/// Find the key in `map` matching `segment` directly or via its translation.
+fn resolve_key(map: &Map<String, Value>, segment: &str) -> Option<String> {
+    if map.contains_key(segment) {
+        return Some(segment.to_string());
+    }
+    translations(segment).find(|key| map.contains_key(key))
+}
+
+/// Candidate names for a key in the other language.
+fn translations(key: &str) -> impl Iterator<Item = String> {
+    let english = mapping::to_english(key).map(mapping::snake_to_camel);
+    let german = mapping::to_german(&mapping::camel_to_snake(key)).map(str::to_string);
+    english.into_iter().chain(german)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::{MarketLocation, Meter};
+    use bo4e_core::com::Address;
+    use bo4e_core::enums::Division;
+    use serde_json::json;
+
+    fn sample_location() -> Value {
+        let malo = MarketLocation {
+            market_location_id: Some("12345678901".to_string()),
+            address: Some(Address {
+                postal_code: Some("50667".to_string()),
+                city: Some("Köln".to_string()),
+                ..Default::default()
+            }),
+            metering_location_ids: vec!["DE0001".to_string()],
+            ..Default::default()
+        };
+        serde_json::to_value(&malo).unwrap()
+    }
+
+    #[test]
+    fn test_get_pointer_nested_address() {
+        let value = sample_location();
+        assert_eq!(
+            get_pointer(&value, "/address/postalCode"),
+            Some(&json!("50667"))
+        );
+        assert_eq!(
+            get_pointer(&value, "/address/postleitzahl"),
+            Some(&json!("50667"))
+        );
+        assert_eq!(
+            get_pointer(&value, "/marktlokationsId"),
+            Some(&json!("12345678901"))
+        );
+        assert_eq!(
+            get_pointer(&value, "/meteringLocationIds/0"),
+            Some(&json!("DE0001"))
+        );
+        assert_eq!(get_pointer(&value, "/address/street"), None);
+        assert_eq!(get_pointer(&value, "address"), None);
+        assert_eq!(get_pointer(&value, ""), Some(&value));
+    }
+
+    #[test]
+    fn test_set_pointer_nested_address() {
+        let mut value = sample_location();
+        set_pointer(&mut value, "/address/postleitzahl", json!("10115")).unwrap();
+        set_pointer(&mut value, "/address/ort", json!("Berlin")).unwrap();
+        set_pointer(&mut value, "/meteringLocationIds/-", json!("DE0002")).unwrap();
+
+        let malo: MarketLocation = serde_json::from_value(value.clone()).unwrap();
+        let address = malo.address.unwrap();
+        assert_eq!(address.postal_code, Some("10115".to_string()));
+        assert_eq!(address.city, Some("Berlin".to_string()));
+        assert_eq!(malo.metering_location_ids, vec!["DE0001", "DE0002"]);
+
+        assert_eq!(
+            set_pointer(&mut value, "/lokationsadresse/plz", json!("1")),
+            Err(PointerError::NotFound("/lokationsadresse/plz".to_string()))
+        );
+        assert_eq!(
+            set_pointer(&mut value, "/meteringLocationIds/5", json!("x")),
+            Err(PointerError::InvalidIndex(
+                "/meteringLocationIds/5".to_string()
+            ))
+        );
+        assert!(matches!(
+            set_pointer(&mut value, "address", json!(1)),
+            Err(PointerError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_patch_meter() {
+        let meter = Meter {
+            meter_number: Some("1EMH0001".to_string()),
+            division: Some(Division::Electricity),
+            ..Default::default()
+        };
+
+        let patched = apply_patch(
+            &meter,
+            &json!({"meterNumber": "1EMH0002", "division": null}),
+        )
+        .unwrap();
+        assert_eq!(patched.meter_number, Some("1EMH0002".to_string()));
+        assert_eq!(patched.division, None);
+
+        let patched = apply_patch(&meter, &json!({"zaehlernummer": "1EMH0003"})).unwrap();
+        assert_eq!(patched.meter_number, Some("1EMH0003".to_string()));
+        assert_eq!(patched.division, Some(Division::Electricity));
+    }
+
+    #[test]
+    fn test_merge_patch_rfc_semantics() {
+        let mut value = json!({"a": {"b": 1, "c": 2}, "list": [1, 2], "keep": true});
+        merge_patch(
+            &mut value,
+            &json!({"a": {"c": null, "d": {"e": 3}}, "list": [3], "new": "x"}),
+        );
+        assert_eq!(
+            value,
+            json!({"a": {"b": 1, "d": {"e": 3}}, "list": [3], "keep": true, "new": "x"})
+        );
+
+        merge_patch(&mut value, &json!(["replaced"]));
+        assert_eq!(value, json!(["replaced"]));
+    }
+}
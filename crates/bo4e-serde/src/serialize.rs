@@ -1,6 +1,12 @@
 //! JSON serialization functions.
 
 use serde::Serialize;
+use serde_json::Value;
+
+use crate::{mapping, JsonLanguage, MetaKeyStyle, SerializeConfig};
+
+/// JSON keys produced by the flattened `Bo4eMeta` struct.
+const META_KEYS: [&str; 4] = ["_typ", "_version", "_id", "zusatzAttribute"];
 
 /// Serialize to a compact JSON string.
 pub fn to_string<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
@@ -17,6 +23,178 @@ pub fn to_vec<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
     serde_json::to_vec(value)
 }
 
+/// Returns `true` if the config requires a post-processing pass over the
+/// serialized `Value` tree.
+pub(crate) fn needs_value_pass(config: &SerializeConfig) -> bool {
+    config.skip_meta
+        || config.meta_key_style != MetaKeyStyle::Underscore
+        || config.translate_keys
+        || config.utc_offset
+        || crate::number::needs_number_pass(config)
+}
+
+/// Apply config-driven transformations to a serialized value tree.
+///
+/// `root_type` is the [`std::any::type_name`] of the serialized value; it
+/// identifies the BO4E type of the root for key translation.
+pub(crate) fn apply_config(value: &mut Value, config: &SerializeConfig, root_type: &str) {
+    if config.skip_meta {
+        strip_meta(value);
+    } else if config.meta_key_style != MetaKeyStyle::Underscore {
+        rename_meta(value, config.meta_key_style);
+    }
+    if config.translate_keys {
+        let fields = mapping::type_fields(mapping::short_type_name(root_type));
+        translate_keys(value, config.language, fields);
+    }
+    if config.utc_offset {
+        rewrite_utc_offset(value);
+    }
+}
+
+/// Recursively remove the flattened metadata keys from all objects.
+fn strip_meta(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for key in META_KEYS {
+                map.remove(key);
+            }
+            map.values_mut().for_each(strip_meta);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_meta),
+        _ => {}
+    }
+}
+
+/// Recursively rename the metadata keys of all objects to `style`.
+fn rename_meta(value: &mut Value, style: MetaKeyStyle) {
+    match value {
+        Value::Object(map) => {
+            let from = MetaKeyStyle::Underscore.keys();
+            for (old, new) in from.iter().zip(style.keys()) {
+                if let Some(v) = map.remove(*old) {
+                    map.insert(new.to_string(), v);
+                }
+            }
+            map.values_mut().for_each(|v| rename_meta(v, style));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|v| rename_meta(v, style)),
+        _ => {}
+    }
+}
+
+/// Recursively remove `null` members and empty objects and arrays.
+///
+/// Children are pruned first, so containers that only held empty values
+/// disappear as well. This applies to array elements too: `null`s and
+/// empty objects and arrays are removed from arrays. The root itself is
+/// never removed.
+pub(crate) fn prune_empty(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.values_mut().for_each(prune_empty);
+            map.retain(|_, v| !is_empty_value(v));
+        }
+        Value::Array(items) => {
+            items.iter_mut().for_each(prune_empty);
+            items.retain(|v| !is_empty_value(v));
+        }
+        _ => {}
+    }
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Object(map) => map.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Recursively rename object keys to their names in `language`.
+///
+/// Keys are renamed per BO4E type, using [`mapping::type_fields`]. The type
+/// of an object is taken from its `_typ`, from the field holding it or, for
+/// the root, from `fields`. Objects of unknown types keep their keys, so a
+/// document never mixes languages within one object.
+fn translate_keys(
+    value: &mut Value,
+    language: JsonLanguage,
+    fields: Option<&'static [mapping::Field]>,
+) {
+    match value {
+        Value::Object(map) => {
+            let fields = map
+                .get("_typ")
+                .and_then(Value::as_str)
+                .and_then(mapping::type_fields)
+                .or(fields);
+            let entries = std::mem::take(map);
+            for (key, mut child) in entries {
+                let field = fields.and_then(|fields| {
+                    fields.iter().find(|(english, german, _)| {
+                        key == *german || key == mapping::snake_to_camel(english)
+                    })
+                });
+                let nested = field
+                    .and_then(|(_, _, nested)| *nested)
+                    .and_then(mapping::type_fields);
+                translate_keys(&mut child, language, nested);
+                let key = match (field, language) {
+                    (Some((_, german, _)), JsonLanguage::German) => german.to_string(),
+                    (Some((english, _, _)), JsonLanguage::English) => {
+                        mapping::snake_to_camel(english)
+                    }
+                    (None, _) => key,
+                };
+                map.insert(key, child);
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| translate_keys(item, language, fields)),
+        _ => {}
+    }
+}
+
+/// Recursively replace the `Z` suffix of UTC timestamps with `+00:00`.
+fn rewrite_utc_offset(value: &mut Value) {
+    match value {
+        Value::String(s) if is_utc_timestamp(s) => {
+            s.pop();
+            s.push_str("+00:00");
+        }
+        Value::Object(map) => map.values_mut().for_each(rewrite_utc_offset),
+        Value::Array(items) => items.iter_mut().for_each(rewrite_utc_offset),
+        _ => {}
+    }
+}
+
+/// Matches `YYYY-MM-DDTHH:MM:SS[.fraction]Z`.
+fn is_utc_timestamp(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let Some((b'Z', rest)) = bytes.split_last() else {
+        return false;
+    };
+    if rest.len() < 19 {
+        return false;
+    }
+    let (head, fraction) = rest.split_at(19);
+    let head_ok = head.iter().enumerate().all(|(i, b)| match i {
+        4 | 7 => *b == b'-',
+        10 => *b == b'T',
+        13 | 16 => *b == b':',
+        _ => b.is_ascii_digit(),
+    });
+    let fraction_ok = match fraction.split_first() {
+        None => true,
+        Some((b'.', digits)) => !digits.is_empty() && digits.iter().all(u8::is_ascii_digit),
+        Some(_) => false,
+    };
+    head_ok && fraction_ok
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +222,107 @@ mod tests {
         assert!(json.contains('\n'));
         assert!(json.contains(r#""meterNumber""#));
     }
+
+    #[test]
+    fn test_strip_meta_nested() {
+        let mut value = serde_json::json!({
+            "_typ": "Zaehler",
+            "_version": "202401.0.1",
+            "zaehlwerke": [{"_typ": "Zaehlwerk", "obisKennzahl": "1-0:1.8.0"}]
+        });
+        apply_config(
+            &mut value,
+            &SerializeConfig::german().with_skip_meta(true),
+            "",
+        );
+        assert_eq!(
+            value,
+            serde_json::json!({"zaehlwerke": [{"obisKennzahl": "1-0:1.8.0"}]})
+        );
+    }
+
+    #[test]
+    fn test_translate_keys_and_utc_offset() {
+        let mut value = serde_json::json!({
+            "_typ": "Zaehler",
+            "meterNumber": "1",
+            "installationDate": "2024-01-15T08:00:00Z",
+            "registers": [{"obisCode": "1-0:1.8.0", "unit": "KWH"}],
+            "note": "not a dateZ"
+        });
+        apply_config(&mut value, &SerializeConfig::python_compatible(), "");
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "_typ": "Zaehler",
+                "zaehlernummer": "1",
+                "einbaudatum": "2024-01-15T08:00:00+00:00",
+                "zaehlwerke": [{"obisKennzahl": "1-0:1.8.0", "einheit": "KWH"}],
+                "note": "not a dateZ"
+            })
+        );
+        assert!(is_utc_timestamp("2024-01-15T08:00:00.123Z"));
+        assert!(!is_utc_timestamp("2024-01-15T08:00:00+01:00"));
+        assert!(!is_utc_timestamp("2024-01-15T08:00:00.Z"));
+    }
+
+    #[test]
+    fn test_translate_keys_per_type() {
+        use bo4e_core::com::{Address, MeterRegister};
+
+        let meter = Meter {
+            meter_number: Some("1EMH0012345678".to_string()),
+            location: Some(Address {
+                city: Some("Köln".to_string()),
+                ..Default::default()
+            }),
+            registers: vec![MeterRegister {
+                obis_code: Some("1-0:1.8.0".to_string()),
+                description: Some("HT".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut value = serde_json::to_value(&meter).unwrap();
+        let config = SerializeConfig::german().with_translate_keys(true);
+        apply_config(&mut value, &config, std::any::type_name::<Meter>());
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "zaehlernummer": "1EMH0012345678",
+                "standort": {"ort": "Köln"},
+                "zaehlwerke": [{"obisKennzahl": "1-0:1.8.0", "bezeichnung": "HT"}]
+            })
+        );
+
+        let config = SerializeConfig::english().with_translate_keys(true);
+        apply_config(&mut value, &config, std::any::type_name::<Meter>());
+        assert_eq!(value, serde_json::to_value(&meter).unwrap());
+
+        // Keys of unknown types are left alone
+        let mut value = serde_json::json!({"description": "x", "value": 1});
+        apply_config(&mut value, &SerializeConfig::python_compatible(), "");
+        assert_eq!(value, serde_json::json!({"description": "x", "value": 1}));
+    }
+
+    #[test]
+    fn test_prune_empty_nested() {
+        let mut value = serde_json::json!({
+            "a": {"b": {}, "c": [], "d": null},
+            "list": [{}, {"x": []}, 0, [1]],
+            "keep": false
+        });
+        prune_empty(&mut value);
+        assert_eq!(value, serde_json::json!({"list": [0, [1]], "keep": false}));
+    }
+
+    #[test]
+    fn test_prune_empty_nested_arrays() {
+        let mut value = serde_json::json!({
+            "matrix": [[null, []], [[], [2, null]], null, [{}]],
+            "only_empty": [[null], [[]]]
+        });
+        prune_empty(&mut value);
+        assert_eq!(value, serde_json::json!({"matrix": [[[2]]]}));
+    }
 }
@@ -1,12 +1,28 @@
 //! SIMD-accelerated JSON parsing.
 
-use serde::de::DeserializeOwned;
+use serde::de::{DeserializeOwned, Error as _};
+use simd_json::ErrorType;
+
+use crate::config::current_config;
 
 /// Deserialize from a mutable byte slice using simd-json.
 ///
 /// simd-json performs in-place parsing, which requires a mutable slice.
 /// The input will be modified during parsing.
 ///
+/// simd-json rejects some valid JSON, such as integers beyond 64 bits. If
+/// [`SerializeConfig::simd_fallback`](crate::SerializeConfig::simd_fallback)
+/// is set, such input is parsed again with `serde_json` and its result
+/// returned. The input is then copied before every parse, since simd-json
+/// may leave it partially rewritten, so the fallback is off by default.
+///
+/// Input nested deeper than
+/// [`SerializeConfig::max_depth`](crate::SerializeConfig::max_depth) is
+/// rejected without being parsed.
+///
+/// A leading UTF-8 byte order mark, as written by some Windows tools, and
+/// leading ASCII whitespace are skipped before parsing.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -17,7 +33,96 @@ use serde::de::DeserializeOwned;
 /// let meter: Meter = from_slice(&mut json)?;
 /// ```
 pub fn from_slice<T: DeserializeOwned>(json: &mut [u8]) -> Result<T, simd_json::Error> {
-    simd_json::from_slice(json)
+    let json = prepare(json)?;
+    if !current_config().simd_fallback {
+        return simd_json::from_slice(json);
+    }
+    let original = json.to_vec();
+    match simd_json::from_slice(json) {
+        Err(e) if recoverable(&e) => {
+            serde_json::from_slice(&original).map_err(|e| simd_json::Error::custom(e.to_string()))
+        }
+        result => result,
+    }
+}
+
+/// Skip a leading byte order mark and whitespace, and enforce
+/// [`SerializeConfig::max_depth`](crate::SerializeConfig::max_depth).
+///
+/// Every entry point that hands input to simd-json goes through this.
+pub(crate) fn prepare(json: &mut [u8]) -> Result<&mut [u8], simd_json::Error> {
+    let json = trim_start(json);
+    if let Some(max) = current_config().max_depth {
+        if let Some(offset) = depth_exceeded_at(json, max) {
+            return Err(simd_json::Error::custom(format!(
+                "nesting depth exceeds the maximum of {max} at byte {offset}"
+            )));
+        }
+    }
+    Ok(json)
+}
+
+/// UTF-8 byte order mark (`EF BB BF`).
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Skip a leading byte order mark and ASCII whitespace.
+pub(crate) fn trim_start(json: &mut [u8]) -> &mut [u8] {
+    let bom = if json.starts_with(UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    };
+    let whitespace = json[bom..]
+        .iter()
+        .take_while(|b| b.is_ascii_whitespace())
+        .count();
+    &mut json[bom + whitespace..]
+}
+
+/// Offset of the first bracket that opens level `max + 1`, if any.
+///
+/// Brackets inside strings are ignored. The input is not validated.
+fn depth_exceeded_at(json: &[u8], max: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &byte) in json.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > max {
+                    return Some(i);
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns `true` for errors on input that `serde_json` may accept.
+fn recoverable(error: &simd_json::Error) -> bool {
+    matches!(
+        error.error(),
+        ErrorType::InvalidNumber
+            | ErrorType::InvalidExponent
+            | ErrorType::InvalidEscape
+            | ErrorType::InvalidUnicodeEscape
+            | ErrorType::InvalidUnicodeCodepoint
+            | ErrorType::InputTooLarge
+    )
 }
 
 /// Deserialize from a string, converting to mutable bytes internally.
@@ -61,4 +166,59 @@ mod tests {
         let meter: Meter = from_vec(json).unwrap();
         assert_eq!(meter.meter_number, Some("TEST789".to_string()));
     }
+
+    #[test]
+    fn test_fallback_for_wide_integer() {
+        use crate::config::{with_config, SerializeConfig};
+        use bo4e_core::bo::MarketLocation;
+
+        let json = br#"{"marktlokationsId":"51238696781","annualConsumption":123456789012345678901234567890}"#;
+
+        let result: Result<MarketLocation, _> = from_slice(&mut json.to_vec());
+        assert_eq!(result.unwrap_err().error(), &ErrorType::InvalidNumber);
+
+        let lenient = SerializeConfig::default().with_simd_fallback(true);
+        let malo: MarketLocation = with_config(lenient, || from_slice(&mut json.to_vec())).unwrap();
+        assert_eq!(malo.market_location_id.as_deref(), Some("51238696781"));
+        assert_eq!(malo.annual_consumption, Some(1.2345678901234568e29));
+    }
+
+    #[test]
+    fn test_leading_bom_and_whitespace() {
+        let mut json = b"\xEF\xBB\xBF\r\n  {\"zaehlernummer\":\"BOM123\"}".to_vec();
+        let meter: Meter = from_slice(&mut json).unwrap();
+        assert_eq!(meter.meter_number.as_deref(), Some("BOM123"));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        use crate::config::{with_config, SerializeConfig};
+
+        let nesting = 100_000;
+        let json = format!(
+            r#"{{"zaehlernummer":"1","nested":{}{}}}"#,
+            "[".repeat(nesting),
+            "]".repeat(nesting)
+        );
+        let limited = SerializeConfig::default().with_max_depth(64);
+
+        let err = with_config(limited.clone(), || {
+            crate::from_json::<Meter>(&mut json.clone().into_bytes())
+        })
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("nesting depth exceeds the maximum of 64"));
+
+        let shallow = br#"{"zaehlernummer":"1","nested":[["[[[["]]}"#;
+        let meter: Meter = with_config(limited, || from_slice(&mut shallow.to_vec())).unwrap();
+        assert_eq!(meter.meter_number.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_no_fallback_for_syntax_errors() {
+        let mut json = br#"{"meterNumber":"TEST123""#.to_vec();
+        let err = from_slice::<Meter>(&mut json).unwrap_err();
+        assert!(!recoverable(&err));
+    }
 }
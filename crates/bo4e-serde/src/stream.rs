@@ -0,0 +1,297 @@
+//! Incremental parsing of large JSON arrays.
+
+use std::io::{BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use crate::{from_slice, Error};
+
+/// Iterator over the elements of a top-level JSON array read from `R`.
+///
+/// Only one element is held in memory at a time, so arbitrarily large
+/// files can be processed. Elements are located by tracking bracket depth
+/// (ignoring brackets inside strings) and each one is parsed with simd-json.
+///
+/// Iteration stops after the first error.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_core::bo::Meter;
+/// use bo4e_serde::JsonArrayStream;
+///
+/// let json = br#"[{"zaehlernummer":"1"},{"zaehlernummer":"2"}]"#;
+/// let meters: Vec<Meter> = JsonArrayStream::new(&json[..])
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(meters.len(), 2);
+/// ```
+pub struct JsonArrayStream<T, R> {
+    reader: BufReader<R>,
+    state: State,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Before the opening bracket
+    Start,
+    /// Inside the array; `true` until the first element was read
+    Elements { first: bool },
+    /// After the closing bracket or an error
+    Done,
+}
+
+impl<T: DeserializeOwned, R: Read> JsonArrayStream<T, R> {
+    /// Create a stream over the array read from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Skip whitespace and return the next byte without consuming it.
+    fn peek_non_whitespace(&mut self) -> Result<Option<u8>, Error> {
+        loop {
+            let chunk = self.reader.fill_buf().map_err(io_error)?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            match chunk.iter().position(|b| !b.is_ascii_whitespace()) {
+                Some(i) => {
+                    let byte = chunk[i];
+                    self.reader.consume(i);
+                    return Ok(Some(byte));
+                }
+                None => {
+                    let len = chunk.len();
+                    self.reader.consume(len);
+                }
+            }
+        }
+    }
+
+    /// Read the raw bytes of the next element and consume its terminator.
+    ///
+    /// Returns the element and whether the terminator closed the array.
+    fn read_element(&mut self) -> Result<(Vec<u8>, bool), Error> {
+        let mut element = Vec::new();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        loop {
+            let chunk = self.reader.fill_buf().map_err(io_error)?;
+            if chunk.is_empty() {
+                return Err(Error::Deserialize(
+                    "unexpected end of input inside JSON array".to_string(),
+                ));
+            }
+
+            let mut end = None;
+            for (i, &byte) in chunk.iter().enumerate() {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if byte == b'\\' {
+                        escaped = true;
+                    } else if byte == b'"' {
+                        in_string = false;
+                    }
+                    continue;
+                }
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth > 0 => depth -= 1,
+                    b',' | b']' if depth == 0 => {
+                        end = Some((i, byte == b']'));
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            match end {
+                Some((i, closed)) => {
+                    element.extend_from_slice(&chunk[..i]);
+                    self.reader.consume(i + 1);
+                    return Ok((element, closed));
+                }
+                None => {
+                    let len = chunk.len();
+                    element.extend_from_slice(chunk);
+                    self.reader.consume(len);
+                }
+            }
+        }
+    }
+
+    fn next_element(&mut self) -> Result<Option<T>, Error> {
+        if self.state == State::Start {
+            match self.peek_non_whitespace()? {
+                Some(b'[') => {
+                    self.reader.consume(1);
+                    self.state = State::Elements { first: true };
+                }
+                _ => return Err(Error::Deserialize("expected a JSON array".to_string())),
+            }
+        }
+        let State::Elements { first } = self.state else {
+            return Ok(None);
+        };
+
+        let (mut element, closed) = self.read_element()?;
+        self.state = if closed {
+            State::Done
+        } else {
+            State::Elements { first: false }
+        };
+
+        if element.iter().all(u8::is_ascii_whitespace) {
+            return if closed && first {
+                Ok(None)
+            } else {
+                Err(Error::Deserialize("empty JSON array element".to_string()))
+            };
+        }
+        from_slice(&mut element).map(Some).map_err(Error::from)
+    }
+}
+
+impl<T: DeserializeOwned, R: Read> Iterator for JsonArrayStream<T, R> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+        let result = self.next_element();
+        if result.is_err() {
+            self.state = State::Done;
+        }
+        result.transpose()
+    }
+}
+
+/// Find the end offset of the first complete JSON value in `bytes`.
+///
+/// Leading whitespace is counted as part of the value. Returns `None` if
+/// `bytes` holds only whitespace or the first value is not complete.
+pub(crate) fn first_value_end(bytes: &[u8]) -> Option<usize> {
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace())?;
+    if !matches!(bytes[start], b'{' | b'[' | b'"') {
+        // A number or literal ends at the next whitespace or delimiter
+        let len = bytes[start..]
+            .iter()
+            .position(|b| b.is_ascii_whitespace() || b"{}[],:\"".contains(b))
+            .unwrap_or(bytes.len() - start);
+        return Some(start + len);
+    }
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &byte) in bytes.iter().enumerate().skip(start) {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::Deserialize(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+
+    /// Reader that hands out a few bytes per call to split tokens across reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_stream_nested_elements() {
+        let json = br#" [
+            {"zaehlernummer": "A", "zaehlwerke": [{"obisCode": "1-0:1.8.0"}, {"obisCode": "1-0:2.8.0"}]},
+            {"zaehlernummer": "B]\"[,", "zaehlwerke": []},
+            {"zaehlernummer": "C", "standort": {"ort": "K{ln"}, "zaehlwerke": [{"bezeichnung": "HT"}]}
+        ] "#;
+
+        let meters: Vec<Meter> = JsonArrayStream::new(Trickle(json))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(meters.len(), 3);
+        assert_eq!(meters[0].registers.len(), 2);
+        assert_eq!(meters[1].meter_number, Some("B]\"[,".to_string()));
+        assert!(meters[1].registers.is_empty());
+        assert_eq!(meters[2].registers.len(), 1);
+    }
+
+    #[test]
+    fn test_stream_empty_array() {
+        let mut stream = JsonArrayStream::<Meter, _>::new(&b" [ ] "[..]);
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let mut truncated = JsonArrayStream::<Meter, _>::new(&br#"[{"zaehlernummer":"1"}"#[..]);
+        assert!(truncated.next().unwrap().is_err());
+        assert!(truncated.next().is_none());
+
+        let mut not_array = JsonArrayStream::<Meter, _>::new(&b"{}"[..]);
+        assert!(not_array.next().unwrap().is_err());
+
+        let mut trailing = JsonArrayStream::<Meter, _>::new(&b"[{},]"[..]);
+        assert!(trailing.next().unwrap().is_ok());
+        assert!(trailing.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_first_value_end() {
+        assert_eq!(first_value_end(br#" {"a":"}"}{"b":1}"#), Some(10));
+        assert_eq!(first_value_end(br#"[1,[2]] 3"#), Some(7));
+        assert_eq!(first_value_end(br#""x\"y" 1"#), Some(6));
+        assert_eq!(first_value_end(b"42 17"), Some(2));
+        assert_eq!(first_value_end(b"true"), Some(4));
+        assert_eq!(first_value_end(br#"{"a":1"#), None);
+        assert_eq!(first_value_end(b"  "), None);
+    }
+}
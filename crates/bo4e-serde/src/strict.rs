@@ -0,0 +1,289 @@
+//! Strict deserialization that rejects unknown fields.
+//!
+//! `#[serde(deny_unknown_fields)]` cannot be combined with the flattened
+//! `Bo4eMeta`, so unknown keys are detected by observing how the derived
+//! `Deserialize` impls consume a `serde_json::Value`: declared fields are
+//! requested with a typed call (`deserialize_option`, `deserialize_seq`, ...),
+//! while keys that no field claims are either ignored or buffered through
+//! `deserialize_any` for the flattened metadata.
+
+use std::cell::RefCell;
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Error, Value};
+
+/// JSON keys claimed by the flattened `Bo4eMeta` struct.
+const META_KEYS: [&str; 7] = [
+    "_typ",
+    "_version",
+    "_id",
+    "boTyp",
+    "boVersion",
+    "boId",
+    "zusatzAttribute",
+];
+
+/// Deserialize `T` from `value`, returning the paths of all unknown fields.
+pub(crate) fn deserialize_tracked<T>(value: Value) -> Result<(T, Vec<String>), Error>
+where
+    T: de::DeserializeOwned,
+{
+    let unknown = RefCell::new(Vec::new());
+    let parsed = T::deserialize(Probe {
+        value,
+        path: String::new(),
+        unknown: &unknown,
+    })?;
+    Ok((parsed, unknown.into_inner()))
+}
+
+/// A value deserializer that records keys no field claims.
+struct Probe<'a> {
+    value: Value,
+    path: String,
+    unknown: &'a RefCell<Vec<String>>,
+}
+
+impl<'a> Probe<'a> {
+    fn child(parent: &str, segment: &str, value: Value, unknown: &'a RefCell<Vec<String>>) -> Self {
+        let path = if parent.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}/{}", parent, segment)
+        };
+        Probe {
+            value,
+            path,
+            unknown,
+        }
+    }
+
+    fn record_unknown(&self) {
+        self.unknown.borrow_mut().push(self.path.clone());
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.value.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for Probe<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        // Buffered for a flattened struct: only metadata keys end up there.
+        let key = self.path.rsplit('/').next().unwrap_or_default();
+        if !self.path.is_empty() && !META_KEYS.contains(&key) {
+            self.record_unknown();
+        }
+        self.value.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record_unknown();
+        self.value.deserialize_ignored_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Object(map) => visitor.visit_map(ProbeMap {
+                entries: map.into_iter(),
+                pending: None,
+                path: self.path,
+                unknown: self.unknown,
+            }),
+            other => other.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Array(items) => visitor.visit_seq(ProbeSeq {
+                items: items.into_iter().enumerate(),
+                path: self.path,
+                unknown: self.unknown,
+            }),
+            other => other.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.value.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    forward_to_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_unit
+        deserialize_identifier
+    }
+}
+
+struct ProbeMap<'a> {
+    entries: serde_json::map::IntoIter,
+    pending: Option<(String, Value)>,
+    path: String,
+    unknown: &'a RefCell<Vec<String>>,
+}
+
+impl<'de, 'a> MapAccess<'de> for ProbeMap<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                let parsed = seed.deserialize(Key(key.clone()))?;
+                self.pending = Some((key, value));
+                Ok(Some(parsed))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (key, value) = self
+            .pending
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        seed.deserialize(Probe::child(&self.path, &key, value, self.unknown))
+    }
+}
+
+struct ProbeSeq<'a> {
+    items: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+    path: String,
+    unknown: &'a RefCell<Vec<String>>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ProbeSeq<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.items.next() {
+            Some((index, value)) => seed
+                .deserialize(Probe::child(
+                    &self.path,
+                    &index.to_string(),
+                    value,
+                    self.unknown,
+                ))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Deserializer for an object key.
+struct Key(String);
+
+impl<'de> Deserializer<'de> for Key {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        IntoDeserializer::<Error>::into_deserializer(self.0).deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+
+    #[test]
+    fn test_known_fields_not_reported() {
+        let value = serde_json::json!({
+            "_typ": "Zaehler",
+            "zaehlernummer": "123",
+            "sparte": "STROM",
+            "zaehlwerke": [{"obisCode": "1-0:1.8.0"}],
+            "standort": {"ort": "Köln"}
+        });
+        let (meter, unknown): (Meter, _) = deserialize_tracked(value).unwrap();
+        assert_eq!(meter.meter_number, Some("123".to_string()));
+        assert!(unknown.is_empty(), "unexpected: {:?}", unknown);
+    }
+
+    #[test]
+    fn test_nested_unknown_fields_reported() {
+        let value = serde_json::json!({
+            "foo": 1,
+            "zaehlwerke": [{"bar": true}],
+            "standort": {"baz": "x"}
+        });
+        let (_, mut unknown): (Meter, _) = deserialize_tracked(value).unwrap();
+        unknown.sort();
+        assert_eq!(unknown, vec!["foo", "standort/baz", "zaehlwerke/0/bar"]);
+    }
+}
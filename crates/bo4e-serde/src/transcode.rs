@@ -0,0 +1,235 @@
+//! Transcoding of enum values in raw JSON between German and English.
+//!
+//! BO4E enums are written with their German codes, e.g. `"STROM"` for
+//! [`Division::Electricity`]. Their English codes, from
+//! [`Division::english_code`] and its counterparts, are the Rust variant
+//! names in upper snake case, e.g. `"ELECTRICITY"`. [`transcode_enums`]
+//! swaps the codes of known enum fields without deserializing into the BO4E
+//! types.
+//!
+//! Only values under the JSON keys of the enums listed in this module are
+//! touched, in both their English and German spelling, and only if they are
+//! a code of that enum. English codes are an output format for consumers;
+//! [`from_json`](crate::from_json) only accepts the German codes.
+
+use std::collections::HashMap;
+
+use bo4e_core::enums::{
+    CustomerType, Division, EnergyDirection, MarketRole, MeterSize, MeterType, Unit,
+};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::JsonLanguage;
+
+/// German and English codes of one enum, and the keys it is stored under.
+struct EnumTable {
+    keys: &'static [&'static str],
+    /// (German, English) code pairs
+    codes: Vec<(String, String)>,
+}
+
+impl EnumTable {
+    fn transcode(&self, code: &str, to: JsonLanguage) -> Option<&str> {
+        self.codes.iter().find_map(|(german, english)| match to {
+            JsonLanguage::German if english == code => Some(german.as_str()),
+            JsonLanguage::English if german == code => Some(english.as_str()),
+            _ => None,
+        })
+    }
+}
+
+fn table<T: Serialize>(
+    keys: &'static [&'static str],
+    variants: &[T],
+    english_code: fn(&T) -> &'static str,
+) -> EnumTable {
+    let codes = variants
+        .iter()
+        .filter_map(|variant| match serde_json::to_value(variant) {
+            Ok(Value::String(german)) => Some((german, english_code(variant).to_string())),
+            _ => None,
+        })
+        .collect();
+    EnumTable { keys, codes }
+}
+
+static TABLES: Lazy<Vec<EnumTable>> = Lazy::new(|| {
+    vec![
+        table(
+            &["division", "sparte"],
+            Division::ALL,
+            Division::english_code,
+        ),
+        table(
+            &["energyDirection", "energierichtung"],
+            EnergyDirection::ALL,
+            EnergyDirection::english_code,
+        ),
+        table(
+            &["meterType", "zaehlertyp", "zaehlerart"],
+            MeterType::ALL,
+            MeterType::english_code,
+        ),
+        table(
+            &["meterSize", "zaehlergroesse"],
+            MeterSize::ALL,
+            MeterSize::english_code,
+        ),
+        table(&["unit", "einheit"], Unit::ALL, Unit::english_code),
+        table(
+            &["marketRole", "marktrolle"],
+            MarketRole::ALL,
+            MarketRole::english_code,
+        ),
+        table(
+            &["customerType", "kundentyp", "customerTypes", "kundentypen"],
+            CustomerType::ALL,
+            CustomerType::english_code,
+        ),
+    ]
+});
+
+static BY_KEY: Lazy<HashMap<&'static str, &'static EnumTable>> = Lazy::new(|| {
+    TABLES
+        .iter()
+        .flat_map(|table| table.keys.iter().map(move |key| (*key, table)))
+        .collect()
+});
+
+/// Rewrite the enum codes in `value` to the codes of `to`.
+///
+/// Walks all objects and arrays. A string is rewritten if its key belongs
+/// to a known enum field and it is a code of that enum in the other
+/// language; lists of enum values are rewritten element by element. Codes
+/// already in the target language and all other strings are left alone.
+///
+/// # Example
+///
+/// ```rust
+/// use bo4e_serde::{transcode_enums, JsonLanguage};
+/// use serde_json::json;
+///
+/// let mut value = json!({"sparte": "STROM", "zaehlernummer": "STROM"});
+/// transcode_enums(&mut value, JsonLanguage::English);
+/// assert_eq!(value, json!({"sparte": "ELECTRICITY", "zaehlernummer": "STROM"}));
+/// ```
+pub fn transcode_enums(value: &mut Value, to: JsonLanguage) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match BY_KEY.get(key.as_str()) {
+                    Some(table) => transcode_field(child, table, to),
+                    None => transcode_enums(child, to),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| transcode_enums(item, to)),
+        _ => {}
+    }
+}
+
+fn transcode_field(value: &mut Value, table: &EnumTable, to: JsonLanguage) {
+    match value {
+        Value::String(code) => {
+            if let Some(new) = table.transcode(code, to) {
+                *code = new.to_string();
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| transcode_field(item, table, to)),
+        other => transcode_enums(other, to),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bo4e_core::bo::Meter;
+    use serde_json::json;
+
+    #[test]
+    fn test_tables_cover_every_variant() {
+        fn assert_covers<T: Serialize>(
+            key: &str,
+            variants: &[T],
+            english_code: fn(&T) -> &'static str,
+        ) {
+            let table = BY_KEY[key];
+            assert_eq!(table.codes.len(), variants.len());
+            for variant in variants {
+                let german = serde_json::to_value(variant).unwrap();
+                let german = german.as_str().unwrap();
+                let english = english_code(variant);
+                assert_eq!(
+                    table.transcode(german, JsonLanguage::English),
+                    Some(english)
+                );
+                assert_eq!(table.transcode(english, JsonLanguage::German), Some(german));
+            }
+        }
+
+        assert_covers("division", Division::ALL, Division::english_code);
+        assert_covers(
+            "energyDirection",
+            EnergyDirection::ALL,
+            EnergyDirection::english_code,
+        );
+        assert_covers("meterType", MeterType::ALL, MeterType::english_code);
+        assert_covers("meterSize", MeterSize::ALL, MeterSize::english_code);
+        assert_covers("unit", Unit::ALL, Unit::english_code);
+        assert_covers("marketRole", MarketRole::ALL, MarketRole::english_code);
+        assert_covers(
+            "customerType",
+            CustomerType::ALL,
+            CustomerType::english_code,
+        );
+        assert_eq!(TABLES.len(), 7);
+    }
+
+    #[test]
+    fn test_transcode_meter_division() {
+        let meter = Meter {
+            meter_number: Some("STROM".to_string()),
+            division: Some(Division::Electricity),
+            meter_size: Some(MeterSize::G2_5),
+            ..Default::default()
+        };
+        let original = serde_json::to_value(&meter).unwrap();
+
+        let mut value = original.clone();
+        transcode_enums(&mut value, JsonLanguage::English);
+        assert_eq!(value["division"], "ELECTRICITY");
+        assert_eq!(value["meterSize"], "G2_5");
+        // Not an enum field, even though the text is a division code
+        assert_eq!(value["meterNumber"], "STROM");
+
+        // Already English
+        let english = value.clone();
+        transcode_enums(&mut value, JsonLanguage::English);
+        assert_eq!(value, english);
+
+        transcode_enums(&mut value, JsonLanguage::German);
+        assert_eq!(value, original);
+    }
+
+    #[test]
+    fn test_transcode_nested_and_lists() {
+        let mut value = json!({
+            "kundentypen": ["PRIVAT", "WAERMEPUMPE", "UNBEKANNT"],
+            "zaehlwerke": [{"einheit": "KWH", "energierichtung": "AUSSP"}],
+            "sparte": {"unexpected": "object"}
+        });
+        transcode_enums(&mut value, JsonLanguage::English);
+        assert_eq!(
+            value,
+            json!({
+                "kundentypen": ["PRIVATE", "HEAT_PUMP", "UNBEKANNT"],
+                "zaehlwerke": [{"einheit": "KILOWATT_HOUR", "energierichtung": "FEED_OUT"}],
+                "sparte": {"unexpected": "object"}
+            })
+        );
+    }
+}
@@ -63,14 +63,30 @@
 
 // Re-export core types
 pub use bo4e_core::bo;
+pub use bo4e_core::calc;
 pub use bo4e_core::com;
 pub use bo4e_core::enums;
 pub use bo4e_core::traits;
-pub use bo4e_core::{Bo4eMeta, Bo4eObject};
+pub use bo4e_core::validation;
+pub use bo4e_core::{Bo4eMeta, Bo4eObject, CostReport, Validate, ValidationIssue, BO4E_VERSION};
 
 // Re-export serialization
-pub use bo4e_serde::{from_json, to_json_english, to_json_german};
-pub use bo4e_serde::{Error, JsonLanguage, SerializeConfig};
+pub use bo4e_serde::{borrowed, diff, framing, intern, patch};
+pub use bo4e_serde::{
+    content_id, from_json, from_json_any, from_json_borrowed, from_json_checked, from_json_prefix,
+    from_json_strict, from_json_strict_language, from_json_with_warnings, quick_validate,
+    to_json_compact, to_json_english, to_json_german, to_json_german_each, to_json_with_config,
+    to_jsonapi, transcode_enums,
+};
+#[cfg(feature = "cbor")]
+pub use bo4e_serde::{from_cbor, to_cbor};
+#[cfg(feature = "csv")]
+pub use bo4e_serde::{from_csv, to_csv};
+#[cfg(feature = "msgpack")]
+pub use bo4e_serde::{from_msgpack, to_msgpack};
+pub use bo4e_serde::{
+    Error, JsonArrayStream, JsonLanguage, MetaKeyStyle, SerializeConfig, Warning,
+};
 
 /// Prelude for convenient imports.
 #[allow(unused_imports)]
@@ -79,5 +95,5 @@ pub mod prelude {
     pub use crate::com::*;
     pub use crate::enums::*;
     pub use crate::{from_json, to_json_english, to_json_german};
-    pub use crate::{Bo4eMeta, Bo4eObject};
+    pub use crate::{Bo4eMeta, Bo4eObject, CostReport, Validate, ValidationIssue};
 }
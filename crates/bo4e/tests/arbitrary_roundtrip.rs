@@ -0,0 +1,55 @@
+//! Round-trip tests for `Arbitrary`-generated objects.
+//!
+//! Runs the same property as the `roundtrip_meter` fuzz target on a fixed
+//! set of pseudo-random inputs, so it is checked on every CI run.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use bo4e::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deterministic pseudo-random bytes (xorshift).
+fn noise(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+fn assert_roundtrips<T>()
+where
+    T: for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    for seed in 0..200 {
+        let bytes = noise(seed, 4096);
+        let value = T::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+        let mut german = to_json_german(&value).unwrap().into_bytes();
+        assert_eq!(from_json::<T>(&mut german).unwrap(), value, "seed {seed}");
+
+        let mut english = to_json_english(&value).unwrap().into_bytes();
+        assert_eq!(from_json::<T>(&mut english).unwrap(), value, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_meter_roundtrip() {
+    assert_roundtrips::<Meter>();
+}
+
+#[test]
+fn test_market_location_roundtrip() {
+    assert_roundtrips::<MarketLocation>();
+}
+
+#[test]
+fn test_metering_location_roundtrip() {
+    assert_roundtrips::<MeteringLocation>();
+}
@@ -76,6 +76,32 @@ fn test_business_partner_from_german_json() {
     assert_eq!(partner.meta.typ, Some("Geschaeftspartner".to_string()));
 }
 
+#[test]
+#[ignore = "needs the fixture written by generate.py with BO4E-Python installed"]
+fn test_meter_python_compatible_output() {
+    let expected: serde_json::Value =
+        serde_json::from_str(&load_fixture("meter_python_compatible")).unwrap();
+    let version = expected["_version"].as_str().unwrap_or_default();
+
+    let meter = Meter {
+        meta: Bo4eMeta::with_type("Zaehler").version(version),
+        meter_number: Some("1EMH0012345678".to_string()),
+        division: Some(Division::Electricity),
+        registers: vec![MeterRegister {
+            obis_code: Some("1-0:1.8.0".to_string()),
+            unit: Some(Unit::KilowattHour),
+            description: Some("HT".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let json = bo4e::to_json_with_config(&meter, &bo4e::SerializeConfig::python_compatible())
+        .expect("Failed to serialize");
+    let actual: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn test_roundtrip_meter() {
     // Create a Meter in Rust
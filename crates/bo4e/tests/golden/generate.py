@@ -15,8 +15,8 @@ from pathlib import Path
 
 # Try to import BO4E Python library
 try:
-    from bo4e import Zaehler, Marktlokation, Geschaeftspartner
-    from bo4e.enum import Sparte, Energierichtung, Geschaeftspartnerrolle
+    from bo4e import Zaehler, Zaehlwerk, Marktlokation, Geschaeftspartner
+    from bo4e.enum import Sparte, Energierichtung, Geschaeftspartnerrolle, Mengeneinheit
     HAS_BO4E = True
 except ImportError:
     HAS_BO4E = False
@@ -58,6 +58,25 @@ def generate_with_bo4e():
     )
     write_fixture("business_partner", partner.model_dump(by_alias=True, exclude_none=True))
 
+    # Meter as BO4E-Python emits it, compared against
+    # SerializeConfig::python_compatible(). Only written from BO4E-Python,
+    # never hardcoded, so the comparison is not circular.
+    meter = Zaehler(
+        zaehlernummer="1EMH0012345678",
+        sparte=Sparte.STROM,
+        zaehlwerke=[
+            Zaehlwerk(
+                obisKennzahl="1-0:1.8.0",
+                einheit=Mengeneinheit.KWH,
+                bezeichnung="HT",
+            )
+        ],
+    )
+    write_fixture(
+        "meter_python_compatible",
+        meter.model_dump(mode="json", by_alias=True, exclude_none=True),
+    )
+
 
 def generate_hardcoded():
     """Generate hardcoded fixtures that match BO4E Python format."""
@@ -0,0 +1,278 @@
+//! Schema conformance tests against the published BO4E JSON schemas.
+//!
+//! The schemas of the BO4E-Schemas release matching [`BO4E_VERSION`] are
+//! vendored unmodified under `tests/schemas/` by
+//! `scripts/fetch_bo4e_schemas.sh`, keeping the upstream `bo/`, `com/` and
+//! `enum/` layout. Each business object is serialized with German keys and
+//! validated against its schema. Besides type and enum violations, keys that
+//! the schema does not declare are reported, so a renamed or misspelled field
+//! fails the test instead of passing silently. The schemas restrict `_typ`
+//! to the upper-case values of their `Typ` enum, e.g. `"ZAEHLER"`, so the
+//! objects are built with those instead of the type names.
+//!
+//! The conformance tests are ignored until the schemas are vendored; run them
+//! with `cargo test --test schema_conformance -- --ignored`.
+
+use bo4e::prelude::*;
+use bo4e::{SerializeConfig, BO4E_VERSION};
+use chrono::{NaiveDate, TimeZone, Utc};
+use jsonschema::{JSONSchema, SchemaResolver, SchemaResolverError};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use url::Url;
+
+const MISSING_SCHEMAS: &str =
+    "needs the BO4E-Schemas release vendored by scripts/fetch_bo4e_schemas.sh";
+
+/// Directory holding the vendored schemas.
+fn schema_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("schemas")
+}
+
+/// Load a vendored schema by its path relative to the schema directory,
+/// e.g. `bo/Zaehler.json`.
+fn load_schema(path: &str) -> Value {
+    let text = fs::read_to_string(schema_dir().join(path)).unwrap_or_else(|e| {
+        panic!(
+            "Failed to load schema {} ({}): {}",
+            path, MISSING_SCHEMAS, e
+        )
+    });
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("Invalid schema {}: {}", path, e))
+}
+
+/// The `<directory>/<file>` part of a reference such as `../enum/Sparte.json#`.
+fn schema_file(reference: &str) -> Option<String> {
+    let path = reference.split('#').next()?;
+    let mut segments = path.rsplit('/').filter(|s| !s.is_empty());
+    let file = segments.next()?;
+    let dir = segments.next()?;
+    file.ends_with(".json").then(|| format!("{}/{}", dir, file))
+}
+
+/// Resolves references between the vendored schema files.
+struct VendoredSchemas;
+
+impl SchemaResolver for VendoredSchemas {
+    fn resolve(
+        &self,
+        _root_schema: &Value,
+        url: &Url,
+        original_reference: &str,
+    ) -> Result<Arc<Value>, SchemaResolverError> {
+        let path = schema_file(url.path())
+            .or_else(|| schema_file(original_reference))
+            .ok_or_else(|| anyhow::anyhow!("cannot resolve {}", url))?;
+        Ok(Arc::new(load_schema(&path)))
+    }
+}
+
+/// Serialize `object` the way it is exchanged with other BO4E implementations.
+fn to_german_value<T: serde::Serialize>(object: &T) -> Value {
+    let config = SerializeConfig::german().with_translate_keys(true);
+    let json = bo4e::to_json_with_config(object, &config).expect("Failed to serialize");
+    serde_json::from_str(&json).expect("Serialized output is not valid JSON")
+}
+
+/// Collect all violations of `instance` against `schema`.
+fn violations(schema: &Value, instance: &Value) -> Vec<String> {
+    let compiled = JSONSchema::options()
+        .with_resolver(VendoredSchemas)
+        .compile(schema)
+        .unwrap_or_else(|e| panic!("Failed to compile schema: {}", e));
+
+    let mut found = match compiled.validate(instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|e| format!("{}: {}", e.instance_path, e))
+            .collect(),
+    };
+    undeclared_keys(schema, schema, instance, "", &mut found);
+    found
+}
+
+/// Follow `$ref`s from `node`, loading other schema files as needed.
+///
+/// Returns the resolved node together with the document it belongs to.
+fn resolve(document: &Value, node: &Value) -> (Value, Value) {
+    let Some(reference) = node.get("$ref").and_then(Value::as_str) else {
+        return (document.clone(), node.clone());
+    };
+    let (file, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+    let document = if file.is_empty() {
+        document.clone()
+    } else {
+        load_schema(&schema_file(file).unwrap_or_else(|| panic!("Bad reference {}", reference)))
+    };
+    let target = document.pointer(pointer).cloned().unwrap_or(Value::Null);
+    resolve(&document, &target)
+}
+
+/// The schema nodes `node` may stand for: itself and its `anyOf`, `oneOf`
+/// and `allOf` alternatives, with references resolved.
+fn alternatives(document: &Value, node: &Value) -> Vec<(Value, Value)> {
+    let (document, node) = resolve(document, node);
+    let mut found = vec![(document.clone(), node.clone())];
+    for keyword in ["anyOf", "oneOf", "allOf"] {
+        if let Some(options) = node.get(keyword).and_then(Value::as_array) {
+            for option in options {
+                found.extend(alternatives(&document, option));
+            }
+        }
+    }
+    found
+}
+
+/// Report object keys missing from the `properties` of the matching schema node.
+fn undeclared_keys(
+    document: &Value,
+    node: &Value,
+    instance: &Value,
+    path: &str,
+    out: &mut Vec<String>,
+) {
+    let options = alternatives(document, node);
+    match instance {
+        Value::Object(map) => {
+            let Some((document, properties)) = options.iter().find_map(|(document, node)| {
+                Some((document, node.get("properties")?.as_object()?))
+            }) else {
+                return;
+            };
+            for (key, value) in map {
+                let child = format!("{}/{}", path, key);
+                match properties.get(key) {
+                    Some(property) => undeclared_keys(document, property, value, &child, out),
+                    None => out.push(format!("{}: property not declared in schema", child)),
+                }
+            }
+        }
+        Value::Array(items) => {
+            let Some((document, item_schema)) = options
+                .iter()
+                .find_map(|(document, node)| Some((document, node.get("items")?)))
+            else {
+                return;
+            };
+            for (i, item) in items.iter().enumerate() {
+                let child = format!("{}/{}", path, i);
+                undeclared_keys(document, item_schema, item, &child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn assert_conforms<T: serde::Serialize>(path: &str, object: &T) {
+    let schema = load_schema(path);
+    let instance = to_german_value(object);
+    let found = violations(&schema, &instance);
+    assert!(
+        found.is_empty(),
+        "{} does not conform to its schema:\n  {}\n{}",
+        path,
+        found.join("\n  "),
+        serde_json::to_string_pretty(&instance).unwrap()
+    );
+}
+
+#[test]
+#[ignore = "needs the BO4E-Schemas release vendored by scripts/fetch_bo4e_schemas.sh"]
+fn test_vendored_schemas_match_bo4e_version() {
+    let version = fs::read_to_string(schema_dir().join("VERSION")).expect(MISSING_SCHEMAS);
+    assert_eq!(version.trim(), BO4E_VERSION);
+}
+
+#[test]
+#[ignore = "needs the BO4E-Schemas release vendored by scripts/fetch_bo4e_schemas.sh"]
+fn test_meter_conforms_to_schema() {
+    let meter = Meter {
+        meta: Bo4eMeta::with_type("ZAEHLER"),
+        meter_number: Some("1EMH0012345678".to_string()),
+        division: Some(Division::Electricity),
+        installation_date: Some(Utc.with_ymd_and_hms(2024, 1, 15, 8, 0, 0).unwrap()),
+        ..Default::default()
+    };
+    assert_conforms("bo/Zaehler.json", &meter);
+}
+
+#[test]
+#[ignore = "needs the BO4E-Schemas release vendored by scripts/fetch_bo4e_schemas.sh"]
+fn test_market_location_conforms_to_schema() {
+    let malo = MarketLocation {
+        meta: Bo4eMeta::with_type("MARKTLOKATION"),
+        market_location_id: Some("51238696781".to_string()),
+        division: Some(Division::Electricity),
+        energy_direction: Some(EnergyDirection::FeedOut),
+        annual_consumption: Some(3500.0),
+        ..Default::default()
+    };
+    assert_conforms("bo/Marktlokation.json", &malo);
+}
+
+#[test]
+#[ignore = "needs the BO4E-Schemas release vendored by scripts/fetch_bo4e_schemas.sh"]
+fn test_invoice_conforms_to_schema() {
+    let invoice = Invoice {
+        meta: Bo4eMeta::with_type("RECHNUNG"),
+        invoice_number: Some("RE-2024-0001".to_string()),
+        invoice_type: Some(InvoiceType::EndCustomerInvoice),
+        division: Some(Division::Electricity),
+        invoice_date: NaiveDate::from_ymd_opt(2024, 1, 31),
+        due_date: NaiveDate::from_ymd_opt(2024, 2, 14),
+        net_amount: Some(Amount::eur(100.0)),
+        tax_amount: Some(Amount::eur(19.0)),
+        gross_amount: Some(Amount::eur(119.0)),
+        ..Default::default()
+    };
+    assert_conforms("bo/Rechnung.json", &invoice);
+}
+
+#[test]
+fn test_violations_name_the_offending_field() {
+    // Shaped like the generated BO4E schemas: optional fields are an
+    // `anyOf` with null, nested types are referenced through `$defs`.
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "marktlokationsId": {"anyOf": [{"type": "string"}, {"type": "null"}]},
+            "adresse": {"anyOf": [{"$ref": "#/$defs/Adresse"}, {"type": "null"}]}
+        },
+        "$defs": {
+            "Adresse": {"type": "object", "properties": {"ort": {"type": "string"}}}
+        }
+    });
+    let mut instance = to_german_value(&MarketLocation {
+        market_location_id: Some("51238696781".to_string()),
+        ..Default::default()
+    });
+    instance["marktlokationsId"] = Value::from(51238696781u64);
+    instance["lokationsnummer"] = Value::from("x");
+    instance["adresse"] = serde_json::json!({"ort": "Köln", "plz": "50667"});
+
+    let found = violations(&schema, &instance);
+    assert!(found.iter().any(|v| v.starts_with("/marktlokationsId: ")));
+    assert!(found
+        .iter()
+        .any(|v| v == "/lokationsnummer: property not declared in schema"));
+    assert!(found
+        .iter()
+        .any(|v| v == "/adresse/plz: property not declared in schema"));
+}
+
+#[test]
+fn test_schema_file_from_reference() {
+    assert_eq!(
+        schema_file("../enum/Sparte.json#").as_deref(),
+        Some("enum/Sparte.json")
+    );
+    assert_eq!(
+        schema_file("/com/Adresse.json").as_deref(),
+        Some("com/Adresse.json")
+    );
+    assert_eq!(schema_file("#/$defs/Adresse"), None);
+}
@@ -0,0 +1,14 @@
+//! Serialize an arbitrary `Meter` and parse it back.
+//!
+//! Run with `cargo +nightly fuzz run roundtrip_meter` from the repository root.
+
+#![no_main]
+
+use bo4e::bo::Meter;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|meter: Meter| {
+    let mut json = bo4e::to_json_english(&meter).unwrap().into_bytes();
+    let parsed: Meter = bo4e::from_json(&mut json).unwrap();
+    assert_eq!(meter, parsed);
+});
@@ -0,0 +1,57 @@
+#!/usr/bin/env python3
+"""Convert the BDEW standard load profile workbook for bo4e-core.
+
+Writes crates/bo4e-core/data/bdew_slp.csv from the BDEW workbook
+"Repräsentative Profile VDEW" (one sheet per profile H0, G0-G6, L0-L2).
+
+Each sheet holds 96 quarter-hour rows after its header rows. Their nine value
+columns are Saturday, Sunday and weekday for winter, summer and the
+transition period, in that order. The values are copied as published.
+
+Usage:
+    python scripts/convert_bdew_slp.py "Repräsentative Profile VDEW.xls"
+
+Note: Requires pandas and xlrd: pip install pandas xlrd
+"""
+
+import csv
+import sys
+from pathlib import Path
+
+import pandas as pd
+
+PROFILES = ["H0", "G0", "G1", "G2", "G3", "G4", "G5", "G6", "L0", "L1", "L2"]
+COLUMNS = [
+    (season, day_type)
+    for season in ("winter", "summer", "transition")
+    for day_type in ("saturday", "sunday", "weekday")
+]
+OUTPUT = Path(__file__).parent.parent / "crates" / "bo4e-core" / "data" / "bdew_slp.csv"
+
+
+def quarter_hour_rows(sheet):
+    """The 96 rows of a sheet, found by their time of day in the first column."""
+    start = next(
+        i for i, cell in enumerate(sheet[0]) if str(cell).strip().startswith("00:15")
+    )
+    rows = sheet.iloc[start : start + 96]
+    if len(rows) != 96:
+        raise ValueError("expected 96 quarter-hour rows")
+    return rows
+
+
+def main():
+    workbook = pd.read_excel(sys.argv[1], sheet_name=None, header=None)
+    with open(OUTPUT, "w", newline="") as f:
+        writer = csv.writer(f, lineterminator="\n")
+        writer.writerow(["profile", "season", "day_type", "quarter_hour", "value"])
+        for profile in PROFILES:
+            rows = quarter_hour_rows(workbook[profile])
+            for column, (season, day_type) in enumerate(COLUMNS, start=1):
+                for quarter, value in enumerate(rows[column]):
+                    writer.writerow([profile, season, day_type, quarter, float(value)])
+    print(f"Wrote {OUTPUT}")
+
+
+if __name__ == "__main__":
+    main()
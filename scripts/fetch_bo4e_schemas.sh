@@ -0,0 +1,30 @@
+#!/usr/bin/env sh
+# Vendor the BO4E-Schemas release matching BO4E_VERSION for the schema
+# conformance tests.
+#
+# Usage:
+#     scripts/fetch_bo4e_schemas.sh
+#
+# The bo/, com/ and enum/ directories of the release are copied unmodified
+# to crates/bo4e/tests/schemas/, together with a VERSION file.
+
+set -eu
+
+root=$(cd "$(dirname "$0")/.." && pwd)
+version=$(sed -n 's/^pub const BO4E_VERSION: &str = "\(.*\)";$/\1/p' "$root/crates/bo4e-core/src/lib.rs")
+dest="$root/crates/bo4e/tests/schemas"
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+curl -fsSL "https://github.com/bo4e/BO4E-Schemas/archive/refs/tags/v$version.tar.gz" | tar -xz -C "$tmp"
+src=$(find "$tmp" -type d -path "*/src/bo4e_schemas" | head -n 1)
+if [ -z "$src" ]; then
+    echo "bo4e_schemas directory not found in release v$version" >&2
+    exit 1
+fi
+
+rm -rf "$dest"
+mkdir -p "$dest"
+cp -R "$src/bo" "$src/com" "$src/enum" "$dest/"
+echo "$version" > "$dest/VERSION"
+echo "Vendored BO4E-Schemas v$version to $dest"